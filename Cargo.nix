@@ -11,6 +11,9 @@ args@{
   hostPlatform,
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
   mkRustCrate,
   rustLib,
   lib,
@@ -25,9 +28,12 @@ in let
   rootFeatures' = expandFeatures rootFeatures;
   overridableMkRustCrate = f:
     let
-      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures; } // (f profileName)));
-    in { compileMode ? null, profileName ? decideProfile compileMode release }:
-      let drv = drvs.${profileName}; in if compileMode == null then drv else drv.override { inherit compileMode; };
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
 in
 {
   cargo2nixVersion = "0.9.0";
@@ -39,6 +45,15 @@ in
     version = "0.2.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ee2a4ec343196209d6594e19543ae87a39f96d5534d7174822a3ad825dd6ed7e"; };
+    edition = "2015";
+    libName = "adler";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Jonas Schievink <jonasschievink@gmail.com>\"]\ncategories = [\"algorithms\"]\ndescription = \"A simple clean-room implementation of the Adler-32 checksum\"\ndocumentation = \"https://docs.rs/adler/\"\nkeywords = [\"checksum\", \"integrity\", \"hash\", \"adler32\"]\nlicense = \"0BSD OR MIT OR Apache-2.0\"\nname = \"adler\"\nreadme = \"README.md\"\nrepository = \"https://github.com/jonas-schievink/adler.git\"\nversion = \"0.2.3\"\n[package.metadata.docs.rs]\nrustdoc-args = [\"--cfg docsrs\"]\n\n[package.metadata.release]\nno-dev-version = true\npre-release-commit-message = \"Release {{version}}\"\ntag-message = \"{{version}}\"\n\n[[package.metadata.release.pre-release-replacements]]\nfile = \"CHANGELOG.md\"\nreplace = \"## Unreleased\\n\\nNo changes.\\n\\n## [{{version}} - {{date}}](https://github.com/jonas-schievink/adler/releases/tag/v{{version}})\\n\"\nsearch = \"## Unreleased\\n\"\n\n[[package.metadata.release.pre-release-replacements]]\nfile = \"README.md\"\nreplace = \"adler = \\\"{{version}}\\\"\"\nsearch = \"adler = \\\"[a-z0-9\\\\\\\\.-]+\\\"\"\n\n[[package.metadata.release.pre-release-replacements]]\nfile = \"src/lib.rs\"\nreplace = \"https://docs.rs/adler/{{version}}\"\nsearch = \"https://docs.rs/adler/[a-z0-9\\\\.-]+\"\n";
+    meta = {
+      description = "A simple clean-room implementation of the Adler-32 checksum";
+      license = [ lib.licenses.bsd0 lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/jonas-schievink/adler.git";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".aho-corasick."0.7.15" = overridableMkRustCrate (profileName: rec {
@@ -46,6 +61,15 @@ in
     version = "0.7.15";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7404febffaa47dac81aa44dba71523c9d069b1bdc50a77db41195149e17f68e5"; };
+    edition = "2015";
+    libName = "aho_corasick";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"aho_corasick\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\nautotests = false\ncategories = [\"text-processing\"]\ndescription = \"Fast multiple substring searching.\"\nexclude = [\"/aho-corasick-debug\", \"/ci/*\", \"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/BurntSushi/aho-corasick\"\nkeywords = [\"string\", \"search\", \"text\", \"aho\", \"multi\"]\nlicense = \"Unlicense/MIT\"\nname = \"aho-corasick\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/aho-corasick\"\nversion = \"0.7.15\"\n";
+    meta = {
+      description = "Fast multiple substring searching.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/aho-corasick";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -60,8 +84,17 @@ in
     version = "0.11.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ee49baf6cb617b853aa8d93bf420db2383fab46d314482ca2803b40d5fde979b"; };
+    edition = "2015";
+    libName = "ansi_term";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"ansi_term\"\n\n[package]\nauthors = [\"ogham@bsago.me\", \"Ryan Scheel (Havvy) <ryan.havvy@gmail.com>\", \"Josh Triplett <josh@joshtriplett.org>\"]\ndescription = \"Library for ANSI terminal colours and styles (bold, underline)\"\ndocumentation = \"https://docs.rs/ansi_term\"\nhomepage = \"https://github.com/ogham/rust-ansi-term\"\nlicense = \"MIT\"\nname = \"ansi_term\"\nreadme = \"README.md\"\nversion = \"0.11.0\"\n";
+    meta = {
+      description = "Library for ANSI terminal colours and styles (bold, underline)";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/ogham/rust-ansi-term";
+    };
     dependencies = {
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
+      ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
   
@@ -70,6 +103,16 @@ in
     version = "1.0.35";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2c0df63cb2955042487fad3aefd2c6e3ae7389ac5dc1beb28921de0b69f779d4"; };
+    edition = "2018";
+    libName = "anyhow";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"rust-patterns\"]\ndescription = \"Flexible concrete Error type built on std::error::Error\"\ndocumentation = \"https://docs.rs/anyhow\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"anyhow\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/anyhow\"\nversion = \"1.0.35\"\n[package.metadata.docs.rs]\nrustdoc-args = [\"--cfg\", \"doc_cfg\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Flexible concrete Error type built on std::error::Error";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/anyhow";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -81,6 +124,15 @@ in
     version = "0.2.14";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d9b39be18770d11421cdb1b9947a45dd3f37e93092cbf377614828a319d5fee8"; };
+    edition = "2015";
+    libName = "atty";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"softprops <d.tangren@gmail.com>\"]\ndescription = \"A simple interface for querying atty\"\ndocumentation = \"http://softprops.github.io/atty\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/softprops/atty\"\nkeywords = [\"terminal\", \"tty\", \"isatty\"]\nlicense = \"MIT\"\nname = \"atty\"\nreadme = \"README.md\"\nrepository = \"https://github.com/softprops/atty\"\nversion = \"0.2.14\"\n";
+    meta = {
+      description = "A simple interface for querying atty";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/softprops/atty";
+    };
     dependencies = {
       ${ if hostPlatform.parsed.kernel.name == "hermit" then "hermit_abi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.1.17" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
@@ -93,6 +145,15 @@ in
     version = "1.0.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cdb031dd78e28731d87d56cc8ffef4a8f36ca26c38fe2de700543e627f8a464a"; };
+    edition = "2015";
+    libName = "autocfg";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Josh Stone <cuviper@gmail.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"Automatic cfg for Rust compiler features\"\nexclude = [\"/.github/**\", \"/bors.toml\"]\nkeywords = [\"rustc\", \"build\", \"autoconf\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"autocfg\"\nreadme = \"README.md\"\nrepository = \"https://github.com/cuviper/autocfg\"\nversion = \"1.0.1\"\n";
+    meta = {
+      description = "Automatic cfg for Rust compiler features";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/cuviper/autocfg";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" = overridableMkRustCrate (profileName: rec {
@@ -100,6 +161,16 @@ in
     version = "1.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf1de2fe8c75bc145a2f577add951f8134889b4795d47466a54a5c846d691693"; };
+    edition = "2015";
+    libName = "bitflags";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"no-std\"]\ndescription = \"A macro to generate structures which behave like bitflags.\\n\"\ndocumentation = \"https://docs.rs/bitflags\"\nexclude = [\".travis.yml\", \"appveyor.yml\", \"bors.toml\"]\nhomepage = \"https://github.com/bitflags/bitflags\"\nkeywords = [\"bit\", \"bitmask\", \"bitflags\", \"flags\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"bitflags\"\nreadme = \"README.md\"\nrepository = \"https://github.com/bitflags/bitflags\"\nversion = \"1.2.1\"\n[package.metadata.docs.rs]\nfeatures = [\"example_generated\"]\n";
+    meta = {
+      description = "A macro to generate structures which behave like bitflags.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bitflags/bitflags";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -110,6 +181,15 @@ in
     version = "2.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "031043d04099746d8db04daf1fa424b2bc8bd69d92b25962dcde24da39ab64a2"; };
+    edition = "2018";
+    libName = "bitmaps";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Bodil Stokke <bodil@bodil.org>\"]\ncategories = [\"data-structures\"]\ndescription = \"Fixed size boolean arrays\"\ndocumentation = \"http://docs.rs/bitmaps\"\nedition = \"2018\"\nexclude = [\"release.toml\", \"proptest-regressions/**\"]\nlicense = \"MPL-2.0+\"\nname = \"bitmaps\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/bodil/bitmaps\"\nversion = \"2.1.0\"\n";
+    meta = {
+      description = "Fixed size boolean arrays";
+      license = "MPL-2.0+";
+      homepage = "https://github.com/bodil/bitmaps";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -124,6 +204,15 @@ in
     version = "0.7.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c0940dc441f31689269e10ac70eb1002a3a1d3ad1390e030043662eb7fe4688b"; };
+    edition = "2015";
+    libName = "block_buffer";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"RustCrypto Developers\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"Fixed size buffer for block processing of data\"\ndocumentation = \"https://docs.rs/block-buffer\"\nkeywords = [\"block\", \"buffer\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"block-buffer\"\nrepository = \"https://github.com/RustCrypto/utils\"\nversion = \"0.7.3\"\n";
+    meta = {
+      description = "Fixed size buffer for block processing of data";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/utils";
+    };
     dependencies = {
       block_padding = rustPackages."registry+https://github.com/rust-lang/crates.io-index".block-padding."0.1.5" { inherit profileName; };
       byte_tools = rustPackages."registry+https://github.com/rust-lang/crates.io-index".byte-tools."0.3.1" { inherit profileName; };
@@ -137,6 +226,15 @@ in
     version = "0.1.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fa79dedbb091f449f1f39e53edf88d5dbe95f895dae6135a8d7b881fb5af73f5"; };
+    edition = "2015";
+    libName = "block_padding";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"RustCrypto Developers\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"Padding and unpadding of messages divided into blocks.\"\ndocumentation = \"https://docs.rs/block-padding\"\nkeywords = [\"padding\", \"pkcs7\", \"ansix923\", \"iso7816\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"block-padding\"\nrepository = \"https://github.com/RustCrypto/utils\"\nversion = \"0.1.5\"\n";
+    meta = {
+      description = "Padding and unpadding of messages divided into blocks.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/utils";
+    };
     dependencies = {
       byte_tools = rustPackages."registry+https://github.com/rust-lang/crates.io-index".byte-tools."0.3.1" { inherit profileName; };
     };
@@ -147,6 +245,15 @@ in
     version = "0.2.14";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "473fc6b38233f9af7baa94fb5852dca389e3d95b8e21c8e3719301462c5d9faf"; };
+    edition = "2015";
+    libName = "bstr";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ncategories = [\"text-processing\", \"encoding\"]\ndescription = \"A string type that is not required to be valid UTF-8.\"\ndocumentation = \"https://docs.rs/bstr\"\nexclude = [\"/.github\"]\nhomepage = \"https://github.com/BurntSushi/bstr\"\nkeywords = [\"string\", \"str\", \"byte\", \"bytes\", \"text\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"bstr\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/bstr\"\nversion = \"0.2.14\"\n";
+    meta = {
+      description = "A string type that is not required to be valid UTF-8.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/BurntSushi/bstr";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
@@ -160,6 +267,15 @@ in
     version = "0.3.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e3b5ca7a04898ad4bcd41c90c5285445ff5b791899bb1b0abdd2a2aa791211d7"; };
+    edition = "2015";
+    libName = "byte_tools";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"RustCrypto Developers\"]\ndescription = \"Bytes related utility functions\"\ndocumentation = \"https://docs.rs/byte-tools\"\nkeywords = [\"bytes\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"byte-tools\"\nrepository = \"https://github.com/RustCrypto/utils\"\nversion = \"0.3.1\"\n";
+    meta = {
+      description = "Bytes related utility functions";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/utils";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".byteorder."1.3.4" = overridableMkRustCrate (profileName: rec {
@@ -167,6 +283,16 @@ in
     version = "1.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "08c48aae112d48ed9f069b33538ea9e3e90aa263cfa3d1c24309612b1f7472de"; };
+    edition = "2015";
+    libName = "byteorder";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"byteorder\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"encoding\", \"parsing\"]\ndescription = \"Library for reading/writing numbers in big-endian and little-endian.\"\ndocumentation = \"https://docs.rs/byteorder\"\nexclude = [\"/ci/*\"]\nhomepage = \"https://github.com/BurntSushi/byteorder\"\nkeywords = [\"byte\", \"endian\", \"big-endian\", \"little-endian\", \"binary\"]\nlicense = \"Unlicense OR MIT\"\nname = \"byteorder\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/byteorder\"\nversion = \"1.3.4\"\n";
+    meta = {
+      description = "Library for reading/writing numbers in big-endian and little-endian.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/byteorder";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".bytesize."1.0.1" = overridableMkRustCrate (profileName: rec {
@@ -174,6 +300,15 @@ in
     version = "1.0.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "81a18687293a1546b67c246452202bbbf143d239cb43494cc163da14979082da"; };
+    edition = "2015";
+    libName = "bytesize";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Hyunsik Choi <hyunsik.choi@gmail.com>\"]\ndescription = \"an utility for human-readable bytes representations\"\ndocumentation = \"https://docs.rs/bytesize/\"\nhomepage = \"https://github.com/hyunsik/bytesize/\"\nkeywords = [\"byte\", \"byte-size\", \"utility\", \"human-readable\", \"format\"]\nlicense = \"Apache-2.0\"\nname = \"bytesize\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hyunsik/bytesize/\"\nversion = \"1.0.1\"\n";
+    meta = {
+      description = "an utility for human-readable bytes representations";
+      license = [ lib.licenses.asl20 ];
+      homepage = "https://github.com/hyunsik/bytesize/";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cargo."0.48.0" = overridableMkRustCrate (profileName: rec {
@@ -181,13 +316,23 @@ in
     version = "0.48.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cb089b07971f458df6e92fb0434e0d3d9542f304b6d82b7f288b22c7264f0e7f"; };
+    edition = "2018";
+    libName = "cargo";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[bin]]\ndoc = false\nname = \"cargo\"\ntest = false\n\n[lib]\nname = \"cargo\"\npath = \"src/cargo/lib.rs\"\n\n[package]\nauthors = [\"Yehuda Katz <wycats@gmail.com>\", \"Carl Lerche <me@carllerche.com>\", \"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Cargo, a package manager for Rust.\\n\"\ndocumentation = \"https://docs.rs/cargo\"\nedition = \"2018\"\nhomepage = \"https://crates.io\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"cargo\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/cargo\"\nversion = \"0.48.0\"\n";
+    meta = {
+      description = "Cargo, a package manager for Rust.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io";
+    };
     dependencies = {
       anyhow = rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.35" { inherit profileName; };
       atty = rustPackages."registry+https://github.com/rust-lang/crates.io-index".atty."0.2.14" { inherit profileName; };
       bytesize = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytesize."1.0.1" { inherit profileName; };
       cargo_platform = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cargo-platform."0.1.1" { inherit profileName; };
       clap = rustPackages."registry+https://github.com/rust-lang/crates.io-index".clap."2.33.3" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "darwin" then "core_foundation" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.1" { inherit profileName; };
+      ${ if hostPlatform.isMacOS then "core_foundation" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation."0.9.1" { inherit profileName; };
       crates_io = rustPackages."registry+https://github.com/rust-lang/crates.io-index".crates-io."0.31.1" { inherit profileName; };
       crossbeam_utils = rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.7.2" { inherit profileName; };
       crypto_hash = rustPackages."registry+https://github.com/rust-lang/crates.io-index".crypto-hash."0.3.4" { inherit profileName; };
@@ -246,6 +391,15 @@ in
     version = "0.1.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0226944a63d1bf35a3b5f948dd7c59e263db83695c9e8bffc4037de02e30f1d7"; };
+    edition = "2018";
+    libName = "cargo_platform";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Cargo Project Developers\"]\ndescription = \"Cargo's representation of a target platform.\"\ndocumentation = \"https://docs.rs/cargo-platform\"\nedition = \"2018\"\nhomepage = \"https://github.com/rust-lang/cargo\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"cargo-platform\"\nrepository = \"https://github.com/rust-lang/cargo\"\nversion = \"0.1.1\"\n";
+    meta = {
+      description = "Cargo's representation of a target platform.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/cargo";
+    };
     dependencies = {
       serde = rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.118" { inherit profileName; };
     };
@@ -256,6 +410,11 @@ in
     version = "0.9.0";
     registry = "unknown";
     src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nlicense = \"MIT\"\nname = \"cargo2nix\"\nversion = \"0.9.0\"\n";
+    meta = {
+      license = [ lib.licenses.mit ];
+    };
     dependencies = {
       anyhow = rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.35" { inherit profileName; };
       cargo = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cargo."0.48.0" { inherit profileName; };
@@ -264,10 +423,15 @@ in
       pathdiff = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pathdiff."0.2.0" { inherit profileName; };
       semver = rustPackages."registry+https://github.com/rust-lang/crates.io-index".semver."0.9.0" { inherit profileName; };
       serde = rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.118" { inherit profileName; };
+      serde_json = rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde_json."1.0.60" { inherit profileName; };
       tempfile = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.1.0" { inherit profileName; };
       tera = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tera."1.5.0" { inherit profileName; };
       toml = rustPackages."registry+https://github.com/rust-lang/crates.io-index".toml."0.5.7" { inherit profileName; };
     };
+    targets = [
+      { kind = "bin"; name = "cargo2nix"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+      { kind = "test"; name = "snapshots"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cc."1.0.66" = overridableMkRustCrate (profileName: rec {
@@ -275,6 +439,15 @@ in
     version = "1.0.66";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4c0496836a84f8d0495758516b8621a622beb77c0fed418570e50764093ced48"; };
+    edition = "2018";
+    libName = "cc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"A build-time dependency for Cargo build scripts to assist in invoking the native\\nC compiler to compile native C code into a static archive to be linked into Rust\\ncode.\\n\"\ndocumentation = \"https://docs.rs/cc\"\nedition = \"2018\"\nexclude = [\"/.github\", \"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/alexcrichton/cc-rs\"\nkeywords = [\"build-dependencies\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"cc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cc-rs\"\nversion = \"1.0.66\"\n";
+    meta = {
+      description = "A build-time dependency for Cargo build scripts to assist in invoking the native\nC compiler to compile native C code into a static archive to be linked into Rust\ncode.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cc-rs";
+    };
     features = builtins.concatLists [
       [ "jobserver" ]
       [ "parallel" ]
@@ -289,6 +462,15 @@ in
     version = "0.1.10";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4785bdd1c96b2a846b2bd7cc02e86b6b3dbf14e7e53446c4f54c92a361040822"; };
+    edition = "2018";
+    libName = "cfg_if";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A macro to ergonomically define an item depending on a large number of #[cfg]\\nparameters. Structured like an if-else chain, the first matching branch is the\\nitem that gets emitted.\\n\"\ndocumentation = \"https://docs.rs/cfg-if\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/cfg-if\"\nlicense = \"MIT/Apache-2.0\"\nname = \"cfg-if\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cfg-if\"\nversion = \"0.1.10\"\n";
+    meta = {
+      description = "A macro to ergonomically define an item depending on a large number of #[cfg]\nparameters. Structured like an if-else chain, the first matching branch is the\nitem that gets emitted.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cfg-if";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" = overridableMkRustCrate (profileName: rec {
@@ -296,6 +478,15 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd"; };
+    edition = "2018";
+    libName = "cfg_if";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A macro to ergonomically define an item depending on a large number of #[cfg]\\nparameters. Structured like an if-else chain, the first matching branch is the\\nitem that gets emitted.\\n\"\ndocumentation = \"https://docs.rs/cfg-if\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/cfg-if\"\nlicense = \"MIT/Apache-2.0\"\nname = \"cfg-if\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cfg-if\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A macro to ergonomically define an item depending on a large number of #[cfg]\nparameters. Structured like an if-else chain, the first matching branch is the\nitem that gets emitted.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cfg-if";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".clap."2.33.3" = overridableMkRustCrate (profileName: rec {
@@ -303,6 +494,15 @@ in
     version = "2.33.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "37e58ac78573c40708d45522f0d80fa2f01cc4f9b4e2bf749807255454312002"; };
+    edition = "2015";
+    libName = "clap";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Kevin K. <kbknapp@gmail.com>\"]\ncategories = [\"command-line-interface\"]\ndescription = \"A simple to use, efficient, and full-featured Command Line Argument Parser\\n\"\ndocumentation = \"https://docs.rs/clap/\"\nexclude = [\"examples/*\", \"clap-test/*\", \"tests/*\", \"benches/*\", \"*.png\", \"clap-perf/*\", \"*.dot\"]\nhomepage = \"https://clap.rs/\"\nkeywords = [\"argument\", \"cli\", \"arg\", \"parser\", \"parse\"]\nlicense = \"MIT\"\nname = \"clap\"\nreadme = \"README.md\"\nrepository = \"https://github.com/clap-rs/clap\"\nversion = \"2.33.3\"\n[package.metadata.docs.rs]\nfeatures = [\"doc\"]\n";
+    meta = {
+      description = "A simple to use, efficient, and full-featured Command Line Argument Parser";
+      license = [ lib.licenses.mit ];
+      homepage = "https://clap.rs/";
+    };
     features = builtins.concatLists [
       [ "ansi_term" ]
       [ "atty" ]
@@ -328,6 +528,15 @@ in
     version = "0.2.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "07ab9b64c78c2640c33acfa2384ac5329bd0a82b58e4d7b0bc622fe4e1e4ca65"; };
+    edition = "2015";
+    libName = "colorify";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Nick Sanders <cogciprocate@gmail.com>\"]\ndescription = \"Convenience macros for printing to the terminal in color on non-Windows platforms.\"\ndocumentation = \"http://doc.cogciprocate.com/colorify/colorify/index.html\"\nexclude = [\"target/*\", \"bak/*\"]\nhomepage = \"https://github.com/cogciprocate/colorify\"\nkeywords = [\"color\", \"terminal\", \"ansi\", \"console\", \"format\"]\nlicense = \"MIT\"\nname = \"colorify\"\nreadme = \"README.md\"\nrepository = \"https://github.com/cogciprocate/colorify\"\nversion = \"0.2.3\"\n";
+    meta = {
+      description = "Convenience macros for printing to the terminal in color on non-Windows platforms.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/cogciprocate/colorify";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".commoncrypto."0.2.0" = overridableMkRustCrate (profileName: rec {
@@ -335,6 +544,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d056a8586ba25a1e4d61cb090900e495952c7886786fc55f909ab2f819b69007"; };
+    edition = "2015";
+    libName = "commoncrypto";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Mark Lee\"]\ndescription = \"Idiomatic Rust wrappers for Mac OS X's CommonCrypto library\"\ndocumentation = \"https://docs.rs/commoncrypto\"\nkeywords = [\"crypto\", \"hash\", \"digest\", \"osx\", \"commoncrypto\"]\nlicense = \"MIT\"\nname = \"commoncrypto\"\nrepository = \"https://github.com/malept/rust-commoncrypto\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "Idiomatic Rust wrappers for Mac OS X's CommonCrypto library";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/malept/rust-commoncrypto";
+    };
     dependencies = {
       commoncrypto_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".commoncrypto-sys."0.2.0" { inherit profileName; };
     };
@@ -345,6 +563,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1fed34f46747aa73dfaa578069fd8279d2818ade2b55f38f22a9401c7f4083e2"; };
+    edition = "2015";
+    libName = "commoncrypto_sys";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Mark Lee\"]\ndescription = \"FFI bindings to Mac OS X's CommonCrypto library\"\ndocumentation = \"https://docs.rs/commoncrypto-sys\"\nkeywords = [\"crypto\", \"hash\", \"digest\", \"osx\", \"commoncrypto\"]\nlicense = \"MIT\"\nname = \"commoncrypto-sys\"\nrepository = \"https://github.com/malept/rust-commoncrypto\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "FFI bindings to Mac OS X's CommonCrypto library";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/malept/rust-commoncrypto";
+    };
     dependencies = {
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -355,6 +582,15 @@ in
     version = "0.9.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0a89e2ae426ea83155dccf10c0fa6b1463ef6d5fcb44cee0b224a408fa640a62"; };
+    edition = "2015";
+    libName = "core_foundation";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Servo Project Developers\"]\ncategories = [\"os::macos-apis\"]\ndescription = \"Bindings to Core Foundation for macOS\"\nhomepage = \"https://github.com/servo/core-foundation-rs\"\nkeywords = [\"macos\", \"framework\", \"objc\"]\nlicense = \"MIT / Apache-2.0\"\nname = \"core-foundation\"\nrepository = \"https://github.com/servo/core-foundation-rs\"\nversion = \"0.9.1\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-apple-darwin\"\n";
+    meta = {
+      description = "Bindings to Core Foundation for macOS";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/core-foundation-rs";
+    };
     features = builtins.concatLists [
       [ "mac_os_10_7_support" ]
     ];
@@ -369,6 +605,16 @@ in
     version = "0.8.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ea221b5284a47e40033bf9b66f35f984ec0ea2931eb03505246cd27a963f981b"; };
+    edition = "2015";
+    libName = "core_foundation_sys";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Servo Project Developers\"]\nbuild = \"build.rs\"\ndescription = \"Bindings to Core Foundation for macOS\"\nhomepage = \"https://github.com/servo/core-foundation-rs\"\nlicense = \"MIT / Apache-2.0\"\nname = \"core-foundation-sys\"\nrepository = \"https://github.com/servo/core-foundation-rs\"\nversion = \"0.8.2\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-apple-darwin\"\n";
+    meta = {
+      description = "Bindings to Core Foundation for macOS";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/core-foundation-rs";
+    };
     features = builtins.concatLists [
       [ "mac_os_10_7_support" ]
     ];
@@ -379,6 +625,15 @@ in
     version = "0.31.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "09f977948a46e9edf93eb3dc2d7a8dd4ce3105d36de63300befed37cdf051d4a"; };
+    edition = "2018";
+    libName = "crates_io";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"crates_io\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Helpers for interacting with crates.io\\n\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"crates-io\"\nrepository = \"https://github.com/rust-lang/cargo\"\nversion = \"0.31.1\"\n";
+    meta = {
+      description = "Helpers for interacting with crates.io";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/cargo";
+    };
     dependencies = {
       anyhow = rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.35" { inherit profileName; };
       curl = rustPackages."registry+https://github.com/rust-lang/crates.io-index".curl."0.4.34" { inherit profileName; };
@@ -395,6 +650,16 @@ in
     version = "1.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "81156fece84ab6a9f2afdb109ce3ae577e42b1228441eded99bd77f627953b1a"; };
+    edition = "2015";
+    libName = "crc32fast";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sam Rijs <srijs@airpost.net>\", \"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Fast, SIMD-accelerated CRC32 (IEEE) checksum computation\"\nkeywords = [\"checksum\", \"crc\", \"crc32\", \"simd\", \"fast\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"crc32fast\"\nreadme = \"README.md\"\nrepository = \"https://github.com/srijs/rust-crc32fast\"\nversion = \"1.2.1\"\n";
+    meta = {
+      description = "Fast, SIMD-accelerated CRC32 (IEEE) checksum computation";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/srijs/rust-crc32fast";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -409,6 +674,16 @@ in
     version = "0.7.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c3c7c73a2d1e9fc0886a08b93e98eb643461230d5f1925e4036204d5f2e261a8"; };
+    edition = "2015";
+    libName = "crossbeam_utils";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Crossbeam Project Developers\"]\ncategories = [\"algorithms\", \"concurrency\", \"data-structures\", \"no-std\"]\ndescription = \"Utilities for concurrent programming\"\ndocumentation = \"https://docs.rs/crossbeam-utils\"\nhomepage = \"https://github.com/crossbeam-rs/crossbeam/tree/master/crossbeam-utils\"\nkeywords = [\"scoped\", \"thread\", \"atomic\", \"cache\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"crossbeam-utils\"\nreadme = \"README.md\"\nrepository = \"https://github.com/crossbeam-rs/crossbeam\"\nversion = \"0.7.2\"\n";
+    meta = {
+      description = "Utilities for concurrent programming";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/crossbeam-rs/crossbeam/tree/master/crossbeam-utils";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "lazy_static" ]
@@ -428,6 +703,16 @@ in
     version = "0.8.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "02d96d1e189ef58269ebe5b97953da3274d83a93af647c2ddd6f9dab28cedb8d"; };
+    edition = "2018";
+    libName = "crossbeam_utils";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Crossbeam Project Developers\"]\ncategories = [\"algorithms\", \"concurrency\", \"data-structures\", \"no-std\"]\ndescription = \"Utilities for concurrent programming\"\ndocumentation = \"https://docs.rs/crossbeam-utils\"\nedition = \"2018\"\nhomepage = \"https://github.com/crossbeam-rs/crossbeam/tree/master/crossbeam-utils\"\nkeywords = [\"scoped\", \"thread\", \"atomic\", \"cache\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"crossbeam-utils\"\nreadme = \"README.md\"\nrepository = \"https://github.com/crossbeam-rs/crossbeam\"\nversion = \"0.8.1\"\n";
+    meta = {
+      description = "Utilities for concurrent programming";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/crossbeam-rs/crossbeam/tree/master/crossbeam-utils";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "lazy_static" ]
@@ -447,11 +732,20 @@ in
     version = "0.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8a77162240fd97248d19a564a565eb563a3f592b386e4136fb300909e67dddca"; };
+    edition = "2015";
+    libName = "crypto_hash";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Mark Lee\"]\ndescription = \"A wrapper for OS-level cryptographic hash functions\"\ndocumentation = \"https://docs.rs/crypto-hash\"\nexclude = [\".*.yml\", \"ci/*\"]\nkeywords = [\"crypto\", \"hash\", \"digest\"]\nlicense = \"MIT\"\nname = \"crypto-hash\"\nreadme = \"README.md\"\nrepository = \"https://github.com/malept/crypto-hash\"\nversion = \"0.3.4\"\n";
+    meta = {
+      description = "A wrapper for OS-level cryptographic hash functions";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/malept/crypto-hash";
+    };
     dependencies = {
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "commoncrypto" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".commoncrypto."0.2.0" { inherit profileName; };
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "commoncrypto" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".commoncrypto."0.2.0" { inherit profileName; };
       hex = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hex."0.3.2" { inherit profileName; };
-      ${ if !(hostPlatform.parsed.kernel.name == "windows" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "openssl" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl."0.10.31" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
+      ${ if !(hostPlatform.isMacOS || hostPlatform.isWindows || hostPlatform.isiOS) then "openssl" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl."0.10.31" { inherit profileName; };
+      ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
   
@@ -460,6 +754,16 @@ in
     version = "0.4.34";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e268162af1a5fe89917ae25ba3b0a77c8da752bdc58e7dbb4f15b91fbd33756e"; };
+    edition = "2015";
+    libName = "curl";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[example]]\nname = \"ssl_proxy\"\npath = \"examples/ssl_proxy.rs\"\nrequired-features = [\"ssl\"]\n\n[[test]]\nharness = false\nname = \"atexit\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\nautotests = true\ncategories = [\"api-bindings\", \"web-programming::http-client\"]\ndescription = \"Rust bindings to libcurl for making HTTP requests\"\ndocumentation = \"https://docs.rs/curl\"\nhomepage = \"https://github.com/alexcrichton/curl-rust\"\nlicense = \"MIT\"\nname = \"curl\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/curl-rust\"\nversion = \"0.4.34\"\n";
+    meta = {
+      description = "Rust bindings to libcurl for making HTTP requests";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/alexcrichton/curl-rust";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "http2" ]
@@ -470,8 +774,8 @@ in
     dependencies = {
       curl_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".curl-sys."0.4.39+curl-7.74.0" { inherit profileName; };
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.isUnix && !(hostPlatform.parsed.kernel.name == "darwin") then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
-      ${ if hostPlatform.isUnix && !(hostPlatform.parsed.kernel.name == "darwin") then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
+      ${ if !hostPlatform.isMacOS && hostPlatform.isUnix then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
+      ${ if !hostPlatform.isMacOS && hostPlatform.isUnix then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
       ${ if hostPlatform.parsed.abi.name == "msvc" then "schannel" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".schannel."0.1.19" { inherit profileName; };
       socket2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".socket2."0.3.17" { inherit profileName; };
       ${ if hostPlatform.parsed.abi.name == "msvc" then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
@@ -483,6 +787,17 @@ in
     version = "0.4.39+curl-7.74.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "07a8ce861e7b68a0b394e814d7ee9f1b2750ff8bd10372c6ad3bacc10e86f874"; };
+    edition = "2015";
+    libName = "curl_sys";
+    libCrateTypes = [ "lib" ];
+    links = "curl";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"curl_sys\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\nbuild = \"build.rs\"\ncategories = [\"external-ffi-bindings\"]\ndescription = \"Native bindings to the libcurl library\"\ndocumentation = \"https://docs.rs/curl-sys\"\nlicense = \"MIT\"\nlinks = \"curl\"\nname = \"curl-sys\"\nrepository = \"https://github.com/alexcrichton/curl-rust\"\nversion = \"0.4.39+curl-7.74.0\"\n";
+    meta = {
+      description = "Native bindings to the libcurl library";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/alexcrichton/curl-rust";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "http2" ]
@@ -494,7 +809,7 @@ in
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       libnghttp2_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libnghttp2-sys."0.1.5+1.42.0" { inherit profileName; };
       libz_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libz-sys."1.1.2" { inherit profileName; };
-      ${ if hostPlatform.isUnix && !(hostPlatform.parsed.kernel.name == "darwin") then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
+      ${ if !hostPlatform.isMacOS && hostPlatform.isUnix then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
     buildDependencies = {
@@ -509,6 +824,15 @@ in
     version = "0.8.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f3d0c8c8752312f9713efd397ff63acb9f85585afbf179282e720e7704954dd5"; };
+    edition = "2015";
+    libName = "digest";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"RustCrypto Developers\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"Traits for cryptographic hash functions\"\ndocumentation = \"https://docs.rs/digest\"\nkeywords = [\"digest\", \"crypto\", \"hash\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"digest\"\nrepository = \"https://github.com/RustCrypto/traits\"\nversion = \"0.8.1\"\n[package.metadata.docs.rs]\nfeatures = [\"std\"]\n";
+    meta = {
+      description = "Traits for cryptographic hash functions";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/traits";
+    };
     dependencies = {
       generic_array = rustPackages."registry+https://github.com/rust-lang/crates.io-index".generic-array."0.12.3" { inherit profileName; };
     };
@@ -519,6 +843,15 @@ in
     version = "0.7.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "44533bbbb3bb3c1fa17d9f2e4e38bbbaf8396ba82193c4cb1b6445d711445d36"; };
+    edition = "2018";
+    libName = "env_logger";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"regexp_filter\"\n\n[[test]]\nharness = false\nname = \"log-in-log\"\n\n[[test]]\nharness = false\nname = \"log_tls_dtors\"\n\n[[test]]\nharness = false\nname = \"init-twice-retains-filter\"\n\n[package]\nauthors = [\"The Rust Project Developers\"]\ncategories = [\"development-tools::debugging\"]\ndescription = \"A logging implementation for `log` which is configured via an environment\\nvariable.\\n\"\ndocumentation = \"https://docs.rs/env_logger\"\nedition = \"2018\"\nkeywords = [\"logging\", \"log\", \"logger\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"env_logger\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sebasmagri/env_logger/\"\nversion = \"0.7.1\"\n";
+    meta = {
+      description = "A logging implementation for `log` which is configured via an environment\nvariable.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sebasmagri/env_logger/";
+    };
     features = builtins.concatLists [
       [ "atty" ]
       [ "default" ]
@@ -540,6 +873,15 @@ in
     version = "0.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e88a8acf291dafb59c2d96e8f59828f3838bb1a70398823ade51a84de6a6deed"; };
+    edition = "2015";
+    libName = "fake_simd";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust-Crypto Project Developers\"]\ndescription = \"Crate for mimicking simd crate on stable Rust\"\ndocumentation = \"https://docs.rs/fake-simd\"\nkeywords = [\"simd\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"fake-simd\"\nrepository = \"https://github.com/RustCrypto/utils\"\nversion = \"0.1.2\"\n";
+    meta = {
+      description = "Crate for mimicking simd crate on stable Rust";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/utils";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".filetime."0.2.13" = overridableMkRustCrate (profileName: rec {
@@ -547,10 +889,19 @@ in
     version = "0.2.13";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0c122a393ea57648015bf06fbd3d372378992e86b9ff5a7a497b076a28c79efe"; };
+    edition = "2018";
+    libName = "filetime";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Platform-agnostic accessors of timestamps in File metadata\\n\"\ndocumentation = \"https://docs.rs/filetime\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/filetime\"\nkeywords = [\"timestamp\", \"mtime\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"filetime\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/filetime\"\nversion = \"0.2.13\"\n";
+    meta = {
+      description = "Platform-agnostic accessors of timestamps in File metadata";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/filetime";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -560,6 +911,15 @@ in
     version = "1.0.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7411863d55df97a419aa64cb4d2f167103ea9d767e2c54a1868b7ac3f6b47129"; };
+    edition = "2018";
+    libName = "flate2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Josh Triplett <josh@joshtriplett.org>\"]\ncategories = [\"compression\", \"api-bindings\"]\ndescription = \"DEFLATE compression and decompression exposed as Read/BufRead/Write streams.\\nSupports miniz_oxide, miniz.c, and multiple zlib implementations. Supports\\nzlib, gzip, and raw deflate streams.\\n\"\ndocumentation = \"https://docs.rs/flate2\"\nedition = \"2018\"\nhomepage = \"https://github.com/rust-lang/flate2-rs\"\nkeywords = [\"gzip\", \"deflate\", \"zlib\", \"zlib-ng\", \"encoding\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"flate2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/flate2-rs\"\nversion = \"1.0.19\"\n";
+    meta = {
+      description = "DEFLATE compression and decompression exposed as Read/BufRead/Write streams.\nSupports miniz_oxide, miniz.c, and multiple zlib implementations. Supports\nzlib, gzip, and raw deflate streams.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/flate2-rs";
+    };
     features = builtins.concatLists [
       [ "any_zlib" ]
       [ "libz-sys" ]
@@ -579,6 +939,15 @@ in
     version = "1.0.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3f9eec918d3f24069decb9af1554cad7c880e2da24a9afd88aca000531ab82c1"; };
+    edition = "2015";
+    libName = "fnv";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"fnv\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Fowler–Noll–Vo hash function\"\ndocumentation = \"https://doc.servo.org/fnv/\"\nlicense = \"Apache-2.0 / MIT\"\nname = \"fnv\"\nreadme = \"README.md\"\nrepository = \"https://github.com/servo/rust-fnv\"\nversion = \"1.0.7\"\n";
+    meta = {
+      description = "Fowler–Noll–Vo hash function";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/servo/rust-fnv";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -590,6 +959,15 @@ in
     version = "0.3.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f6f339eb8adc052cd2ca78910fda869aefa38d22d5cb648e6485e4d3fc06f3b1"; };
+    edition = "2015";
+    libName = "foreign_types";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"A framework for Rust wrappers over C APIs\"\nlicense = \"MIT/Apache-2.0\"\nname = \"foreign-types\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/foreign-types\"\nversion = \"0.3.2\"\n";
+    meta = {
+      description = "A framework for Rust wrappers over C APIs";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/foreign-types";
+    };
     dependencies = {
       foreign_types_shared = rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types-shared."0.1.1" { inherit profileName; };
     };
@@ -600,6 +978,15 @@ in
     version = "0.1.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "00b0228411908ca8685dba7fc2cdd70ec9990a6e753e89b6ac91a84c40fbaf4b"; };
+    edition = "2015";
+    libName = "foreign_types_shared";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"An internal crate used by foreign-types\"\nlicense = \"MIT/Apache-2.0\"\nname = \"foreign-types-shared\"\nrepository = \"https://github.com/sfackler/foreign-types\"\nversion = \"0.1.1\"\n";
+    meta = {
+      description = "An internal crate used by foreign-types";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/foreign-types";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.0.0" = overridableMkRustCrate (profileName: rec {
@@ -607,6 +994,15 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ece68d15c92e84fa4f19d3780f1294e5ca82a78a6d515f1efaabcc144688be00"; };
+    edition = "2015";
+    libName = "form_urlencoded";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\ndescription = \"Parser and serializer for the application/x-www-form-urlencoded syntax, as used by HTML forms.\"\nlicense = \"MIT/Apache-2.0\"\nname = \"form_urlencoded\"\nrepository = \"https://github.com/servo/rust-url\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "Parser and serializer for the application/x-www-form-urlencoded syntax, as used by HTML forms.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url";
+    };
     dependencies = {
       matches = rustPackages."registry+https://github.com/rust-lang/crates.io-index".matches."0.1.8" { inherit profileName; };
       percent_encoding = rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.1.0" { inherit profileName; };
@@ -618,6 +1014,15 @@ in
     version = "1.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "08c1f5787fe85505d1f7777268db5103d80a7a374d2316a7ce262e57baf8f208"; };
+    edition = "2015";
+    libName = "fwdansi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"kennytm <kennytm@gmail.com>\"]\ncategories = [\"command-line-interface\"]\ndescription = \"Forwards a byte string with ANSI escape code to a termcolor terminal\"\nkeywords = [\"ansi\", \"windows\", \"console\", \"terminal\", \"color\"]\nlicense = \"MIT\"\nname = \"fwdansi\"\nrepository = \"https://github.com/kennytm/fwdansi\"\nversion = \"1.1.0\"\n";
+    meta = {
+      description = "Forwards a byte string with ANSI escape code to a termcolor terminal";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/kennytm/fwdansi";
+    };
     dependencies = {
       memchr = rustPackages."registry+https://github.com/rust-lang/crates.io-index".memchr."2.3.4" { inherit profileName; };
       termcolor = rustPackages."registry+https://github.com/rust-lang/crates.io-index".termcolor."1.1.2" { inherit profileName; };
@@ -629,6 +1034,15 @@ in
     version = "0.12.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c68f0274ae0e023facc3c97b2e00f076be70e254bc851d972503b328db79b2ec"; };
+    edition = "2015";
+    libName = "generic_array";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"generic_array\"\n\n[package]\nauthors = [\"Bartłomiej Kamiński <fizyk20@gmail.com>\", \"Aaron Trent <novacrazy@gmail.com>\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"Generic types implementing functionality of arrays\"\ndocumentation = \"http://fizyk20.github.io/generic-array/generic_array/\"\nkeywords = [\"generic\", \"array\"]\nlicense = \"MIT\"\nname = \"generic-array\"\nreadme = \"README.md\"\nrepository = \"https://github.com/fizyk20/generic-array.git\"\nversion = \"0.12.3\"\n";
+    meta = {
+      description = "Generic types implementing functionality of arrays";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/fizyk20/generic-array.git";
+    };
     dependencies = {
       typenum = rustPackages."registry+https://github.com/rust-lang/crates.io-index".typenum."1.12.0" { inherit profileName; };
     };
@@ -639,13 +1053,23 @@ in
     version = "0.1.15";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fc587bc0ec293155d5bfa6b9891ec18a1e330c234f896ea47fbada4cadbe47e6"; };
+    edition = "2018";
+    libName = "getrandom";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\"]\ncategories = [\"os\", \"no-std\"]\ndescription = \"A small cross-platform library for retrieving random data from system source\"\ndocumentation = \"https://docs.rs/getrandom\"\nedition = \"2018\"\nexclude = [\"utils/*\", \".*\", \"appveyor.yml\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"getrandom\"\nrepository = \"https://github.com/rust-random/getrandom\"\nversion = \"0.1.15\"\n";
+    meta = {
+      description = "A small cross-platform library for retrieving random data from system source";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-random/getrandom";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "wasi" then "wasi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.9.0+wasi-snapshot-preview1" { inherit profileName; };
+      ${ if hostPlatform.isWasi then "wasi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.9.0+wasi-snapshot-preview1" { inherit profileName; };
     };
   });
   
@@ -654,6 +1078,15 @@ in
     version = "0.13.12";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ca6f1a0238d7f8f8fd5ee642f4ebac4dbc03e03d1f78fbe7a3ede35dcf7e2224"; };
+    edition = "2018";
+    libName = "git2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Josh Triplett <josh@joshtriplett.org>\", \"Alex Crichton <alex@alexcrichton.com>\"]\ncategories = [\"api-bindings\"]\ndescription = \"Bindings to libgit2 for interoperating with git repositories. This library is\\nboth threadsafe and memory safe and allows both reading and writing git\\nrepositories.\\n\"\ndocumentation = \"https://docs.rs/git2\"\nedition = \"2018\"\nkeywords = [\"git\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"git2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/git2-rs\"\nversion = \"0.13.12\"\n";
+    meta = {
+      description = "Bindings to libgit2 for interoperating with git repositories. This library is\nboth threadsafe and memory safe and allows both reading and writing git\nrepositories.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/git2-rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "https" ]
@@ -667,8 +1100,8 @@ in
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       libgit2_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libgit2-sys."0.12.14+1.1.0" { inherit profileName; };
       log = rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.11" { inherit profileName; };
-      ${ if hostPlatform.isUnix && !(hostPlatform.parsed.kernel.name == "darwin") then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
-      ${ if hostPlatform.isUnix && !(hostPlatform.parsed.kernel.name == "darwin") then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
+      ${ if !hostPlatform.isMacOS && hostPlatform.isUnix then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
+      ${ if !hostPlatform.isMacOS && hostPlatform.isUnix then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
       url = rustPackages."registry+https://github.com/rust-lang/crates.io-index".url."2.2.0" { inherit profileName; };
     };
   });
@@ -678,6 +1111,15 @@ in
     version = "0.14.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "883539cb0ea94bab3f8371a98cd8e937bbe9ee7c044499184aa4c17deb643a50"; };
+    edition = "2018";
+    libName = "git2_curl";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"all\"\n\n[package]\nauthors = [\"Josh Triplett <josh@joshtriplett.org>\", \"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Backend for an HTTP transport in libgit2 powered by libcurl.\\n\\nIntended to be used with the git2 crate.\\n\"\ndocumentation = \"https://docs.rs/git2-curl\"\nedition = \"2018\"\nlicense = \"MIT/Apache-2.0\"\nname = \"git2-curl\"\nrepository = \"https://github.com/rust-lang/git2-rs\"\nversion = \"0.14.1\"\n";
+    meta = {
+      description = "Backend for an HTTP transport in libgit2 powered by libcurl.\n\nIntended to be used with the git2 crate.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/git2-rs";
+    };
     dependencies = {
       curl = rustPackages."registry+https://github.com/rust-lang/crates.io-index".curl."0.4.34" { inherit profileName; };
       git2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".git2."0.13.12" { inherit profileName; };
@@ -691,6 +1133,15 @@ in
     version = "0.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9b919933a397b79c37e33b77bb2aa3dc8eb6e165ad809e58ff75bc7db2e34574"; };
+    edition = "2015";
+    libName = "glob";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\ncategories = [\"filesystem\"]\ndescription = \"Support for matching file paths against Unix shell style patterns.\\n\"\ndocumentation = \"https://docs.rs/glob/0.3.0\"\nhomepage = \"https://github.com/rust-lang/glob\"\nlicense = \"MIT/Apache-2.0\"\nname = \"glob\"\nrepository = \"https://github.com/rust-lang/glob\"\nversion = \"0.3.0\"\n";
+    meta = {
+      description = "Support for matching file paths against Unix shell style patterns.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/glob";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".globset."0.4.6" = overridableMkRustCrate (profileName: rec {
@@ -698,6 +1149,15 @@ in
     version = "0.4.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c152169ef1e421390738366d2f796655fec62621dabbd0fd476f905934061e4a"; };
+    edition = "2015";
+    libName = "globset";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"globset\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ndescription = \"Cross platform single glob and glob set matching. Glob set matching is the\\nprocess of matching one or more glob patterns against a single candidate path\\nsimultaneously, and returning all of the globs that matched.\\n\"\ndocumentation = \"https://docs.rs/globset\"\nhomepage = \"https://github.com/BurntSushi/ripgrep/tree/master/crates/globset\"\nkeywords = [\"regex\", \"glob\", \"multiple\", \"set\", \"pattern\"]\nlicense = \"Unlicense/MIT\"\nname = \"globset\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/ripgrep/tree/master/crates/globset\"\nversion = \"0.4.6\"\n";
+    meta = {
+      description = "Cross platform single glob and glob set matching. Glob set matching is the\nprocess of matching one or more glob patterns against a single candidate path\nsimultaneously, and returning all of the globs that matched.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/ripgrep/tree/master/crates/globset";
+    };
     dependencies = {
       aho_corasick = rustPackages."registry+https://github.com/rust-lang/crates.io-index".aho-corasick."0.7.15" { inherit profileName; };
       bstr = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bstr."0.2.14" { inherit profileName; };
@@ -712,6 +1172,15 @@ in
     version = "0.8.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "93e3af942408868f6934a7b85134a3230832b9977cf66125df2f9edcfce4ddcc"; };
+    edition = "2015";
+    libName = "globwalk";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Gilad Naaman <gilad@naaman.io>\"]\ncategories = [\"filesystem\"]\ndescription = \"Glob-matched recursive file system walking.\"\nkeywords = [\"regex\", \"glob\", \"pattern\", \"walk\", \"iterator\"]\nlicense = \"MIT\"\nname = \"globwalk\"\nreadme = \"README.md\"\nrepository = \"https://github.com/gilnaa/globwalk\"\nversion = \"0.8.1\"\n";
+    meta = {
+      description = "Glob-matched recursive file system walking.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/gilnaa/globwalk";
+    };
     dependencies = {
       bitflags = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" { inherit profileName; };
       ignore = rustPackages."registry+https://github.com/rust-lang/crates.io-index".ignore."0.4.17" { inherit profileName; };
@@ -724,6 +1193,15 @@ in
     version = "0.1.17";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5aca5565f760fb5b220e499d72710ed156fdb74e631659e99377d9ebfbd13ae8"; };
+    edition = "2018";
+    libName = "hermit_abi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Stefan Lankes\"]\ncategories = [\"os\"]\ndescription = \"hermit-abi is small interface to call functions from the unikernel RustyHermit.\\nIt is used to build the target `x86_64-unknown-hermit`.\\n\"\ndocumentation = \"https://hermitcore.github.io/rusty-hermit/hermit_abi\"\nedition = \"2018\"\nkeywords = [\"unikernel\", \"libos\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"hermit-abi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hermitcore/libhermit-rs\"\nversion = \"0.1.17\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-unknown-hermit\"\nfeatures = [\"docs\"]\n";
+    meta = {
+      description = "hermit-abi is small interface to call functions from the unikernel RustyHermit.\nIt is used to build the target `x86_64-unknown-hermit`.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/hermitcore/libhermit-rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -737,6 +1215,15 @@ in
     version = "0.3.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "805026a5d0141ffc30abb3be3173848ad46a1b1664fe632428479619a3644d77"; };
+    edition = "2015";
+    libName = "hex";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"KokaKiwi <kokakiwi@kokakiwi.net>\"]\ndescription = \"Encoding and decoding data into/from hexadecimal representation.\"\ndocumentation = \"https://docs.rs/hex/\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"hex\"\nrepository = \"https://github.com/KokaKiwi/rust-hex\"\nversion = \"0.3.2\"\n";
+    meta = {
+      description = "Encoding and decoding data into/from hexadecimal representation.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/KokaKiwi/rust-hex";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".hex."0.4.2" = overridableMkRustCrate (profileName: rec {
@@ -744,6 +1231,15 @@ in
     version = "0.4.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "644f9158b2f133fd50f5fb3242878846d9eb792e445c893805ff0e3824006e35"; };
+    edition = "2018";
+    libName = "hex";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"KokaKiwi <kokakiwi@kokakiwi.net>\"]\ncategories = [\"encoding\", \"no-std\"]\ndescription = \"Encoding and decoding data into/from hexadecimal representation.\"\ndocumentation = \"https://docs.rs/hex/\"\nedition = \"2018\"\nkeywords = [\"no_std\", \"hex\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"hex\"\nreadme = \"README.md\"\nrepository = \"https://github.com/KokaKiwi/rust-hex\"\nversion = \"0.4.2\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Encoding and decoding data into/from hexadecimal representation.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/KokaKiwi/rust-hex";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -755,6 +1251,15 @@ in
     version = "0.5.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2456aef2e6b6a9784192ae780c0f15bc57df0e918585282325e8c8ac27737654"; };
+    edition = "2018";
+    libName = "home";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Brian Anderson <andersrb@gmail.com>\"]\ndescription = \"Shared definitions of home directories\"\ndocumentation = \"https://docs.rs/home\"\nedition = \"2018\"\ninclude = [\"/src\", \"/Cargo.toml\", \"/CHANGELOG\", \"/LICENSE-*\", \"/README.md\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"home\"\nreadme = \"README.md\"\nrepository = \"https://github.com/brson/home\"\nversion = \"0.5.3\"\n";
+    meta = {
+      description = "Shared definitions of home directories";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/brson/home";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -765,6 +1270,15 @@ in
     version = "1.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "df004cfca50ef23c36850aaaa59ad52cc70d0e90243c3c7737a4dd32dc7a3c4f"; };
+    edition = "2015";
+    libName = "humantime";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"humantime\"\npath = \"src/lib.rs\"\n\n[package]\nauthors = [\"Paul Colomiets <paul@colomiets.name>\"]\ncategories = [\"date-and-time\"]\ndescription = \"    A parser and formatter for std::time::{Duration, SystemTime}\\n\"\ndocumentation = \"https://docs.rs/humantime\"\nhomepage = \"https://github.com/tailhook/humantime\"\nkeywords = [\"time\", \"human\", \"human-friendly\", \"parser\", \"duration\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"humantime\"\nreadme = \"README.md\"\nversion = \"1.3.0\"\n";
+    meta = {
+      description = "A parser and formatter for std::time::{Duration, SystemTime}";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/tailhook/humantime";
+    };
     dependencies = {
       quick_error = rustPackages."registry+https://github.com/rust-lang/crates.io-index".quick-error."1.2.3" { inherit profileName; };
     };
@@ -775,6 +1289,15 @@ in
     version = "2.0.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3c1ad908cc71012b7bea4d0c53ba96a8cba9962f048fa68d143376143d863b7a"; };
+    edition = "2018";
+    libName = "humantime";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"humantime\"\npath = \"src/lib.rs\"\n\n[package]\nauthors = [\"Paul Colomiets <paul@colomiets.name>\"]\ncategories = [\"date-and-time\"]\ndescription = \"    A parser and formatter for std::time::{Duration, SystemTime}\\n\"\ndocumentation = \"https://docs.rs/humantime\"\nedition = \"2018\"\nhomepage = \"https://github.com/tailhook/humantime\"\nkeywords = [\"time\", \"human\", \"human-friendly\", \"parser\", \"duration\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"humantime\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tailhook/humantime\"\nversion = \"2.0.1\"\n";
+    meta = {
+      description = "A parser and formatter for std::time::{Duration, SystemTime}";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/tailhook/humantime";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".idna."0.2.0" = overridableMkRustCrate (profileName: rec {
@@ -782,6 +1305,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "02e2673c30ee86b5b96a9cb52ad15718aa1f966f5ab9ad54a8b95d5ca33120a9"; };
+    edition = "2015";
+    libName = "idna";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"tests\"\n\n[[test]]\nname = \"unit\"\n\n[lib]\ndoctest = false\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\nautotests = false\ndescription = \"IDNA (Internationalizing Domain Names in Applications) and Punycode.\"\nlicense = \"MIT/Apache-2.0\"\nname = \"idna\"\nrepository = \"https://github.com/servo/rust-url/\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "IDNA (Internationalizing Domain Names in Applications) and Punycode.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url/";
+    };
     dependencies = {
       matches = rustPackages."registry+https://github.com/rust-lang/crates.io-index".matches."0.1.8" { inherit profileName; };
       unicode_bidi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-bidi."0.3.4" { inherit profileName; };
@@ -794,6 +1326,15 @@ in
     version = "0.4.17";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b287fb45c60bb826a0dc68ff08742b9d88a2fea13d6e0c286b3172065aaf878c"; };
+    edition = "2015";
+    libName = "ignore";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"ignore\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ndescription = \"A fast library for efficiently matching ignore files such as `.gitignore`\\nagainst file paths.\\n\"\ndocumentation = \"https://docs.rs/ignore\"\nhomepage = \"https://github.com/BurntSushi/ripgrep/tree/master/crates/ignore\"\nkeywords = [\"glob\", \"ignore\", \"gitignore\", \"pattern\", \"file\"]\nlicense = \"Unlicense/MIT\"\nname = \"ignore\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/ripgrep/tree/master/crates/ignore\"\nversion = \"0.4.17\"\n";
+    meta = {
+      description = "A fast library for efficiently matching ignore files such as `.gitignore`\nagainst file paths.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/ripgrep/tree/master/crates/ignore";
+    };
     dependencies = {
       crossbeam_utils = rustPackages."registry+https://github.com/rust-lang/crates.io-index".crossbeam-utils."0.8.1" { inherit profileName; };
       globset = rustPackages."registry+https://github.com/rust-lang/crates.io-index".globset."0.4.6" { inherit profileName; };
@@ -813,6 +1354,16 @@ in
     version = "15.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3ca8957e71f04a205cb162508f9326aea04676c8dfd0711220190d6b83664f3f"; };
+    edition = "2018";
+    libName = "im_rc";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\npath = \"./src/lib.rs\"\n\n[package]\nauthors = [\"Bodil Stokke <bodil@bodil.org>\"]\nbuild = \"./build.rs\"\ncategories = [\"data-structures\"]\ndescription = \"Immutable collection datatypes (the fast but not thread safe version)\"\ndocumentation = \"http://immutable.rs/\"\nedition = \"2018\"\nhomepage = \"http://immutable.rs/\"\nkeywords = [\"immutable\", \"persistent\", \"hamt\", \"b-tree\", \"rrb-tree\"]\nlicense = \"MPL-2.0+\"\nname = \"im-rc\"\nreadme = \"../../README.md\"\nrepository = \"https://github.com/bodil/im-rs\"\nversion = \"15.0.0\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Immutable collection datatypes (the fast but not thread safe version)";
+      license = "MPL-2.0+";
+      homepage = "http://immutable.rs/";
+    };
     dependencies = {
       bitmaps = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitmaps."2.1.0" { inherit profileName; };
       rand_core = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_core."0.5.1" { inherit profileName; };
@@ -830,6 +1381,15 @@ in
     version = "0.4.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dc6f3ad7b9d11a0c00842ff8de1b60ee58661048eb8049ed33c73594f359d7e6"; };
+    edition = "2015";
+    libName = "itoa";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"value-formatting\"]\ndescription = \"Fast functions for printing integer primitives to an io::Write\"\ndocumentation = \"https://github.com/dtolnay/itoa\"\nexclude = [\"performance.png\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"itoa\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/itoa\"\nversion = \"0.4.6\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Fast functions for printing integer primitives to an io::Write";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/itoa";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".jobserver."0.1.21" = overridableMkRustCrate (profileName: rec {
@@ -837,6 +1397,15 @@ in
     version = "0.1.21";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5c71313ebb9439f74b00d9d2dcec36440beaf57a6aa0623068441dd7cd81a7f2"; };
+    edition = "2018";
+    libName = "jobserver";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"client\"\npath = \"tests/client.rs\"\n\n[[test]]\nname = \"server\"\npath = \"tests/server.rs\"\n\n[[test]]\nharness = false\nname = \"client-of-myself\"\npath = \"tests/client-of-myself.rs\"\n\n[[test]]\nharness = false\nname = \"make-as-a-client\"\npath = \"tests/make-as-a-client.rs\"\n\n[[test]]\nname = \"helper\"\npath = \"tests/helper.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"An implementation of the GNU make jobserver for Rust\\n\"\ndocumentation = \"https://docs.rs/jobserver\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/jobserver-rs\"\nlicense = \"MIT/Apache-2.0\"\nname = \"jobserver\"\nrepository = \"https://github.com/alexcrichton/jobserver-rs\"\nversion = \"0.1.21\"\n";
+    meta = {
+      description = "An implementation of the GNU make jobserver for Rust";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/jobserver-rs";
+    };
     dependencies = {
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -847,6 +1416,15 @@ in
     version = "1.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e2abad23fbc42b3700f2f279844dc832adb2b2eb069b2df918f455c4e18cc646"; };
+    edition = "2015";
+    libName = "lazy_static";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Marvin Löbel <loebel.marvin@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\", \"memory-management\"]\ndescription = \"A macro for declaring lazily evaluated statics in Rust.\"\ndocumentation = \"https://docs.rs/lazy_static\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nkeywords = [\"macro\", \"lazy\", \"static\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"lazy_static\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang-nursery/lazy-static.rs\"\nversion = \"1.4.0\"\n";
+    meta = {
+      description = "A macro for declaring lazily evaluated statics in Rust.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang-nursery/lazy-static.rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".lazycell."1.3.0" = overridableMkRustCrate (profileName: rec {
@@ -854,6 +1432,15 @@ in
     version = "1.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "830d08ce1d1d941e6b30645f1a0eb5643013d835ce3779a5fc208261dbe10f55"; };
+    edition = "2015";
+    libName = "lazycell";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Nikita Pekin <contact@nikitapek.in>\"]\ndescription = \"A library providing a lazily filled Cell struct\"\ndocumentation = \"http://indiv0.github.io/lazycell/lazycell/\"\ninclude = [\"CHANGELOG.md\", \"Cargo.toml\", \"LICENSE-MIT\", \"LICENSE-APACHE\", \"README.md\", \"src/**/*.rs\"]\nkeywords = [\"lazycell\", \"lazy\", \"cell\", \"library\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"lazycell\"\nreadme = \"README.md\"\nrepository = \"https://github.com/indiv0/lazycell\"\nversion = \"1.3.0\"\n";
+    meta = {
+      description = "A library providing a lazily filled Cell struct";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/indiv0/lazycell";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" = overridableMkRustCrate (profileName: rec {
@@ -861,6 +1448,16 @@ in
     version = "0.2.81";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1482821306169ec4d07f6aca392a4681f66c75c9918aa49641a2595db64053cb"; };
+    edition = "2015";
+    libName = "libc";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"external-ffi-bindings\", \"no-std\", \"os\"]\ndescription = \"Raw FFI bindings to platform libraries like libc.\\n\"\ndocumentation = \"https://docs.rs/libc/\"\nexclude = [\"/ci/*\", \"/.github/*\", \"/.cirrus.yml\", \"/triagebot.toml\"]\nhomepage = \"https://github.com/rust-lang/libc\"\nkeywords = [\"libc\", \"ffi\", \"bindings\", \"operating\", \"system\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"libc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/libc\"\nversion = \"0.2.81\"\n";
+    meta = {
+      description = "Raw FFI bindings to platform libraries like libc.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/libc";
+    };
     features = builtins.concatLists [
       [ "align" ]
       [ "default" ]
@@ -873,6 +1470,17 @@ in
     version = "0.12.14+1.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8f25af58e6495f7caf2919d08f212de550cfa3ed2f5e744988938ea292b9f549"; };
+    edition = "2018";
+    libName = "libgit2_sys";
+    libCrateTypes = [ "lib" ];
+    links = "git2";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"libgit2_sys\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Josh Triplett <josh@joshtriplett.org>\", \"Alex Crichton <alex@alexcrichton.com>\"]\nbuild = \"build.rs\"\ndescription = \"Native bindings to the libgit2 library\"\nedition = \"2018\"\nexclude = [\"libgit2/tests/*\"]\nlicense = \"MIT/Apache-2.0\"\nlinks = \"git2\"\nname = \"libgit2-sys\"\nrepository = \"https://github.com/rust-lang/git2-rs\"\nversion = \"0.12.14+1.1.0\"\n";
+    meta = {
+      description = "Native bindings to the libgit2 library";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/git2-rs";
+    };
     features = builtins.concatLists [
       [ "https" ]
       [ "libssh2-sys" ]
@@ -897,6 +1505,17 @@ in
     version = "0.1.5+1.42.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9657455ff47889b70ffd37c3e118e8cdd23fd1f9f3293a285f141070621c4c79"; };
+    edition = "2015";
+    libName = "libnghttp2_sys";
+    libCrateTypes = [ "lib" ];
+    links = "nghttp2";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\ndoctest = false\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"FFI bindings for libnghttp2 (nghttp2)\\n\"\nhomepage = \"https://github.com/alexcrichton/nghttp2-rs\"\nlicense = \"MIT/Apache-2.0\"\nlinks = \"nghttp2\"\nname = \"libnghttp2-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/nghttp2-rs\"\nversion = \"0.1.5+1.42.0\"\n";
+    meta = {
+      description = "FFI bindings for libnghttp2 (nghttp2)";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/nghttp2-rs";
+    };
     dependencies = {
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -910,6 +1529,17 @@ in
     version = "0.2.20";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "df40b13fe7ea1be9b9dffa365a51273816c345fc1811478b57ed7d964fbfc4ce"; };
+    edition = "2015";
+    libName = "libssh2_sys";
+    libCrateTypes = [ "lib" ];
+    links = "ssh2";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"libssh2_sys\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Wez Furlong <wez@wezfurlong.org>\", \"Matteo Bigoi <bigo@crisidev.org>\"]\nbuild = \"build.rs\"\ndescription = \"Native bindings to the libssh2 library\"\ndocumentation = \"https://docs.rs/libssh2-sys\"\nlicense = \"MIT/Apache-2.0\"\nlinks = \"ssh2\"\nname = \"libssh2-sys\"\nrepository = \"https://github.com/alexcrichton/ssh2-rs\"\nversion = \"0.2.20\"\n";
+    meta = {
+      description = "Native bindings to the libssh2 library";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/ssh2-rs";
+    };
     dependencies = {
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       libz_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libz-sys."1.1.2" { inherit profileName; };
@@ -927,6 +1557,17 @@ in
     version = "1.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "602113192b08db8f38796c4e85c39e960c145965140e918018bcde1952429655"; };
+    edition = "2015";
+    libName = "libz_sys";
+    libCrateTypes = [ "lib" ];
+    links = "z";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Josh Triplett <josh@joshtriplett.org>\"]\nbuild = \"build.rs\"\ncategories = [\"compression\", \"external-ffi-bindings\"]\ndescription = \"Low-level bindings to the system libz library (also known as zlib).\\n\"\ndocumentation = \"https://docs.rs/libz-sys\"\nkeywords = [\"zlib\", \"zlib-ng\"]\nlicense = \"MIT OR Apache-2.0\"\nlinks = \"z\"\nname = \"libz-sys\"\nrepository = \"https://github.com/rust-lang/libz-sys\"\nversion = \"1.1.2\"\n";
+    meta = {
+      description = "Low-level bindings to the system libz library (also known as zlib).";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/libz-sys";
+    };
     features = builtins.concatLists [
       [ "libc" ]
     ];
@@ -945,6 +1586,16 @@ in
     version = "0.4.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4fabed175da42fed1fa0746b0ea71f412aa9d35e76e95e59b192c64b9dc2bf8b"; };
+    edition = "2015";
+    libName = "log";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"filters\"\n\n[[test]]\nharness = true\nname = \"macros\"\n\n[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"development-tools::debugging\"]\ndescription = \"A lightweight logging facade for Rust\\n\"\ndocumentation = \"https://docs.rs/log\"\nexclude = [\"rfcs/**/*\", \"/.travis.yml\", \"/appveyor.yml\"]\nkeywords = [\"logging\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"log\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/log\"\nversion = \"0.4.11\"\n[package.metadata.docs.rs]\nfeatures = [\"std\", \"serde\", \"kv_unstable_sval\"]\n";
+    meta = {
+      description = "A lightweight logging facade for Rust";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/log";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
@@ -958,6 +1609,15 @@ in
     version = "1.0.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3e2e65a1a2e43cfcb47a895c4c8b10d1f4a61097f9f254f183aee60cad9c651d"; };
+    edition = "2015";
+    libName = "maplit";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"bluss\"]\ncategories = [\"rust-patterns\"]\ndescription = \"Collection “literal” macros for HashMap, HashSet, BTreeMap, and BTreeSet.\"\ndocumentation = \"https://docs.rs/maplit/\"\nkeywords = [\"literal\", \"data-structure\", \"hashmap\", \"macro\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"maplit\"\nrepository = \"https://github.com/bluss/maplit\"\nversion = \"1.0.2\"\n[package.metadata.release]\nno-dev-version = true\n";
+    meta = {
+      description = "Collection “literal” macros for HashMap, HashSet, BTreeMap, and BTreeSet.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bluss/maplit";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".matches."0.1.8" = overridableMkRustCrate (profileName: rec {
@@ -965,6 +1625,15 @@ in
     version = "0.1.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7ffc5c5338469d4d3ea17d269fa8ea3512ad247247c30bd2df69e68309ed0a08"; };
+    edition = "2015";
+    libName = "matches";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"matches\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Simon Sapin <simon.sapin@exyr.org>\"]\ndescription = \"A macro to evaluate, as a boolean, whether an expression matches a pattern.\"\ndocumentation = \"https://docs.rs/matches/\"\nlicense = \"MIT\"\nname = \"matches\"\nrepository = \"https://github.com/SimonSapin/rust-std-candidates\"\nversion = \"0.1.8\"\n";
+    meta = {
+      description = "A macro to evaluate, as a boolean, whether an expression matches a pattern.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/SimonSapin/rust-std-candidates";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".memchr."2.3.4" = overridableMkRustCrate (profileName: rec {
@@ -972,6 +1641,16 @@ in
     version = "2.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0ee1c47aaa256ecabcaea351eae4a9b01ef39ed810004e298d2511ed284b1525"; };
+    edition = "2015";
+    libName = "memchr";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"memchr\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\", \"bluss\"]\ndescription = \"Safe interface to memchr.\"\ndocumentation = \"https://docs.rs/memchr/\"\nexclude = [\"/ci/*\", \"/.travis.yml\", \"/Makefile\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/BurntSushi/rust-memchr\"\nkeywords = [\"memchr\", \"char\", \"scan\", \"strchr\", \"string\"]\nlicense = \"Unlicense/MIT\"\nname = \"memchr\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/rust-memchr\"\nversion = \"2.3.4\"\n";
+    meta = {
+      description = "Safe interface to memchr.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/rust-memchr";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -984,6 +1663,16 @@ in
     version = "0.4.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0f2d26ec3309788e423cfbf68ad1800f061638098d76a83681af979dc4eda19d"; };
+    edition = "2018";
+    libName = "miniz_oxide";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"miniz_oxide\"\n\n[package]\nauthors = [\"Frommi <daniil.liferenko@gmail.com>\", \"oyvindln <oyvindln@users.noreply.github.com>\"]\nbuild = \"build.rs\"\ncategories = [\"compression\"]\ndescription = \"DEFLATE compression and decompression library rewritten in Rust based on miniz\"\ndocumentation = \"https://docs.rs/miniz_oxide\"\nedition = \"2018\"\nexclude = [\"benches/*\", \"tests/*\"]\nhomepage = \"https://github.com/Frommi/miniz_oxide/tree/master/miniz_oxide\"\nkeywords = [\"zlib\", \"miniz\", \"deflate\", \"encoding\"]\nlicense = \"MIT OR Zlib OR Apache-2.0\"\nname = \"miniz_oxide\"\nreadme = \"Readme.md\"\nrepository = \"https://github.com/Frommi/miniz_oxide/tree/master/miniz_oxide\"\nversion = \"0.4.3\"\n";
+    meta = {
+      description = "DEFLATE compression and decompression library rewritten in Rust based on miniz";
+      license = [ lib.licenses.mit lib.licenses.zlib lib.licenses.asl20 ];
+      homepage = "https://github.com/Frommi/miniz_oxide/tree/master/miniz_oxide";
+    };
     dependencies = {
       adler = rustPackages."registry+https://github.com/rust-lang/crates.io-index".adler."0.2.3" { inherit profileName; };
     };
@@ -997,6 +1686,15 @@ in
     version = "0.3.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5a33c1b55807fbed163481b5ba66db4b2fa6cde694a5027be10fb724206c5897"; };
+    edition = "2018";
+    libName = "miow";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A zero overhead I/O library for Windows, focusing on IOCP and Async I/O\\nabstractions.\\n\"\ndocumentation = \"https://docs.rs/miow/0.3/x86_64-pc-windows-msvc/miow/\"\nedition = \"2018\"\nhomepage = \"https://github.com/yoshuawuyts/miow\"\nkeywords = [\"iocp\", \"windows\", \"io\", \"overlapped\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"miow\"\nreadme = \"README.md\"\nrepository = \"https://github.com/yoshuawuyts/miow\"\nversion = \"0.3.6\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-pc-windows-msvc\"\ntargets = [\"aarch64-pc-windows-msvc\", \"i686-pc-windows-msvc\", \"x86_64-pc-windows-msvc\"]\n";
+    meta = {
+      description = "A zero overhead I/O library for Windows, focusing on IOCP and Async I/O\nabstractions.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/yoshuawuyts/miow";
+    };
     dependencies = {
       socket2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".socket2."0.3.17" { inherit profileName; };
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
@@ -1008,8 +1706,17 @@ in
     version = "1.13.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "05499f3756671c15885fee9034446956fff3f243d6077b91e5767df161f766b3"; };
+    edition = "2015";
+    libName = "num_cpus";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"hardware-support\"]\ndescription = \"Get the number of CPUs on a machine.\"\ndocumentation = \"https://docs.rs/num_cpus\"\nkeywords = [\"cpu\", \"cpus\", \"cores\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"num_cpus\"\nreadme = \"README.md\"\nrepository = \"https://github.com/seanmonstar/num_cpus\"\nversion = \"1.13.0\"\n";
+    meta = {
+      description = "Get the number of CPUs on a machine.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/seanmonstar/num_cpus";
+    };
     dependencies = {
-      ${ if (hostPlatform.parsed.cpu.name == "x86_64" || hostPlatform.parsed.cpu.name == "aarch64") && hostPlatform.parsed.kernel.name == "hermit" then "hermit_abi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.1.17" { inherit profileName; };
+      ${ if hostPlatform.parsed.kernel.name == "hermit" && (hostPlatform.isAarch64 || hostPlatform.parsed.cpu.name == "x86_64") then "hermit_abi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hermit-abi."0.1.17" { inherit profileName; };
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
   });
@@ -1019,6 +1726,15 @@ in
     version = "0.2.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2839e79665f131bdb5782e51f2c6c9599c133c6098982a54c794358bf432529c"; };
+    edition = "2015";
+    libName = "opaque_debug";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"RustCrypto Developers\"]\ndescription = \"Macro for opaque Debug trait implementation\"\ndocumentation = \"https://docs.rs/opaque-debug\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"opaque-debug\"\nrepository = \"https://github.com/RustCrypto/utils\"\nversion = \"0.2.3\"\n";
+    meta = {
+      description = "Macro for opaque Debug trait implementation";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/utils";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".opener."0.4.1" = overridableMkRustCrate (profileName: rec {
@@ -1026,6 +1742,15 @@ in
     version = "0.4.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "13117407ca9d0caf3a0e74f97b490a7e64c0ae3aa90a8b7085544d0c37b6f3ae"; };
+    edition = "2018";
+    libName = "opener";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Brian Bowman <seeker14491@gmail.com>\"]\ndescription = \"Open a file or link using the system default program.\"\nedition = \"2018\"\nkeywords = [\"open\", \"default\", \"launcher\", \"browser\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"opener\"\nreadme = \"../README.md\"\nrepository = \"https://github.com/Seeker14491/opener\"\nversion = \"0.4.1\"\n";
+    meta = {
+      description = "Open a file or link using the system default program.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/Seeker14491/opener";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -1036,6 +1761,16 @@ in
     version = "0.10.31";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8d008f51b1acffa0d3450a68606e6a51c123012edaacb0f4e1426bd978869187"; };
+    edition = "2015";
+    libName = "openssl";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ncategories = [\"cryptography\", \"api-bindings\"]\ndescription = \"OpenSSL bindings\"\nkeywords = [\"crypto\", \"tls\", \"ssl\", \"dtls\"]\nlicense = \"Apache-2.0\"\nname = \"openssl\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/rust-openssl\"\nversion = \"0.10.31\"\n";
+    meta = {
+      description = "OpenSSL bindings";
+      license = [ lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/rust-openssl";
+    };
     dependencies = {
       bitflags = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" { inherit profileName; };
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
@@ -1051,6 +1786,15 @@ in
     version = "0.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "77af24da69f9d9341038eba93a073b1fdaaa1b788221b00a69bce9e762cb32de"; };
+    edition = "2015";
+    libName = "openssl_probe";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Tool for helping to find SSL certificate locations on the system for OpenSSL\\n\"\nhomepage = \"https://github.com/alexcrichton/openssl-probe\"\nlicense = \"MIT/Apache-2.0\"\nname = \"openssl-probe\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/openssl-probe\"\nversion = \"0.1.2\"\n";
+    meta = {
+      description = "Tool for helping to find SSL certificate locations on the system for OpenSSL";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/openssl-probe";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" = overridableMkRustCrate (profileName: rec {
@@ -1058,6 +1802,17 @@ in
     version = "0.9.59";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "de52d8eabd217311538a39bba130d7dea1f1e118010fee7a033d966845e7d5fe"; };
+    edition = "2015";
+    libName = "openssl_sys";
+    libCrateTypes = [ "lib" ];
+    links = "openssl";
+    buildScript = "build/main.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Steven Fackler <sfackler@gmail.com>\"]\nbuild = \"build/main.rs\"\ncategories = [\"cryptography\", \"external-ffi-bindings\"]\ndescription = \"FFI bindings to OpenSSL\"\nlicense = \"MIT\"\nlinks = \"openssl\"\nname = \"openssl-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/rust-openssl\"\nversion = \"0.9.59\"\n[package.metadata.pkg-config]\nopenssl = \"1.0.1\"\n";
+    meta = {
+      description = "FFI bindings to OpenSSL";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/sfackler/rust-openssl";
+    };
     dependencies = {
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -1074,6 +1829,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "877630b3de15c0b64cc52f659345724fbf6bdad9bd9566699fc53688f3c34a34"; };
+    edition = "2015";
+    libName = "pathdiff";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Manish Goregaokar <manishsmail@gmail.com>\"]\ndescription = \"Library for diffing paths to obtain relative paths\"\ndocumentation = \"https://docs.rs/pathdiff/\"\nkeywords = [\"path\", \"relative\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pathdiff\"\nrepository = \"https://github.com/Manishearth/pathdiff\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "Library for diffing paths to obtain relative paths";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/Manishearth/pathdiff";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.1.0" = overridableMkRustCrate (profileName: rec {
@@ -1081,6 +1845,15 @@ in
     version = "2.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d4fd5641d01c8f18a23da7b6fe29298ff4b55afcccdf78973b24cf3175fee32e"; };
+    edition = "2015";
+    libName = "percent_encoding";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\npath = \"lib.rs\"\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\ndescription = \"Percent encoding and decoding\"\nlicense = \"MIT/Apache-2.0\"\nname = \"percent-encoding\"\nrepository = \"https://github.com/servo/rust-url/\"\nversion = \"2.1.0\"\n";
+    meta = {
+      description = "Percent encoding and decoding";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url/";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".pest."2.1.3" = overridableMkRustCrate (profileName: rec {
@@ -1088,6 +1861,15 @@ in
     version = "2.1.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "10f4872ae94d7b90ae48754df22fd42ad52ce740b8f370b03da4835417403e53"; };
+    edition = "2015";
+    libName = "pest";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Dragoș Tiselice <dragostiselice@gmail.com>\"]\ncategories = [\"parsing\"]\ndescription = \"The Elegant Parser\"\ndocumentation = \"https://docs.rs/pest\"\nhomepage = \"https://pest-parser.github.io/\"\nkeywords = [\"pest\", \"parser\", \"peg\", \"grammar\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pest\"\nreadme = \"_README.md\"\nrepository = \"https://github.com/pest-parser/pest\"\nversion = \"2.1.3\"\n";
+    meta = {
+      description = "The Elegant Parser";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://pest-parser.github.io/";
+    };
     dependencies = {
       ucd_trie = rustPackages."registry+https://github.com/rust-lang/crates.io-index".ucd-trie."0.1.3" { inherit profileName; };
     };
@@ -1098,6 +1880,15 @@ in
     version = "2.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "833d1ae558dc601e9a60366421196a8d94bc0ac980476d0b67e1d0988d72b2d0"; };
+    edition = "2015";
+    libName = "pest_derive";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"pest_derive\"\nproc-macro = true\n\n[package]\nauthors = [\"Dragoș Tiselice <dragostiselice@gmail.com>\"]\ncategories = [\"parsing\"]\ndescription = \"pest's derive macro\"\ndocumentation = \"https://docs.rs/pest\"\nhomepage = \"https://pest-parser.github.io/\"\nkeywords = [\"pest\", \"parser\", \"peg\", \"grammar\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pest_derive\"\nreadme = \"_README.md\"\nrepository = \"https://github.com/pest-parser/pest\"\nversion = \"2.1.0\"\n";
+    meta = {
+      description = "pest's derive macro";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://pest-parser.github.io/";
+    };
     dependencies = {
       pest = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pest."2.1.3" { inherit profileName; };
       pest_generator = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pest_generator."2.1.3" { inherit profileName; };
@@ -1109,6 +1900,15 @@ in
     version = "2.1.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "99b8db626e31e5b81787b9783425769681b347011cc59471e33ea46d2ea0cf55"; };
+    edition = "2015";
+    libName = "pest_generator";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Dragoș Tiselice <dragostiselice@gmail.com>\"]\ncategories = [\"parsing\"]\ndescription = \"pest code generator\"\ndocumentation = \"https://docs.rs/pest\"\nhomepage = \"https://pest-parser.github.io/\"\nkeywords = [\"pest\", \"generator\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pest_generator\"\nreadme = \"_README.md\"\nrepository = \"https://github.com/pest-parser/pest\"\nversion = \"2.1.3\"\n";
+    meta = {
+      description = "pest code generator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://pest-parser.github.io/";
+    };
     dependencies = {
       pest = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pest."2.1.3" { inherit profileName; };
       pest_meta = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pest_meta."2.1.3" { inherit profileName; };
@@ -1123,6 +1923,15 @@ in
     version = "2.1.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "54be6e404f5317079812fc8f9f5279de376d8856929e21c184ecf6bbd692a11d"; };
+    edition = "2015";
+    libName = "pest_meta";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Dragoș Tiselice <dragostiselice@gmail.com>\"]\ncategories = [\"parsing\"]\ndescription = \"pest meta language parser and validator\"\ndocumentation = \"https://docs.rs/pest\"\nexclude = [\"src/grammar.pest\"]\nhomepage = \"https://pest-parser.github.io/\"\ninclude = [\"Cargo.toml\", \"src/**/*\", \"src/grammar.rs\", \"_README.md\", \"LICENSE-*\"]\nkeywords = [\"pest\", \"parser\", \"meta\", \"optimizer\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pest_meta\"\nreadme = \"_README.md\"\nrepository = \"https://github.com/pest-parser/pest\"\nversion = \"2.1.3\"\n";
+    meta = {
+      description = "pest meta language parser and validator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://pest-parser.github.io/";
+    };
     dependencies = {
       maplit = rustPackages."registry+https://github.com/rust-lang/crates.io-index".maplit."1.0.2" { inherit profileName; };
       pest = rustPackages."registry+https://github.com/rust-lang/crates.io-index".pest."2.1.3" { inherit profileName; };
@@ -1137,6 +1946,15 @@ in
     version = "0.3.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3831453b3449ceb48b6d9c7ad7c96d5ea673e9b470a1dc578c2ce6521230884c"; };
+    edition = "2015";
+    libName = "pkg_config";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A library to run the pkg-config system tool at build time in order to be used in\\nCargo build scripts.\\n\"\ndocumentation = \"https://docs.rs/pkg-config\"\nkeywords = [\"build-dependencies\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pkg-config\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/pkg-config-rs\"\nversion = \"0.3.19\"\n";
+    meta = {
+      description = "A library to run the pkg-config system tool at build time in order to be used in\nCargo build scripts.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/pkg-config-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".ppv-lite86."0.2.10" = overridableMkRustCrate (profileName: rec {
@@ -1144,6 +1962,15 @@ in
     version = "0.2.10";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ac74c624d6b2d21f425f752262f42188365d7b8ff1aff74c82e45136510a4857"; };
+    edition = "2018";
+    libName = "ppv_lite86";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The CryptoCorrosion Contributors\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"Implementation of the crypto-simd API for x86\"\nedition = \"2018\"\nkeywords = [\"crypto\", \"simd\", \"x86\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"ppv-lite86\"\nrepository = \"https://github.com/cryptocorrosion/cryptocorrosion\"\nversion = \"0.2.10\"\n";
+    meta = {
+      description = "Implementation of the crypto-simd API for x86";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/cryptocorrosion/cryptocorrosion";
+    };
     features = builtins.concatLists [
       [ "simd" ]
       [ "std" ]
@@ -1155,6 +1982,16 @@ in
     version = "1.0.24";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"; };
+    edition = "2018";
+    libName = "proc_macro2";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"A substitute implementation of the compiler's `proc_macro` API to decouple\\ntoken-based libraries from the procedural macro use case.\\n\"\ndocumentation = \"https://docs.rs/proc-macro2\"\nedition = \"2018\"\nkeywords = [\"macros\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"proc-macro2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/proc-macro2\"\nversion = \"1.0.24\"\n[package.metadata.docs.rs]\nrustc-args = [\"--cfg\", \"procmacro2_semver_exempt\"]\nrustdoc-args = [\"--cfg\", \"procmacro2_semver_exempt\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"span-locations\"]\n";
+    meta = {
+      description = "A substitute implementation of the compiler's `proc_macro` API to decouple\ntoken-based libraries from the procedural macro use case.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/proc-macro2";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "proc-macro" ]
@@ -1169,6 +2006,15 @@ in
     version = "1.2.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a1d01941d82fa2ab50be1e79e6714289dd7cde78eba4c074bc5a4374f650dfe0"; };
+    edition = "2015";
+    libName = "quick_error";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Paul Colomiets <paul@colomiets.name>\", \"Colin Kiegel <kiegel@gmx.de>\"]\ncategories = [\"rust-patterns\"]\ndescription = \"    A macro which makes error types pleasant to write.\\n\"\ndocumentation = \"http://docs.rs/quick-error\"\nhomepage = \"http://github.com/tailhook/quick-error\"\nkeywords = [\"macro\", \"error\", \"type\", \"enum\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"quick-error\"\nrepository = \"http://github.com/tailhook/quick-error\"\nversion = \"1.2.3\"\n";
+    meta = {
+      description = "A macro which makes error types pleasant to write.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "http://github.com/tailhook/quick-error";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".quote."1.0.7" = overridableMkRustCrate (profileName: rec {
@@ -1176,6 +2022,15 @@ in
     version = "1.0.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "aa563d17ecb180e500da1cfd2b028310ac758de548efdd203e18f283af693f37"; };
+    edition = "2018";
+    libName = "quote";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"Quasi-quoting macro quote!(...)\"\ndocumentation = \"https://docs.rs/quote/\"\nedition = \"2018\"\ninclude = [\"Cargo.toml\", \"src/**/*.rs\", \"tests/**/*.rs\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"syn\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"quote\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/quote\"\nversion = \"1.0.7\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Quasi-quoting macro quote!(...)";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/quote";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "proc-macro" ]
@@ -1190,6 +2045,15 @@ in
     version = "0.7.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6a6b1679d49b24bbfe0c803429aa1874472f50d9b363131f0e89fc356b544d03"; };
+    edition = "2018";
+    libName = "rand";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\"]\nautobenches = true\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"Random number generators and other randomness functionality.\\n\"\ndocumentation = \"https://rust-random.github.io/rand/\"\nedition = \"2018\"\nexclude = [\"/utils/*\", \"/.travis.yml\", \"/appveyor.yml\", \".gitignore\"]\nhomepage = \"https://crates.io/crates/rand\"\nkeywords = [\"random\", \"rng\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.7.3\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Random number generators and other randomness functionality.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -1212,6 +2076,15 @@ in
     version = "0.2.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f4c8ed856279c9737206bf725bf36935d8666ead7aa69b52be55af369d193402"; };
+    edition = "2018";
+    libName = "rand_chacha";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\", \"The CryptoCorrosion Contributors\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"ChaCha random number generator\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_chacha/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_chacha\"\nkeywords = [\"random\", \"rng\", \"chacha\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand_chacha\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.2.2\"\n";
+    meta = {
+      description = "ChaCha random number generator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_chacha";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
@@ -1226,6 +2099,15 @@ in
     version = "0.5.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "90bde5296fc891b0cef12a6d03ddccc162ce7b2aff54160af9338f8d40df6d19"; };
+    edition = "2018";
+    libName = "rand_core";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"Core random number generator traits and tools for implementation.\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_core/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_core\"\nkeywords = [\"random\", \"rng\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand_core\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.5.1\"\n";
+    meta = {
+      description = "Core random number generator traits and tools for implementation.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_core";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "getrandom" ]
@@ -1241,6 +2123,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ca3129af7b92a17112d59ad498c6f81eaf463253766b90396d39ea7a39d6613c"; };
+    edition = "2018";
+    libName = "rand_hc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"HC128 random number generator\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_hc/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_hc\"\nkeywords = [\"random\", \"rng\", \"hc128\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"rand_hc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "HC128 random number generator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_hc";
+    };
     dependencies = {
       rand_core = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_core."0.5.1" { inherit profileName; };
     };
@@ -1251,6 +2142,15 @@ in
     version = "0.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a9fcdd2e881d02f1d9390ae47ad8e5696a9e4be7b547a1da2afbc61973217004"; };
+    edition = "2018";
+    libName = "rand_xoshiro";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\"]\ncategories = [\"algorithms\"]\ndescription = \"Xoshiro, xoroshiro and splitmix64 random number generators\"\ndocumentation = \"https://docs.rs/rand_xoshiro\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_xoshiro\"\nkeywords = [\"random\", \"rng\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand_xoshiro\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.4.0\"\n";
+    meta = {
+      description = "Xoshiro, xoroshiro and splitmix64 random number generators";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_xoshiro";
+    };
     dependencies = {
       rand_core = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_core."0.5.1" { inherit profileName; };
     };
@@ -1261,6 +2161,15 @@ in
     version = "0.1.57";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "41cc0f7e4d5d4544e8861606a285bb08d3e70712ccc7d2b84d7c0ccfaf4b05ce"; };
+    edition = "2015";
+    libName = "syscall";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"syscall\"\n\n[package]\nauthors = [\"Jeremy Soller <jackpot51@gmail.com>\"]\ndescription = \"A Rust library to access raw Redox system calls\"\ndocumentation = \"https://docs.rs/redox_syscall\"\nlicense = \"MIT\"\nname = \"redox_syscall\"\nrepository = \"https://gitlab.redox-os.org/redox-os/syscall\"\nversion = \"0.1.57\"\n";
+    meta = {
+      description = "A Rust library to access raw Redox system calls";
+      license = [ lib.licenses.mit ];
+      homepage = "https://gitlab.redox-os.org/redox-os/syscall";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".regex."1.4.2" = overridableMkRustCrate (profileName: rec {
@@ -1268,6 +2177,15 @@ in
     version = "1.4.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "38cf2c13ed4745de91a5eb834e11c00bcc3709e773173b2ce4c56c9fbde04b9c"; };
+    edition = "2015";
+    libName = "regex";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nname = \"default\"\npath = \"tests/test_default.rs\"\n\n[[test]]\nname = \"default-bytes\"\npath = \"tests/test_default_bytes.rs\"\n\n[[test]]\nname = \"nfa\"\npath = \"tests/test_nfa.rs\"\n\n[[test]]\nname = \"nfa-utf8bytes\"\npath = \"tests/test_nfa_utf8bytes.rs\"\n\n[[test]]\nname = \"nfa-bytes\"\npath = \"tests/test_nfa_bytes.rs\"\n\n[[test]]\nname = \"backtrack\"\npath = \"tests/test_backtrack.rs\"\n\n[[test]]\nname = \"backtrack-utf8bytes\"\npath = \"tests/test_backtrack_utf8bytes.rs\"\n\n[[test]]\nname = \"backtrack-bytes\"\npath = \"tests/test_backtrack_bytes.rs\"\n\n[[test]]\nname = \"crates-regex\"\npath = \"tests/test_crates_regex.rs\"\n\n[lib]\nbench = false\ndoctest = false\n\n[package]\nauthors = [\"The Rust Project Developers\"]\nautotests = false\ncategories = [\"text-processing\"]\ndescription = \"An implementation of regular expressions for Rust. This implementation uses\\nfinite automata and guarantees linear time matching on all inputs.\\n\"\ndocumentation = \"https://docs.rs/regex\"\nexclude = [\"/scripts/*\", \"/.github/*\"]\nhomepage = \"https://github.com/rust-lang/regex\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"regex\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/regex\"\nversion = \"1.4.2\"\n";
+    meta = {
+      description = "An implementation of regular expressions for Rust. This implementation uses\nfinite automata and guarantees linear time matching on all inputs.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/regex";
+    };
     features = builtins.concatLists [
       [ "aho-corasick" ]
       [ "default" ]
@@ -1301,6 +2219,15 @@ in
     version = "0.6.21";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3b181ba2dcf07aaccad5448e8ead58db5b742cf85dfe035e2227f137a539a189"; };
+    edition = "2015";
+    libName = "regex_syntax";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\ndescription = \"A regular expression parser.\"\ndocumentation = \"https://docs.rs/regex-syntax\"\nhomepage = \"https://github.com/rust-lang/regex\"\nlicense = \"MIT/Apache-2.0\"\nname = \"regex-syntax\"\nrepository = \"https://github.com/rust-lang/regex\"\nversion = \"0.6.21\"\n";
+    meta = {
+      description = "A regular expression parser.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/regex";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "unicode" ]
@@ -1319,6 +2246,15 @@ in
     version = "0.5.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3acd125665422973a33ac9d3dd2df85edad0f4ae9b00dafb1a05e43a9f5ef8e7"; };
+    edition = "2015";
+    libName = "remove_dir_all";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Aaronepower <theaaronepower@gmail.com>\"]\ncategories = [\"filesystem\"]\ndescription = \"A safe, reliable implementation of remove_dir_all for Windows\"\ninclude = [\"Cargo.toml\", \"LICENCE-APACHE\", \"LICENCE-MIT\", \"src/**/*\", \"README.md\"]\nkeywords = [\"utility\", \"filesystem\", \"remove_dir\", \"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"remove_dir_all\"\nreadme = \"README.md\"\nrepository = \"https://github.com/XAMPPRocky/remove_dir_all.git\"\nversion = \"0.5.3\"\n";
+    meta = {
+      description = "A safe, reliable implementation of remove_dir_all for Windows";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/XAMPPRocky/remove_dir_all.git";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -1329,6 +2265,14 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fc71d2faa173b74b232dedc235e3ee1696581bb132fc116fa3626d6151a1a8fb"; };
+    edition = "2015";
+    libName = "rustc_workspace_hack";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Hack for the compiler's own build system\\n\"\nlicense = \"MIT/Apache-2.0\"\nname = \"rustc-workspace-hack\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "Hack for the compiler's own build system";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".rustfix."0.5.1" = overridableMkRustCrate (profileName: rec {
@@ -1336,6 +2280,15 @@ in
     version = "0.5.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f2c50b74badcddeb8f7652fa8323ce440b95286f8e4b64ebfd871c609672704e"; };
+    edition = "2018";
+    libName = "rustfix";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Pascal Hertleif <killercup@gmail.com>\", \"Oliver Schneider <oli-obk@users.noreply.github.com>\"]\ndescription = \"Automatically apply the suggestions made by rustc\"\ndocumentation = \"https://docs.rs/rustfix\"\nedition = \"2018\"\nexclude = [\"etc/*\", \"examples/*\", \"tests/*\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"rustfix\"\nreadme = \"Readme.md\"\nrepository = \"https://github.com/rust-lang-nursery/rustfix\"\nversion = \"0.5.1\"\n";
+    meta = {
+      description = "Automatically apply the suggestions made by rustc";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/rust-lang-nursery/rustfix";
+    };
     dependencies = {
       anyhow = rustPackages."registry+https://github.com/rust-lang/crates.io-index".anyhow."1.0.35" { inherit profileName; };
       log = rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.11" { inherit profileName; };
@@ -1349,6 +2302,16 @@ in
     version = "1.0.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "71d301d4193d031abdd79ff7e3dd721168a9572ef3fe51a1517aba235bd8f86e"; };
+    edition = "2018";
+    libName = "ryu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ndescription = \"Fast floating point to string conversion\"\ndocumentation = \"https://docs.rs/ryu\"\nedition = \"2018\"\nlicense = \"Apache-2.0 OR BSL-1.0\"\nname = \"ryu\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/ryu\"\nversion = \"1.0.5\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Fast floating point to string conversion";
+      license = [ lib.licenses.asl20 lib.licenses.boost ];
+      homepage = "https://github.com/dtolnay/ryu";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".same-file."1.0.6" = overridableMkRustCrate (profileName: rec {
@@ -1356,6 +2319,15 @@ in
     version = "1.0.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "93fc1dc3aaa9bfed95e02e6eadabb4baf7e3078b0bd1b4d7b6b0b68378900502"; };
+    edition = "2018";
+    libName = "same_file";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ndescription = \"A simple crate for determining whether two file paths point to the same file.\\n\"\ndocumentation = \"https://docs.rs/same-file\"\nedition = \"2018\"\nexclude = [\"/.github\"]\nhomepage = \"https://github.com/BurntSushi/same-file\"\nkeywords = [\"same\", \"file\", \"equal\", \"inode\"]\nlicense = \"Unlicense/MIT\"\nname = \"same-file\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/same-file\"\nversion = \"1.0.6\"\n";
+    meta = {
+      description = "A simple crate for determining whether two file paths point to the same file.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/same-file";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi_util" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.5" { inherit profileName; };
     };
@@ -1366,6 +2338,15 @@ in
     version = "0.1.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8f05ba609c234e60bee0d547fe94a4c7e9da733d1c962cf6e59efa4cd9c8bc75"; };
+    edition = "2015";
+    libName = "schannel";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\", \"Steffen Butzer <steffen.butzer@outlook.com>\"]\ndescription = \"Schannel bindings for rust, allowing SSL/TLS (e.g. https) without openssl\"\ndocumentation = \"https://docs.rs/schannel/0/x86_64-pc-windows-gnu/schannel/\"\nkeywords = [\"windows\", \"schannel\", \"tls\", \"ssl\", \"https\"]\nlicense = \"MIT\"\nname = \"schannel\"\nreadme = \"README.md\"\nrepository = \"https://github.com/steffengy/schannel-rs\"\nversion = \"0.1.19\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-pc-windows-msvc\"\n";
+    meta = {
+      description = "Schannel bindings for rust, allowing SSL/TLS (e.g. https) without openssl";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/steffengy/schannel-rs";
+    };
     dependencies = {
       lazy_static = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
@@ -1377,6 +2358,15 @@ in
     version = "0.9.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1d7eb9ef2c18661902cc47e535f9bc51b78acd254da71d375c2f6720d9a40403"; };
+    edition = "2015";
+    libName = "semver";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steve Klabnik <steve@steveklabnik.com>\", \"The Rust Project Developers\"]\ndescription = \"Semantic version parsing and comparison.\\n\"\ndocumentation = \"https://docs.rs/crate/semver/\"\nhomepage = \"https://docs.rs/crate/semver/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"semver\"\nreadme = \"README.md\"\nrepository = \"https://github.com/steveklabnik/semver\"\nversion = \"0.9.0\"\n";
+    meta = {
+      description = "Semantic version parsing and comparison.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://docs.rs/crate/semver/";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1390,6 +2380,15 @@ in
     version = "0.10.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "394cec28fa623e00903caf7ba4fa6fb9a0e260280bb8cdbbba029611108a0190"; };
+    edition = "2015";
+    libName = "semver";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steve Klabnik <steve@steveklabnik.com>\", \"The Rust Project Developers\"]\ncategories = [\"development-tools\", \"parser-implementations\"]\ndescription = \"Semantic version parsing and comparison.\\n\"\ndocumentation = \"https://docs.rs/crate/semver/\"\nhomepage = \"https://docs.rs/crate/semver/\"\nkeywords = [\"version\", \"semantic\", \"compare\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"semver\"\nreadme = \"README.md\"\nrepository = \"https://github.com/steveklabnik/semver\"\nversion = \"0.10.0\"\n";
+    meta = {
+      description = "Semantic version parsing and comparison.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://docs.rs/crate/semver/";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "serde" ]
@@ -1405,6 +2404,15 @@ in
     version = "0.7.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "388a1df253eca08550bef6c72392cfe7c30914bf41df5269b68cbd6ff8f570a3"; };
+    edition = "2015";
+    libName = "semver_parser";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steve Klabnik <steve@steveklabnik.com>\"]\ndescription = \"Parsing of the semver spec.\\n\"\ndocumentation = \"https://docs.rs/semver-parser\"\nhomepage = \"https://github.com/steveklabnik/semver-parser\"\nlicense = \"MIT/Apache-2.0\"\nname = \"semver-parser\"\nrepository = \"https://github.com/steveklabnik/semver-parser\"\nversion = \"0.7.0\"\n";
+    meta = {
+      description = "Parsing of the semver spec.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/steveklabnik/semver-parser";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".serde."1.0.118" = overridableMkRustCrate (profileName: rec {
@@ -1412,6 +2420,16 @@ in
     version = "1.0.118";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "06c64263859d87aa2eb554587e2d23183398d617427327cf2b3d0ed8c69e4800"; };
+    edition = "2015";
+    libName = "serde";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Erick Tryzelaar <erick.tryzelaar@gmail.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"encoding\"]\ndescription = \"A generic serialization/deserialization framework\"\ndocumentation = \"https://docs.serde.rs/serde/\"\nhomepage = \"https://serde.rs\"\ninclude = [\"build.rs\", \"src/**/*.rs\", \"crates-io.md\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"serde\", \"serialization\", \"no_std\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde\"\nreadme = \"crates-io.md\"\nrepository = \"https://github.com/serde-rs/serde\"\nversion = \"1.0.118\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"derive\", \"rc\"]\n";
+    meta = {
+      description = "A generic serialization/deserialization framework";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://serde.rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "derive" ]
@@ -1428,6 +2446,16 @@ in
     version = "1.0.118";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c84d3526699cd55261af4b941e4e725444df67aa4f9e6a3564f18030d12672df"; };
+    edition = "2015";
+    libName = "serde_derive";
+    libCrateTypes = [ "proc-macro" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"serde_derive\"\nproc-macro = true\n\n[package]\nauthors = [\"Erick Tryzelaar <erick.tryzelaar@gmail.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\ndescription = \"Macros 1.1 implementation of #[derive(Serialize, Deserialize)]\"\ndocumentation = \"https://serde.rs/derive.html\"\nhomepage = \"https://serde.rs\"\ninclude = [\"build.rs\", \"src/**/*.rs\", \"crates-io.md\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"serde\", \"serialization\", \"no_std\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde_derive\"\nreadme = \"crates-io.md\"\nrepository = \"https://github.com/serde-rs/serde\"\nversion = \"1.0.118\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Macros 1.1 implementation of #[derive(Serialize, Deserialize)]";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://serde.rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1443,6 +2471,15 @@ in
     version = "0.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1c2c7d39d14f2f2ea82239de71594782f186fd03501ac81f0ce08e674819ff2f"; };
+    edition = "2018";
+    libName = "serde_ignored";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"encoding\"]\ndescription = \"Find out about keys that are ignored when deserializing data\"\nedition = \"2018\"\nkeywords = [\"serde\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde_ignored\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/serde-ignored\"\nversion = \"0.1.2\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Find out about keys that are ignored when deserializing data";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/serde-ignored";
+    };
     dependencies = {
       serde = rustPackages."registry+https://github.com/rust-lang/crates.io-index".serde."1.0.118" { inherit profileName; };
     };
@@ -1453,6 +2490,16 @@ in
     version = "1.0.60";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1500e84d27fe482ed1dc791a56eddc2f230046a040fa908c08bda1d9fb615779"; };
+    edition = "2018";
+    libName = "serde_json";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Erick Tryzelaar <erick.tryzelaar@gmail.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"encoding\"]\ndescription = \"A JSON serialization file format\"\ndocumentation = \"https://docs.serde.rs/serde_json/\"\nedition = \"2018\"\ninclude = [\"build.rs\", \"src/**/*.rs\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"json\", \"serde\", \"serialization\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde_json\"\nreadme = \"README.md\"\nrepository = \"https://github.com/serde-rs/json\"\nversion = \"1.0.60\"\n[package.metadata.docs.rs]\nfeatures = [\"raw_value\", \"unbounded_depth\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"raw_value\"]\n";
+    meta = {
+      description = "A JSON serialization file format";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/serde-rs/json";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "raw_value" ]
@@ -1470,6 +2517,15 @@ in
     version = "0.8.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f7d94d0bede923b3cea61f3f1ff57ff8cdfd77b400fb8f9998949e0cf04163df"; };
+    edition = "2015";
+    libName = "sha1";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"sha1\"\n\n[package]\nauthors = [\"RustCrypto Developers\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"SHA-1 hash function\"\ndocumentation = \"https://docs.rs/sha-1\"\nkeywords = [\"crypto\", \"sha1\", \"hash\", \"digest\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"sha-1\"\nrepository = \"https://github.com/RustCrypto/hashes\"\nversion = \"0.8.2\"\n";
+    meta = {
+      description = "SHA-1 hash function";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/RustCrypto/hashes";
+    };
     dependencies = {
       block_buffer = rustPackages."registry+https://github.com/rust-lang/crates.io-index".block-buffer."0.7.3" { inherit profileName; };
       digest = rustPackages."registry+https://github.com/rust-lang/crates.io-index".digest."0.8.1" { inherit profileName; };
@@ -1483,6 +2539,15 @@ in
     version = "0.1.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "45bb67a18fa91266cc7807181f62f9178a6873bfad7dc788c42e6430db40184f"; };
+    edition = "2015";
+    libName = "shell_escape";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"Escape characters that may have a special meaning in a shell\"\nlicense = \"MIT/Apache-2.0\"\nname = \"shell-escape\"\nrepository = \"https://github.com/sfackler/shell-escape\"\nversion = \"0.1.5\"\n";
+    meta = {
+      description = "Escape characters that may have a special meaning in a shell";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/shell-escape";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".sized-chunks."0.6.2" = overridableMkRustCrate (profileName: rec {
@@ -1490,6 +2555,15 @@ in
     version = "0.6.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1ec31ceca5644fa6d444cc77548b88b67f46db6f7c71683b0f9336e671830d2f"; };
+    edition = "2018";
+    libName = "sized_chunks";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Bodil Stokke <bodil@bodil.org>\"]\ncategories = [\"data-structures\"]\ndescription = \"Efficient sized chunk datatypes\"\ndocumentation = \"http://docs.rs/sized-chunks\"\nedition = \"2018\"\nexclude = [\"release.toml\", \"proptest-regressions/**\"]\nkeywords = [\"sparse-array\"]\nlicense = \"MPL-2.0+\"\nname = \"sized-chunks\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/bodil/sized-chunks\"\nversion = \"0.6.2\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Efficient sized chunk datatypes";
+      license = "MPL-2.0+";
+      homepage = "https://github.com/bodil/sized-chunks";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1505,10 +2579,19 @@ in
     version = "0.3.17";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2c29947abdee2a218277abeca306f25789c938e500ea5a9d4b12a5a504466902"; };
-    dependencies = {
-      ${ if hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox" then "cfg_if" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
-      ${ if hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox" then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
+    edition = "2018";
+    libName = "socket2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Utilities for handling networking sockets with a maximal amount of configuration\\npossible intended.\\n\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/socket2-rs\"\nlicense = \"MIT/Apache-2.0\"\nname = \"socket2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/socket2-rs\"\nversion = \"0.3.17\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Utilities for handling networking sockets with a maximal amount of configuration\npossible intended.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/socket2-rs";
+    };
+    dependencies = {
+      ${ if hostPlatform.isRedox || hostPlatform.isUnix then "cfg_if" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
+      ${ if hostPlatform.isRedox || hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -1518,6 +2601,15 @@ in
     version = "0.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9d63676e2abafa709460982ddc02a3bb586b6d15a49b75c212e06edd3933acee"; };
+    edition = "2015";
+    libName = "strip_ansi_escapes";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Ted Mielczarek <ted@mielczarek.org>\"]\ndescription = \"Strip ANSI escape sequences from byte streams.\"\ndocumentation = \"https://docs.rs/strip-ansi-escapes\"\nhomepage = \"https://github.com/luser/strip-ansi-escapes\"\nkeywords = [\"ansi\", \"escape\", \"terminal\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"strip-ansi-escapes\"\nreadme = \"README.md\"\nrepository = \"https://github.com/luser/strip-ansi-escapes\"\nversion = \"0.1.0\"\n";
+    meta = {
+      description = "Strip ANSI escape sequences from byte streams.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/luser/strip-ansi-escapes";
+    };
     dependencies = {
       vte = rustPackages."registry+https://github.com/rust-lang/crates.io-index".vte."0.3.3" { inherit profileName; };
     };
@@ -1528,6 +2620,15 @@ in
     version = "0.8.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8ea5119cdb4c55b55d432abb513a0429384878c15dde60cc77b1c99de1a95a6a"; };
+    edition = "2015";
+    libName = "strsim";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Danny Guo <dannyguo91@gmail.com>\"]\ndescription = \"Implementations of string similarity metrics.\\nIncludes Hamming, Levenshtein, OSA, Damerau-Levenshtein, Jaro, and Jaro-Winkler.\\n\"\ndocumentation = \"https://docs.rs/strsim/\"\nhomepage = \"https://github.com/dguo/strsim-rs\"\nkeywords = [\"string\", \"similarity\", \"Hamming\", \"Levenshtein\", \"Jaro\"]\nlicense = \"MIT\"\nname = \"strsim\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dguo/strsim-rs\"\nversion = \"0.8.0\"\n";
+    meta = {
+      description = "Implementations of string similarity metrics.\nIncludes Hamming, Levenshtein, OSA, Damerau-Levenshtein, Jaro, and Jaro-Winkler.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/dguo/strsim-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".syn."1.0.54" = overridableMkRustCrate (profileName: rec {
@@ -1535,6 +2636,16 @@ in
     version = "1.0.54";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9a2af957a63d6bd42255c359c93d9bfdb97076bd3b820897ce55ffbfbf107f44"; };
+    edition = "2018";
+    libName = "syn";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"Parser for Rust source code\"\ndocumentation = \"https://docs.rs/syn\"\nedition = \"2018\"\ninclude = [\"/benches/**\", \"/build.rs\", \"/Cargo.toml\", \"/LICENSE-APACHE\", \"/LICENSE-MIT\", \"/README.md\", \"/src/**\", \"/tests/**\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"syn\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/syn\"\nversion = \"1.0.54\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"doc_cfg\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"full\", \"visit\", \"visit-mut\", \"fold\", \"extra-traits\"]\n";
+    meta = {
+      description = "Parser for Rust source code";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/syn";
+    };
     features = builtins.concatLists [
       [ "clone-impls" ]
       [ "default" ]
@@ -1557,10 +2668,19 @@ in
     version = "0.4.30";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "489997b7557e9a43e192c527face4feacc78bfbe6eed67fd55c4c9e381cba290"; };
+    edition = "2018";
+    libName = "tar";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A Rust implementation of a TAR file reader and writer. This library does not\\ncurrently handle compression, but it is abstract over all I/O readers and\\nwriters. Additionally, great lengths are taken to ensure that the entire\\ncontents are never required to be entirely resident in memory all at once.\\n\"\ndocumentation = \"https://docs.rs/tar\"\nedition = \"2018\"\nexclude = [\"tests/archives/*\"]\nhomepage = \"https://github.com/alexcrichton/tar-rs\"\nkeywords = [\"tar\", \"tarfile\", \"encoding\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"tar\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/tar-rs\"\nversion = \"0.4.30\"\n";
+    meta = {
+      description = "A Rust implementation of a TAR file reader and writer. This library does not\ncurrently handle compression, but it is abstract over all I/O readers and\nwriters. Additionally, great lengths are taken to ensure that the entire\ncontents are never required to be entirely resident in memory all at once.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/tar-rs";
+    };
     dependencies = {
       filetime = rustPackages."registry+https://github.com/rust-lang/crates.io-index".filetime."0.2.13" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
     };
   });
   
@@ -1569,12 +2689,21 @@ in
     version = "3.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7a6e24d9338a0a5be79593e2fa15a648add6138caa803e2d5bc782c371732ca9"; };
+    edition = "2018";
+    libName = "tempfile";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Allen <steven@stebalien.com>\", \"The Rust Project Developers\", \"Ashley Mannix <ashleymannix@live.com.au>\", \"Jason White <jasonaw0@gmail.com>\"]\ndescription = \"A library for managing temporary files and directories.\"\ndocumentation = \"https://docs.rs/tempfile\"\nedition = \"2018\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"http://stebalien.com/projects/tempfile-rs\"\nkeywords = [\"tempfile\", \"tmpfile\", \"filesystem\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"tempfile\"\nrepository = \"https://github.com/Stebalien/tempfile\"\nversion = \"3.1.0\"\n";
+    meta = {
+      description = "A library for managing temporary files and directories.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "http://stebalien.com/projects/tempfile-rs";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       rand = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.7.3" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       remove_dir_all = rustPackages."registry+https://github.com/rust-lang/crates.io-index".remove_dir_all."0.5.3" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -1584,6 +2713,15 @@ in
     version = "1.5.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1381c83828bedd5ce4e59473110afa5381ffe523406d9ade4b77c9f7be70ff9a"; };
+    edition = "2018";
+    libName = "tera";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Vincent Prouillet <hello@prouilletvincent.com>\"]\ncategories = [\"template-engine\"]\ndescription = \"Template engine based on Jinja2/Django templates\"\nedition = \"2018\"\nhomepage = \"https://tera.netlify.com/\"\ninclude = [\"src/**/*\", \"LICENSE\", \"README.md\", \"CHANGELOG.md\", \"!**/tests/*\"]\nkeywords = [\"template\", \"html\", \"django\", \"markup\", \"jinja2\"]\nlicense = \"MIT\"\nname = \"tera\"\nreadme = \"README.md\"\nrepository = \"https://github.com/Keats/tera\"\nversion = \"1.5.0\"\n";
+    meta = {
+      description = "Template engine based on Jinja2/Django templates";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tera.netlify.com/";
+    };
     dependencies = {
       globwalk = rustPackages."registry+https://github.com/rust-lang/crates.io-index".globwalk."0.8.1" { inherit profileName; };
       lazy_static = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
@@ -1600,6 +2738,15 @@ in
     version = "1.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2dfed899f0eb03f32ee8c6a0aabdb8a7949659e3466561fc0adf54e26d88c5f4"; };
+    edition = "2018";
+    libName = "termcolor";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"termcolor\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ndescription = \"A simple cross platform library for writing colored text to a terminal.\\n\"\ndocumentation = \"https://docs.rs/termcolor\"\nedition = \"2018\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\", \"/ci/**\"]\nhomepage = \"https://github.com/BurntSushi/termcolor\"\nkeywords = [\"windows\", \"win\", \"color\", \"ansi\", \"console\"]\nlicense = \"Unlicense OR MIT\"\nname = \"termcolor\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/termcolor\"\nversion = \"1.1.2\"\n";
+    meta = {
+      description = "A simple cross platform library for writing colored text to a terminal.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/termcolor";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi_util" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.5" { inherit profileName; };
     };
@@ -1610,6 +2757,15 @@ in
     version = "0.11.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d326610f408c7a4eb6f51c37c330e496b08506c9457c9d34287ecc38809fb060"; };
+    edition = "2015";
+    libName = "textwrap";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Martin Geisler <martin@geisler.net>\"]\ncategories = [\"text-processing\", \"command-line-interface\"]\ndescription = \"Textwrap is a small library for word wrapping, indenting, and\\ndedenting strings.\\n\\nYou can use it to format strings (such as help and error messages) for\\ndisplay in commandline applications. It is designed to be efficient\\nand handle Unicode characters correctly.\\n\"\ndocumentation = \"https://docs.rs/textwrap/\"\nexclude = [\".dir-locals.el\"]\nkeywords = [\"text\", \"formatting\", \"wrap\", \"typesetting\", \"hyphenation\"]\nlicense = \"MIT\"\nname = \"textwrap\"\nreadme = \"README.md\"\nrepository = \"https://github.com/mgeisler/textwrap\"\nversion = \"0.11.0\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Textwrap is a small library for word wrapping, indenting, and\ndedenting strings.\n\nYou can use it to format strings (such as help and error messages) for\ndisplay in commandline applications. It is designed to be efficient\nand handle Unicode characters correctly.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/mgeisler/textwrap";
+    };
     dependencies = {
       unicode_width = rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-width."0.1.8" { inherit profileName; };
     };
@@ -1620,6 +2776,15 @@ in
     version = "1.0.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d40c6d1b69745a6ec6fb1ca717914848da4b44ae29d9b3080cbee91d72a69b14"; };
+    edition = "2015";
+    libName = "thread_local";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Amanieu d'Antras <amanieu@gmail.com>\"]\ndescription = \"Per-object thread-local storage\"\ndocumentation = \"https://amanieu.github.io/thread_local-rs/thread_local/index.html\"\nkeywords = [\"thread_local\", \"concurrent\", \"thread\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"thread_local\"\nreadme = \"README.md\"\nrepository = \"https://github.com/Amanieu/thread_local-rs\"\nversion = \"1.0.1\"\n";
+    meta = {
+      description = "Per-object thread-local storage";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/Amanieu/thread_local-rs";
+    };
     dependencies = {
       lazy_static = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
     };
@@ -1630,6 +2795,15 @@ in
     version = "1.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ccf8dbc19eb42fba10e8feaaec282fb50e2c14b2726d6301dbfeed0f73306a6f"; };
+    edition = "2018";
+    libName = "tinyvec";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nname = \"tinyvec\"\nrequired-features = [\"alloc\"]\n\n[package]\nauthors = [\"Lokathor <zefria@gmail.com>\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"`tinyvec` provides 100% safe vec-like data structures.\"\nedition = \"2018\"\nkeywords = [\"vec\", \"no_std\", \"no-std\"]\nlicense = \"Zlib OR Apache-2.0 OR MIT\"\nname = \"tinyvec\"\nrepository = \"https://github.com/Lokathor/tinyvec\"\nversion = \"1.1.0\"\n[package.metadata.docs.rs]\nfeatures = [\"alloc\", \"grab_spare_slice\", \"rustc_1_40\", \"serde\"]\nrustdoc-args = [\"--cfg\", \"docs_rs\"]\n\n[package.metadata.playground]\nfeatures = [\"alloc\", \"grab_spare_slice\", \"rustc_1_40\", \"serde\"]\n";
+    meta = {
+      description = "`tinyvec` provides 100% safe vec-like data structures.";
+      license = [ lib.licenses.zlib lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/Lokathor/tinyvec";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -1645,6 +2819,15 @@ in
     version = "0.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cda74da7e1a664f795bb1f8a87ec406fb89a02522cf6e50620d016add6dbbf5c"; };
+    edition = "2018";
+    libName = "tinyvec_macros";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Soveu <marx.tomasz@gmail.com>\"]\ndescription = \"Some macros for tiny containers\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0 OR Zlib\"\nname = \"tinyvec_macros\"\nrepository = \"https://github.com/Soveu/tinyvec_macros\"\nversion = \"0.1.0\"\n";
+    meta = {
+      description = "Some macros for tiny containers";
+      license = [ lib.licenses.mit lib.licenses.asl20 lib.licenses.zlib ];
+      homepage = "https://github.com/Soveu/tinyvec_macros";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".toml."0.5.7" = overridableMkRustCrate (profileName: rec {
@@ -1652,6 +2835,15 @@ in
     version = "0.5.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "75cf45bb0bef80604d001caaec0d09da99611b3c0fd39d3080468875cdb65645"; };
+    edition = "2018";
+    libName = "toml";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ncategories = [\"config\", \"encoding\", \"parser-implementations\"]\ndescription = \"A native Rust encoder and decoder of TOML-formatted files and streams. Provides\\nimplementations of the standard Serialize/Deserialize traits for TOML data to\\nfacilitate deserializing and serializing Rust structures.\\n\"\ndocumentation = \"https://docs.rs/toml\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/toml-rs\"\nkeywords = [\"encoding\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"toml\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/toml-rs\"\nversion = \"0.5.7\"\n";
+    meta = {
+      description = "A native Rust encoder and decoder of TOML-formatted files and streams. Provides\nimplementations of the standard Serialize/Deserialize traits for TOML data to\nfacilitate deserializing and serializing Rust structures.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/toml-rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1665,6 +2857,16 @@ in
     version = "1.12.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "373c8a200f9e67a0c95e62a4f52fbf80c23b4381c05a17845531982fa99e6b33"; };
+    edition = "2015";
+    libName = "typenum";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build/main.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"typenum\"\n\n[package]\nauthors = [\"Paho Lurie-Gregg <paho@paholg.com>\", \"Andre Bogus <bogusandre@gmail.com>\"]\nbuild = \"build/main.rs\"\ncategories = [\"no-std\"]\ndescription = \"Typenum is a Rust library for type-level numbers evaluated at compile time. It currently supports bits, unsigned integers, and signed integers. It also provides a type-level array of type-level numbers, but its implementation is incomplete.\"\ndocumentation = \"https://docs.rs/typenum\"\nlicense = \"MIT/Apache-2.0\"\nname = \"typenum\"\nreadme = \"README.md\"\nrepository = \"https://github.com/paholg/typenum\"\nversion = \"1.12.0\"\n";
+    meta = {
+      description = "Typenum is a Rust library for type-level numbers evaluated at compile time. It currently supports bits, unsigned integers, and signed integers. It also provides a type-level array of type-level numbers, but its implementation is incomplete.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/paholg/typenum";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".ucd-trie."0.1.3" = overridableMkRustCrate (profileName: rec {
@@ -1672,6 +2874,15 @@ in
     version = "0.1.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "56dee185309b50d1f11bfedef0fe6d036842e3fb77413abef29f8f8d1c5d4c1c"; };
+    edition = "2018";
+    libName = "ucd_trie";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ndescription = \"A trie for storing Unicode codepoint sets and maps.\\n\"\ndocumentation = \"https://docs.rs/ucd-trie\"\nedition = \"2018\"\nhomepage = \"https://github.com/BurntSushi/ucd-generate\"\nkeywords = [\"unicode\", \"database\", \"character\", \"codepoint\", \"trie\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"ucd-trie\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/ucd-generate\"\nversion = \"0.1.3\"\n";
+    meta = {
+      description = "A trie for storing Unicode codepoint sets and maps.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/BurntSushi/ucd-generate";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1683,6 +2894,15 @@ in
     version = "0.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "49f2bd0c6468a8230e1db229cff8029217cf623c767ea5d60bfbd42729ea54d5"; };
+    edition = "2015";
+    libName = "unicode_bidi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"unicode_bidi\"\n\n[package]\nauthors = [\"The Servo Project Developers\"]\ndescription = \"Implementation of the Unicode Bidirectional Algorithm\"\ndocumentation = \"http://doc.servo.org/unicode_bidi/\"\nexclude = [\"benches/**\", \"data/**\", \"examples/**\", \"tests/**\", \"tools/**\"]\nkeywords = [\"rtl\", \"unicode\", \"text\", \"layout\", \"bidi\"]\nlicense = \"MIT / Apache-2.0\"\nname = \"unicode-bidi\"\nrepository = \"https://github.com/servo/unicode-bidi\"\nversion = \"0.3.4\"\n";
+    meta = {
+      description = "Implementation of the Unicode Bidirectional Algorithm";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/unicode-bidi";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1696,6 +2916,15 @@ in
     version = "0.1.16";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a13e63ab62dbe32aeee58d1c5408d35c36c392bba5d9d3142287219721afe606"; };
+    edition = "2018";
+    libName = "unicode_normalization";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"kwantam <kwantam@gmail.com>\", \"Manish Goregaokar <manishsmail@gmail.com>\"]\ndescription = \"This crate provides functions for normalization of\\nUnicode strings, including Canonical and Compatible\\nDecomposition and Recomposition, as described in\\nUnicode Standard Annex #15.\\n\"\ndocumentation = \"https://docs.rs/unicode-normalization/\"\nedition = \"2018\"\nexclude = [\"target/*\", \"Cargo.lock\", \"scripts/tmp\", \"*.txt\", \"tests/*\"]\nhomepage = \"https://github.com/unicode-rs/unicode-normalization\"\nkeywords = [\"text\", \"unicode\", \"normalization\", \"decomposition\", \"recomposition\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"unicode-normalization\"\nreadme = \"README.md\"\nrepository = \"https://github.com/unicode-rs/unicode-normalization\"\nversion = \"0.1.16\"\n";
+    meta = {
+      description = "This crate provides functions for normalization of\nUnicode strings, including Canonical and Compatible\nDecomposition and Recomposition, as described in\nUnicode Standard Annex #15.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/unicode-rs/unicode-normalization";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1710,6 +2939,15 @@ in
     version = "0.1.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9337591893a19b88d8d87f2cec1e73fad5cdfd10e5a6f349f498ad6ea2ffb1e3"; };
+    edition = "2015";
+    libName = "unicode_width";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"kwantam <kwantam@gmail.com>\", \"Manish Goregaokar <manishsmail@gmail.com>\"]\ndescription = \"Determine displayed width of `char` and `str` types\\naccording to Unicode Standard Annex #11 rules.\\n\"\ndocumentation = \"https://unicode-rs.github.io/unicode-width\"\nexclude = [\"target/*\", \"Cargo.lock\"]\nhomepage = \"https://github.com/unicode-rs/unicode-width\"\nkeywords = [\"text\", \"width\", \"unicode\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"unicode-width\"\nreadme = \"README.md\"\nrepository = \"https://github.com/unicode-rs/unicode-width\"\nversion = \"0.1.8\"\n";
+    meta = {
+      description = "Determine displayed width of `char` and `str` types\naccording to Unicode Standard Annex #11 rules.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/unicode-rs/unicode-width";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1720,6 +2958,15 @@ in
     version = "0.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"; };
+    edition = "2015";
+    libName = "unicode_xid";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"erick.tryzelaar <erick.tryzelaar@gmail.com>\", \"kwantam <kwantam@gmail.com>\"]\ndescription = \"Determine whether characters have the XID_Start\\nor XID_Continue properties according to\\nUnicode Standard Annex #31.\\n\"\ndocumentation = \"https://unicode-rs.github.io/unicode-xid\"\nexclude = [\"/scripts/*\", \"/.travis.yml\"]\nhomepage = \"https://github.com/unicode-rs/unicode-xid\"\nkeywords = [\"text\", \"unicode\", \"xid\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"unicode-xid\"\nreadme = \"README.md\"\nrepository = \"https://github.com/unicode-rs/unicode-xid\"\nversion = \"0.2.1\"\n";
+    meta = {
+      description = "Determine whether characters have the XID_Start\nor XID_Continue properties according to\nUnicode Standard Annex #31.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/unicode-rs/unicode-xid";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1730,6 +2977,15 @@ in
     version = "2.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5909f2b0817350449ed73e8bcd81c8c3c8d9a7a5d8acba4b27db277f1868976e"; };
+    edition = "2018";
+    libName = "url";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The rust-url developers\"]\ncategories = [\"parser-implementations\", \"web-programming\", \"encoding\"]\ndescription = \"URL library for Rust, based on the WHATWG URL Standard\"\ndocumentation = \"https://docs.rs/url\"\nedition = \"2018\"\ninclude = [\"src/**/*\", \"LICENSE-*\", \"README.md\"]\nkeywords = [\"url\", \"parser\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"url\"\nreadme = \"../README.md\"\nrepository = \"https://github.com/servo/rust-url\"\nversion = \"2.2.0\"\n";
+    meta = {
+      description = "URL library for Rust, based on the WHATWG URL Standard";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url";
+    };
     dependencies = {
       form_urlencoded = rustPackages."registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.0.0" { inherit profileName; };
       idna = rustPackages."registry+https://github.com/rust-lang/crates.io-index".idna."0.2.0" { inherit profileName; };
@@ -1743,6 +2999,15 @@ in
     version = "0.1.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8772a4ccbb4e89959023bc5b7cb8623a795caa7092d99f3aa9501b9484d4557d"; };
+    edition = "2015";
+    libName = "utf8parse";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Joe Wilm <joe@jwilm.com>\"]\ndescription = \"Table-driven UTF-8 parser\"\ndocumentation = \"https://docs.rs/utf8parse/\"\nkeywords = [\"utf8\", \"parse\", \"table\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"utf8parse\"\nrepository = \"https://github.com/jwilm/vte\"\nversion = \"0.1.1\"\n";
+    meta = {
+      description = "Table-driven UTF-8 parser";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/jwilm/vte";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".vcpkg."0.2.11" = overridableMkRustCrate (profileName: rec {
@@ -1750,6 +3015,15 @@ in
     version = "0.2.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b00bca6106a5e23f3eee943593759b7fcddb00554332e856d990c893966879fb"; };
+    edition = "2015";
+    libName = "vcpkg";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Jim McGrath <jimmc2@gmail.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"A library to find native dependencies in a vcpkg tree at build\\ntime in order to be used in Cargo build scripts.\\n\"\ndocumentation = \"https://docs.rs/vcpkg\"\nkeywords = [\"build-dependencies\", \"windows\", \"macos\", \"linux\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"vcpkg\"\nreadme = \"../README.md\"\nrepository = \"https://github.com/mcgoo/vcpkg-rs\"\nversion = \"0.2.11\"\n";
+    meta = {
+      description = "A library to find native dependencies in a vcpkg tree at build\ntime in order to be used in Cargo build scripts.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/mcgoo/vcpkg-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".vec_map."0.8.2" = overridableMkRustCrate (profileName: rec {
@@ -1757,6 +3031,15 @@ in
     version = "0.8.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f1bddf1187be692e79c5ffeab891132dfb0f236ed36a43c7ed39f1165ee20191"; };
+    edition = "2015";
+    libName = "vec_map";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Jorge Aparicio <japaricious@gmail.com>\", \"Alexis Beingessner <a.beingessner@gmail.com>\", \"Brian Anderson <>\", \"tbu- <>\", \"Manish Goregaokar <>\", \"Aaron Turon <aturon@mozilla.com>\", \"Adolfo Ochagavía <>\", \"Niko Matsakis <>\", \"Steven Fackler <>\", \"Chase Southwood <csouth3@illinois.edu>\", \"Eduard Burtescu <>\", \"Florian Wilkens <>\", \"Félix Raimundo <>\", \"Tibor Benke <>\", \"Markus Siemens <markus@m-siemens.de>\", \"Josh Branchaud <jbranchaud@gmail.com>\", \"Huon Wilson <dbau.pp@gmail.com>\", \"Corey Farwell <coref@rwell.org>\", \"Aaron Liblong <>\", \"Nick Cameron <nrc@ncameron.org>\", \"Patrick Walton <pcwalton@mimiga.net>\", \"Felix S Klock II <>\", \"Andrew Paseltiner <apaseltiner@gmail.com>\", \"Sean McArthur <sean.monstar@gmail.com>\", \"Vadim Petrochenkov <>\"]\ndescription = \"A simple map based on a vector for small integer keys\"\ndocumentation = \"https://contain-rs.github.io/vec-map/vec_map\"\nexclude = [\"/.travis.yml\", \"/deploy-docs.sh\"]\nhomepage = \"https://github.com/contain-rs/vec-map\"\nkeywords = [\"data-structures\", \"collections\", \"vecmap\", \"vec_map\", \"contain-rs\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"vec_map\"\nreadme = \"README.md\"\nrepository = \"https://github.com/contain-rs/vec-map\"\nversion = \"0.8.2\"\n";
+    meta = {
+      description = "A simple map based on a vector for small integer keys";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/contain-rs/vec-map";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.2" = overridableMkRustCrate (profileName: rec {
@@ -1764,6 +3047,15 @@ in
     version = "0.9.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b5a972e5669d67ba988ce3dc826706fb0a8b01471c088cb0b6110b805cc36aed"; };
+    edition = "2015";
+    libName = "version_check";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sergio Benitez <sb@sergio.bz>\"]\ndescription = \"Tiny crate to check the version of the installed/running rustc.\"\ndocumentation = \"https://docs.rs/version_check/\"\nkeywords = [\"version\", \"rustc\", \"minimum\", \"check\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"version_check\"\nreadme = \"README.md\"\nrepository = \"https://github.com/SergioBenitez/version_check\"\nversion = \"0.9.2\"\n";
+    meta = {
+      description = "Tiny crate to check the version of the installed/running rustc.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/SergioBenitez/version_check";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".vte."0.3.3" = overridableMkRustCrate (profileName: rec {
@@ -1771,6 +3063,15 @@ in
     version = "0.3.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4f42f536e22f7fcbb407639765c8fd78707a33109301f834a594758bedd6e8cf"; };
+    edition = "2015";
+    libName = "vte";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Joe Wilm <joe@jwilm.com>\"]\ndescription = \"Parser for implementing terminal emulators\"\ndocumentation = \"https://docs.rs/vte/\"\nkeywords = [\"ansi\", \"vte\", \"parser\", \"terminal\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"vte\"\nreadme = \"README.md\"\nrepository = \"https://github.com/jwilm/vte\"\nversion = \"0.3.3\"\n";
+    meta = {
+      description = "Parser for implementing terminal emulators";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/jwilm/vte";
+    };
     dependencies = {
       utf8parse = rustPackages."registry+https://github.com/rust-lang/crates.io-index".utf8parse."0.1.1" { inherit profileName; };
     };
@@ -1781,6 +3082,15 @@ in
     version = "2.3.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "777182bc735b6424e1a57516d35ed72cb8019d85c8c9bf536dccb3445c1a2f7d"; };
+    edition = "2018";
+    libName = "walkdir";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ncategories = [\"filesystem\"]\ndescription = \"Recursively walk a directory.\"\ndocumentation = \"https://docs.rs/walkdir/\"\nedition = \"2018\"\nexclude = [\"/ci/*\", \"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/BurntSushi/walkdir\"\nkeywords = [\"directory\", \"recursive\", \"walk\", \"iterator\"]\nlicense = \"Unlicense/MIT\"\nname = \"walkdir\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/walkdir\"\nversion = \"2.3.1\"\n";
+    meta = {
+      description = "Recursively walk a directory.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/walkdir";
+    };
     dependencies = {
       same_file = rustPackages."registry+https://github.com/rust-lang/crates.io-index".same-file."1.0.6" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
@@ -1793,6 +3103,15 @@ in
     version = "0.9.0+wasi-snapshot-preview1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cccddf32554fecc6acb585f82a32a72e28b48f8c4c1883ddfeeeaa96f7d8e519"; };
+    edition = "2018";
+    libName = "wasi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Cranelift Project Developers\"]\ncategories = [\"no-std\", \"wasm\"]\ndescription = \"Experimental WASI API bindings for Rust\"\ndocumentation = \"https://docs.rs/wasi\"\nedition = \"2018\"\nkeywords = [\"webassembly\", \"wasm\"]\nlicense = \"Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT\"\nname = \"wasi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/bytecodealliance/wasi\"\nversion = \"0.9.0+wasi-snapshot-preview1\"\n";
+    meta = {
+      description = "Experimental WASI API bindings for Rust";
+      license = "Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT";
+      homepage = "https://github.com/bytecodealliance/wasi";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1804,6 +3123,16 @@ in
     version = "0.3.9";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5c839a674fcd7a98952e593242ea400abe93992746761e38641405d28b00f419"; };
+    edition = "2015";
+    libName = "winapi";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"external-ffi-bindings\", \"no-std\", \"os::windows-apis\"]\ndescription = \"Raw FFI bindings for all of Windows API.\"\ndocumentation = \"https://docs.rs/winapi/\"\ninclude = [\"/src/**/*\", \"/Cargo.toml\", \"/LICENSE-MIT\", \"/LICENSE-APACHE\", \"/build.rs\", \"/README.md\"]\nkeywords = [\"windows\", \"ffi\", \"win32\", \"com\", \"directx\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.3.9\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-pc-windows-msvc\"\nfeatures = [\"everything\", \"impl-debug\", \"impl-default\"]\ntargets = [\"aarch64-pc-windows-msvc\", \"i686-pc-windows-msvc\", \"x86_64-pc-windows-msvc\"]\n";
+    meta = {
+      description = "Raw FFI bindings for all of Windows API.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
     features = builtins.concatLists [
       [ "basetsd" ]
       [ "consoleapi" ]
@@ -1845,8 +3174,8 @@ in
       [ "ws2tcpip" ]
     ];
     dependencies = {
-      ${ if hostPlatform.config == "i686-pc-windows-gnu" then "winapi_i686_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" { inherit profileName; };
-      ${ if hostPlatform.config == "x86_64-pc-windows-gnu" then "winapi_x86_64_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-x86_64-pc-windows-gnu."0.4.0" { inherit profileName; };
+      ${ if hostPlatform.isGnu && hostPlatform.isWindows && hostPlatform.parsed.cpu.name == "i686" then "winapi_i686_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" { inherit profileName; };
+      ${ if hostPlatform.isGnu && hostPlatform.isWindows && hostPlatform.isx86_64 then "winapi_x86_64_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-x86_64-pc-windows-gnu."0.4.0" { inherit profileName; };
     };
   });
   
@@ -1855,6 +3184,16 @@ in
     version = "0.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ac3b87c63620426dd9b991e5ce0329eff545bccbbb34f3be09ff6fb6ab51b7b6"; };
+    edition = "2015";
+    libName = "winapi_i686_pc_windows_gnu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Import libraries for the i686-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.\"\ninclude = [\"src/*\", \"lib/*\", \"Cargo.toml\", \"build.rs\"]\nkeywords = [\"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi-i686-pc-windows-gnu\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.4.0\"\n";
+    meta = {
+      description = "Import libraries for the i686-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".winapi-util."0.1.5" = overridableMkRustCrate (profileName: rec {
@@ -1862,6 +3201,15 @@ in
     version = "0.1.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "70ec6ce85bb158151cae5e5c87f95a8e97d2c0c4b001223f33a334e3ce5de178"; };
+    edition = "2018";
+    libName = "winapi_util";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\"]\ncategories = [\"os::windows-apis\", \"external-ffi-bindings\"]\ndescription = \"A dumping ground for high level safe wrappers over winapi.\"\ndocumentation = \"https://docs.rs/winapi-util\"\nedition = \"2018\"\nhomepage = \"https://github.com/BurntSushi/winapi-util\"\nkeywords = [\"windows\", \"winapi\", \"util\", \"win\"]\nlicense = \"Unlicense/MIT\"\nname = \"winapi-util\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/winapi-util\"\nversion = \"0.1.5\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-pc-windows-msvc\"]\n";
+    meta = {
+      description = "A dumping ground for high level safe wrappers over winapi.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/winapi-util";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -1872,6 +3220,16 @@ in
     version = "0.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "712e227841d057c1ee1cd2fb22fa7e5a5461ae8e48fa2ca79ec42cfc1931183f"; };
+    edition = "2015";
+    libName = "winapi_x86_64_pc_windows_gnu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Import libraries for the x86_64-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.\"\ninclude = [\"src/*\", \"lib/*\", \"Cargo.toml\", \"build.rs\"]\nkeywords = [\"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi-x86_64-pc-windows-gnu\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.4.0\"\n";
+    meta = {
+      description = "Import libraries for the x86_64-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
 }
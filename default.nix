@@ -53,6 +53,15 @@ let
   #     flags used for compilation of the package set. The value should be a list of the features to be turned on, without the leading "+",
   #     e.g. `[ "aes" "sse2" "ssse3" "sse4.1" ]`.  They will be prefixed with a "+", and comma delimited before passing through to rust.
   #     Crates that check for CPU features such as the `aes` crate will be evaluated against this argument.
+  # - `hostPlatformCfgs` (optional):
+  #     Custom cfgs, equivalent to passing `--cfg` through `RUSTFLAGS`, e.g. `[ "tokio_unstable" ''foo="bar"'' ]`.
+  #     Dependencies gated on these cfgs are included, and every crate is compiled with the corresponding `--cfg` flags.
+  # - `hostPlatformPanic` (optional):
+  #     The panic strategy (`"unwind"` or `"abort"`) that dependencies gated on `cfg(panic = "...")` are evaluated against.
+  #     Defaults to rustc's default for the platform, `"abort"` on WebAssembly and bare-metal targets and `"unwind"` elsewhere.
+  # - `hostPlatformDebugAssertions` (optional):
+  #     Whether dependencies gated on `cfg(debug_assertions)` are included. Defaults to `true`, matching cargo, which
+  #     evaluates dependency cfgs without taking the profile into account.
   rustPkgs = pkgs.rustBuilder.makePackageSet' {
     packageFun = import ./Cargo.nix;
     inherit rustChannel;
@@ -68,6 +77,9 @@ in
   #   `rustPkgs.<registry>.<crate>.<version>{ compileMode = "bench"; }`.
   # For convenience, you can also refer to the crates in the workspace using
   #   `rustPkgs.workspace.<crate>`.
+  # The targets of a workspace crate are listed in its `targets` attribute. To build only one of them,
+  # such as a single binary or example, use
+  #   `rustPkgs.workspace.<crate> { target = { kind = "example"; name = "<example>"; }; }`.
 rec {
   inherit rustPkgs;
   package = rustPkgs.workspace.cargo2nix {};
@@ -11,6 +11,9 @@ args@{
   hostPlatform,
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
   mkRustCrate,
   rustLib,
   lib,
@@ -25,9 +28,12 @@ in let
   rootFeatures' = expandFeatures rootFeatures;
   overridableMkRustCrate = f:
     let
-      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures; } // (f profileName)));
-    in { compileMode ? null, profileName ? decideProfile compileMode release }:
-      let drv = drvs.${profileName}; in if compileMode == null then drv else drv.override { inherit compileMode; };
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
 in
 {
   cargo2nixVersion = "0.9.0";
@@ -39,6 +45,11 @@ in
     version = "0.1.0";
     registry = "unknown";
     src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Eyal Kalderon <eyal.kalderon@tenx.tech>\"]\nedition = \"2018\"\nname = \"hello-world\"\nversion = \"0.1.0\"\n";
+    targets = [
+      { kind = "bin"; name = "hello-world"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
   });
   
 }
@@ -11,6 +11,9 @@ args@{
   hostPlatform,
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
   mkRustCrate,
   rustLib,
   lib,
@@ -25,9 +28,12 @@ in let
   rootFeatures' = expandFeatures rootFeatures;
   overridableMkRustCrate = f:
     let
-      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures; } // (f profileName)));
-    in { compileMode ? null, profileName ? decideProfile compileMode release }:
-      let drv = drvs.${profileName}; in if compileMode == null then drv else drv.override { inherit compileMode; };
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
 in
 {
   cargo2nixVersion = "0.9.0";
@@ -39,6 +45,15 @@ in
     version = "1.0.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cdb031dd78e28731d87d56cc8ffef4a8f36ca26c38fe2de700543e627f8a464a"; };
+    edition = "2015";
+    libName = "autocfg";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Josh Stone <cuviper@gmail.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"Automatic cfg for Rust compiler features\"\nexclude = [\"/.github/**\", \"/bors.toml\"]\nkeywords = [\"rustc\", \"build\", \"autoconf\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"autocfg\"\nreadme = \"README.md\"\nrepository = \"https://github.com/cuviper/autocfg\"\nversion = \"1.0.1\"\n";
+    meta = {
+      description = "Automatic cfg for Rust compiler features";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/cuviper/autocfg";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".base64."0.13.0" = overridableMkRustCrate (profileName: rec {
@@ -46,6 +61,15 @@ in
     version = "0.13.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "904dfeac50f3cdaba28fc6f57fdcddb75f49ed61346676a78c4ffe55877802fd"; };
+    edition = "2018";
+    libName = "base64";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alice Maz <alice@alicemaz.com>\", \"Marshall Pierce <marshall@mpierce.org>\"]\ncategories = [\"encoding\"]\ndescription = \"encodes and decodes base64 as bytes or utf8\"\ndocumentation = \"https://docs.rs/base64\"\nedition = \"2018\"\nkeywords = [\"base64\", \"utf8\", \"encode\", \"decode\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"base64\"\nreadme = \"README.md\"\nrepository = \"https://github.com/marshallpierce/rust-base64\"\nversion = \"0.13.0\"\n";
+    meta = {
+      description = "encodes and decodes base64 as bytes or utf8";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/marshallpierce/rust-base64";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -57,10 +81,15 @@ in
     version = "0.1.0";
     registry = "unknown";
     src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Eyal Kalderon <eyal.kalderon@tenx.tech>\"]\nedition = \"2018\"\nname = \"bigger-project\"\nversion = \"0.1.0\"\n";
     dependencies = {
       reqwest = rustPackages."registry+https://github.com/rust-lang/crates.io-index".reqwest."0.10.9" { inherit profileName; };
       tokio = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."0.2.24" { inherit profileName; };
     };
+    targets = [
+      { kind = "bin"; name = "bigger-project"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" = overridableMkRustCrate (profileName: rec {
@@ -68,6 +97,16 @@ in
     version = "1.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf1de2fe8c75bc145a2f577add951f8134889b4795d47466a54a5c846d691693"; };
+    edition = "2015";
+    libName = "bitflags";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"no-std\"]\ndescription = \"A macro to generate structures which behave like bitflags.\\n\"\ndocumentation = \"https://docs.rs/bitflags\"\nexclude = [\".travis.yml\", \"appveyor.yml\", \"bors.toml\"]\nhomepage = \"https://github.com/bitflags/bitflags\"\nkeywords = [\"bit\", \"bitmask\", \"bitflags\", \"flags\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"bitflags\"\nreadme = \"README.md\"\nrepository = \"https://github.com/bitflags/bitflags\"\nversion = \"1.2.1\"\n[package.metadata.docs.rs]\nfeatures = [\"example_generated\"]\n";
+    meta = {
+      description = "A macro to generate structures which behave like bitflags.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bitflags/bitflags";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -78,6 +117,15 @@ in
     version = "3.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2e8c087f005730276d1096a652e92a8bacee2e2472bcc9715a74d2bec38b5820"; };
+    edition = "2018";
+    libName = "bumpalo";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"try_alloc\"\npath = \"tests/try_alloc.rs\"\n\n[lib]\nbench = false\npath = \"src/lib.rs\"\n\n[package]\nauthors = [\"Nick Fitzgerald <fitzgen@gmail.com>\"]\ncategories = [\"memory-management\", \"rust-patterns\", \"no-std\"]\ndescription = \"A fast bump allocation arena for Rust.\"\ndocumentation = \"https://docs.rs/bumpalo\"\nedition = \"2018\"\nlicense = \"MIT/Apache-2.0\"\nname = \"bumpalo\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/fitzgen/bumpalo\"\nversion = \"3.4.0\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "A fast bump allocation arena for Rust.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/fitzgen/bumpalo";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -88,6 +136,15 @@ in
     version = "0.5.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0e4cec68f03f32e44924783795810fa50a7035d8c8ebe78580ad7e6c703fba38"; };
+    edition = "2018";
+    libName = "bytes";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\", \"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"network-programming\", \"data-structures\"]\ndescription = \"Types and traits for working with bytes\"\ndocumentation = \"https://docs.rs/bytes\"\nedition = \"2018\"\nkeywords = [\"buffers\", \"zero-copy\", \"io\"]\nlicense = \"MIT\"\nname = \"bytes\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/bytes\"\nversion = \"0.5.6\"\n";
+    meta = {
+      description = "Types and traits for working with bytes";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/tokio-rs/bytes";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -99,6 +156,15 @@ in
     version = "1.0.66";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4c0496836a84f8d0495758516b8621a622beb77c0fed418570e50764093ced48"; };
+    edition = "2018";
+    libName = "cc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"A build-time dependency for Cargo build scripts to assist in invoking the native\\nC compiler to compile native C code into a static archive to be linked into Rust\\ncode.\\n\"\ndocumentation = \"https://docs.rs/cc\"\nedition = \"2018\"\nexclude = [\"/.github\", \"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/alexcrichton/cc-rs\"\nkeywords = [\"build-dependencies\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"cc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cc-rs\"\nversion = \"1.0.66\"\n";
+    meta = {
+      description = "A build-time dependency for Cargo build scripts to assist in invoking the native\nC compiler to compile native C code into a static archive to be linked into Rust\ncode.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cc-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" = overridableMkRustCrate (profileName: rec {
@@ -106,6 +172,15 @@ in
     version = "0.1.10";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4785bdd1c96b2a846b2bd7cc02e86b6b3dbf14e7e53446c4f54c92a361040822"; };
+    edition = "2018";
+    libName = "cfg_if";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A macro to ergonomically define an item depending on a large number of #[cfg]\\nparameters. Structured like an if-else chain, the first matching branch is the\\nitem that gets emitted.\\n\"\ndocumentation = \"https://docs.rs/cfg-if\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/cfg-if\"\nlicense = \"MIT/Apache-2.0\"\nname = \"cfg-if\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cfg-if\"\nversion = \"0.1.10\"\n";
+    meta = {
+      description = "A macro to ergonomically define an item depending on a large number of #[cfg]\nparameters. Structured like an if-else chain, the first matching branch is the\nitem that gets emitted.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cfg-if";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" = overridableMkRustCrate (profileName: rec {
@@ -113,6 +188,15 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "baf1de4339761588bc0619e3cbc0120ee582ebb74b53b4efbf79117bd2da40fd"; };
+    edition = "2018";
+    libName = "cfg_if";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A macro to ergonomically define an item depending on a large number of #[cfg]\\nparameters. Structured like an if-else chain, the first matching branch is the\\nitem that gets emitted.\\n\"\ndocumentation = \"https://docs.rs/cfg-if\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/cfg-if\"\nlicense = \"MIT/Apache-2.0\"\nname = \"cfg-if\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cfg-if\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A macro to ergonomically define an item depending on a large number of #[cfg]\nparameters. Structured like an if-else chain, the first matching branch is the\nitem that gets emitted.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cfg-if";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".console_error_panic_hook."0.1.6" = overridableMkRustCrate (profileName: rec {
@@ -120,6 +204,15 @@ in
     version = "0.1.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b8d976903543e0c48546a91908f21588a680a8c8f984df9a5d69feccb2b2a211"; };
+    edition = "2015";
+    libName = "console_error_panic_hook";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Nick Fitzgerald <fitzgen@gmail.com>\"]\ncategories = [\"wasm\"]\ndescription = \"A panic hook for `wasm32-unknown-unknown` that logs panics to `console.error`\"\nlicense = \"Apache-2.0/MIT\"\nname = \"console_error_panic_hook\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/rustwasm/console_error_panic_hook\"\nversion = \"0.1.6\"\n";
+    meta = {
+      description = "A panic hook for `wasm32-unknown-unknown` that logs panics to `console.error`";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/rustwasm/console_error_panic_hook";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
       wasm_bindgen = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.69" { inherit profileName; };
@@ -131,6 +224,15 @@ in
     version = "0.9.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0a89e2ae426ea83155dccf10c0fa6b1463ef6d5fcb44cee0b224a408fa640a62"; };
+    edition = "2015";
+    libName = "core_foundation";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Servo Project Developers\"]\ncategories = [\"os::macos-apis\"]\ndescription = \"Bindings to Core Foundation for macOS\"\nhomepage = \"https://github.com/servo/core-foundation-rs\"\nkeywords = [\"macos\", \"framework\", \"objc\"]\nlicense = \"MIT / Apache-2.0\"\nname = \"core-foundation\"\nrepository = \"https://github.com/servo/core-foundation-rs\"\nversion = \"0.9.1\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-apple-darwin\"\n";
+    meta = {
+      description = "Bindings to Core Foundation for macOS";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/core-foundation-rs";
+    };
     dependencies = {
       core_foundation_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".core-foundation-sys."0.8.2" { inherit profileName; };
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
@@ -142,6 +244,16 @@ in
     version = "0.8.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ea221b5284a47e40033bf9b66f35f984ec0ea2931eb03505246cd27a963f981b"; };
+    edition = "2015";
+    libName = "core_foundation_sys";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Servo Project Developers\"]\nbuild = \"build.rs\"\ndescription = \"Bindings to Core Foundation for macOS\"\nhomepage = \"https://github.com/servo/core-foundation-rs\"\nlicense = \"MIT / Apache-2.0\"\nname = \"core-foundation-sys\"\nrepository = \"https://github.com/servo/core-foundation-rs\"\nversion = \"0.8.2\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-apple-darwin\"\n";
+    meta = {
+      description = "Bindings to Core Foundation for macOS";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/core-foundation-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".encoding_rs."0.8.26" = overridableMkRustCrate (profileName: rec {
@@ -149,6 +261,16 @@ in
     version = "0.8.26";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "801bbab217d7f79c0062f4f7205b5d4427c6d1a7bd7aafdd1475f7c59d62b283"; };
+    edition = "2018";
+    libName = "encoding_rs";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Henri Sivonen <hsivonen@hsivonen.fi>\"]\ncategories = [\"text-processing\", \"encoding\", \"web-programming\", \"internationalization\"]\ndescription = \"A Gecko-oriented implementation of the Encoding Standard\"\ndocumentation = \"https://docs.rs/encoding_rs/\"\nedition = \"2018\"\nhomepage = \"https://docs.rs/encoding_rs/\"\nkeywords = [\"encoding\", \"web\", \"unicode\", \"charset\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"encoding_rs\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hsivonen/encoding_rs\"\nversion = \"0.8.26\"\n";
+    meta = {
+      description = "A Gecko-oriented implementation of the Encoding Standard";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://docs.rs/encoding_rs/";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
     };
@@ -159,6 +281,15 @@ in
     version = "1.0.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3f9eec918d3f24069decb9af1554cad7c880e2da24a9afd88aca000531ab82c1"; };
+    edition = "2015";
+    libName = "fnv";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"fnv\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Fowler–Noll–Vo hash function\"\ndocumentation = \"https://doc.servo.org/fnv/\"\nlicense = \"Apache-2.0 / MIT\"\nname = \"fnv\"\nreadme = \"README.md\"\nrepository = \"https://github.com/servo/rust-fnv\"\nversion = \"1.0.7\"\n";
+    meta = {
+      description = "Fowler–Noll–Vo hash function";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/servo/rust-fnv";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -170,6 +301,15 @@ in
     version = "0.3.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f6f339eb8adc052cd2ca78910fda869aefa38d22d5cb648e6485e4d3fc06f3b1"; };
+    edition = "2015";
+    libName = "foreign_types";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"A framework for Rust wrappers over C APIs\"\nlicense = \"MIT/Apache-2.0\"\nname = \"foreign-types\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/foreign-types\"\nversion = \"0.3.2\"\n";
+    meta = {
+      description = "A framework for Rust wrappers over C APIs";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/foreign-types";
+    };
     dependencies = {
       foreign_types_shared = rustPackages."registry+https://github.com/rust-lang/crates.io-index".foreign-types-shared."0.1.1" { inherit profileName; };
     };
@@ -180,6 +320,15 @@ in
     version = "0.1.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "00b0228411908ca8685dba7fc2cdd70ec9990a6e753e89b6ac91a84c40fbaf4b"; };
+    edition = "2015";
+    libName = "foreign_types_shared";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"An internal crate used by foreign-types\"\nlicense = \"MIT/Apache-2.0\"\nname = \"foreign-types-shared\"\nrepository = \"https://github.com/sfackler/foreign-types\"\nversion = \"0.1.1\"\n";
+    meta = {
+      description = "An internal crate used by foreign-types";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/foreign-types";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.0.0" = overridableMkRustCrate (profileName: rec {
@@ -187,6 +336,15 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ece68d15c92e84fa4f19d3780f1294e5ca82a78a6d515f1efaabcc144688be00"; };
+    edition = "2015";
+    libName = "form_urlencoded";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\ndescription = \"Parser and serializer for the application/x-www-form-urlencoded syntax, as used by HTML forms.\"\nlicense = \"MIT/Apache-2.0\"\nname = \"form_urlencoded\"\nrepository = \"https://github.com/servo/rust-url\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "Parser and serializer for the application/x-www-form-urlencoded syntax, as used by HTML forms.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url";
+    };
     dependencies = {
       matches = rustPackages."registry+https://github.com/rust-lang/crates.io-index".matches."0.1.8" { inherit profileName; };
       percent_encoding = rustPackages."registry+https://github.com/rust-lang/crates.io-index".percent-encoding."2.1.0" { inherit profileName; };
@@ -198,6 +356,15 @@ in
     version = "0.3.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2e9763c69ebaae630ba35f74888db465e49e259ba1bc0eda7d06f4a067615d82"; };
+    edition = "2015";
+    libName = "fuchsia_zircon";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Raph Levien <raph@google.com>\"]\ndescription = \"Rust bindings for the Zircon kernel\"\nlicense = \"BSD-3-Clause\"\nname = \"fuchsia-zircon\"\nrepository = \"https://fuchsia.googlesource.com/garnet/\"\nversion = \"0.3.3\"\n";
+    meta = {
+      description = "Rust bindings for the Zircon kernel";
+      license = [ lib.licenses.bsd3 ];
+      homepage = "https://fuchsia.googlesource.com/garnet/";
+    };
     dependencies = {
       bitflags = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" { inherit profileName; };
       fuchsia_zircon_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".fuchsia-zircon-sys."0.3.3" { inherit profileName; };
@@ -209,6 +376,15 @@ in
     version = "0.3.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3dcaa9ae7725d12cdb85b3ad99a434db70b468c09ded17e012d86b5c1010f7a7"; };
+    edition = "2015";
+    libName = "fuchsia_zircon_sys";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Raph Levien <raph@google.com>\"]\ndescription = \"Low-level Rust bindings for the Zircon kernel\"\nlicense = \"BSD-3-Clause\"\nname = \"fuchsia-zircon-sys\"\nrepository = \"https://fuchsia.googlesource.com/garnet/\"\nversion = \"0.3.3\"\n";
+    meta = {
+      description = "Low-level Rust bindings for the Zircon kernel";
+      license = [ lib.licenses.bsd3 ];
+      homepage = "https://fuchsia.googlesource.com/garnet/";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".futures-channel."0.3.8" = overridableMkRustCrate (profileName: rec {
@@ -216,6 +392,15 @@ in
     version = "0.3.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4b7109687aa4e177ef6fe84553af6280ef2778bdb7783ba44c9dc3399110fe64"; };
+    edition = "2018";
+    libName = "futures_channel";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Channels for asynchronous communication using futures-rs.\\n\"\ndocumentation = \"https://docs.rs/futures-channel/0.3.8\"\nedition = \"2018\"\nhomepage = \"https://rust-lang.github.io/futures-rs\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"futures-channel\"\nrepository = \"https://github.com/rust-lang/futures-rs\"\nversion = \"0.3.8\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Channels for asynchronous communication using futures-rs.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rust-lang.github.io/futures-rs";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -231,6 +416,15 @@ in
     version = "0.3.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "847ce131b72ffb13b6109a221da9ad97a64cbe48feb1028356b836b47b8f1748"; };
+    edition = "2018";
+    libName = "futures_core";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"The core traits and types in for the `futures` library.\\n\"\ndocumentation = \"https://docs.rs/futures-core/0.3.8\"\nedition = \"2018\"\nhomepage = \"https://rust-lang.github.io/futures-rs\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"futures-core\"\nrepository = \"https://github.com/rust-lang/futures-rs\"\nversion = \"0.3.8\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "The core traits and types in for the `futures` library.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rust-lang.github.io/futures-rs";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -243,6 +437,15 @@ in
     version = "0.3.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f878195a49cee50e006b02b93cf7e0a95a38ac7b776b4c4d9cc1207cd20fcb3d"; };
+    edition = "2018";
+    libName = "futures_sink";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"The asynchronous `Sink` trait for the futures-rs library.\\n\"\ndocumentation = \"https://docs.rs/futures-sink/0.3.8\"\nedition = \"2018\"\nhomepage = \"https://rust-lang.github.io/futures-rs\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"futures-sink\"\nrepository = \"https://github.com/rust-lang/futures-rs\"\nversion = \"0.3.8\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "The asynchronous `Sink` trait for the futures-rs library.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rust-lang.github.io/futures-rs";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -255,6 +458,15 @@ in
     version = "0.3.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7c554eb5bf48b2426c4771ab68c6b14468b6e76cc90996f528c3338d761a4d0d"; };
+    edition = "2018";
+    libName = "futures_task";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Tools for working with tasks.\\n\"\ndocumentation = \"https://docs.rs/futures-task/0.3.8\"\nedition = \"2018\"\nhomepage = \"https://rust-lang.github.io/futures-rs\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"futures-task\"\nrepository = \"https://github.com/rust-lang/futures-rs\"\nversion = \"0.3.8\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Tools for working with tasks.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rust-lang.github.io/futures-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".futures-util."0.3.8" = overridableMkRustCrate (profileName: rec {
@@ -262,6 +474,15 @@ in
     version = "0.3.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d304cff4a7b99cfb7986f7d43fbe93d175e72e704a8860787cc95e9ffd85cbd2"; };
+    edition = "2018";
+    libName = "futures_util";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Common utilities and extension traits for the futures-rs library.\\n\"\ndocumentation = \"https://docs.rs/futures-util/0.3.8\"\nedition = \"2018\"\nhomepage = \"https://rust-lang.github.io/futures-rs\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"futures-util\"\nrepository = \"https://github.com/rust-lang/futures-rs\"\nversion = \"0.3.8\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Common utilities and extension traits for the futures-rs library.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rust-lang.github.io/futures-rs";
+    };
     dependencies = {
       futures_core = rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-core."0.3.8" { inherit profileName; };
       futures_task = rustPackages."registry+https://github.com/rust-lang/crates.io-index".futures-task."0.3.8" { inherit profileName; };
@@ -275,13 +496,23 @@ in
     version = "0.1.15";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fc587bc0ec293155d5bfa6b9891ec18a1e330c234f896ea47fbada4cadbe47e6"; };
+    edition = "2018";
+    libName = "getrandom";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\"]\ncategories = [\"os\", \"no-std\"]\ndescription = \"A small cross-platform library for retrieving random data from system source\"\ndocumentation = \"https://docs.rs/getrandom\"\nedition = \"2018\"\nexclude = [\"utils/*\", \".*\", \"appveyor.yml\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"getrandom\"\nrepository = \"https://github.com/rust-random/getrandom\"\nversion = \"0.1.15\"\n";
+    meta = {
+      description = "A small cross-platform library for retrieving random data from system source";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-random/getrandom";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "wasi" then "wasi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.9.0+wasi-snapshot-preview1" { inherit profileName; };
+      ${ if hostPlatform.isWasi then "wasi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasi."0.9.0+wasi-snapshot-preview1" { inherit profileName; };
     };
   });
   
@@ -290,6 +521,15 @@ in
     version = "0.2.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5e4728fd124914ad25e99e3d15a9361a879f6620f63cb56bbb08f95abb97a535"; };
+    edition = "2018";
+    libName = "h2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\", \"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"asynchronous\", \"web-programming\", \"network-programming\"]\ndescription = \"An HTTP/2.0 client and server\"\ndocumentation = \"https://docs.rs/h2/0.2.7/h2/\"\nedition = \"2018\"\nexclude = [\"fixtures/**\", \"ci/**\"]\nkeywords = [\"http\", \"async\", \"non-blocking\"]\nlicense = \"MIT\"\nname = \"h2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hyperium/h2\"\nversion = \"0.2.7\"\n";
+    meta = {
+      description = "An HTTP/2.0 client and server";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/hyperium/h2";
+    };
     dependencies = {
       bytes = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."0.5.6" { inherit profileName; };
       fnv = rustPackages."registry+https://github.com/rust-lang/crates.io-index".fnv."1.0.7" { inherit profileName; };
@@ -311,6 +551,15 @@ in
     version = "0.9.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d7afe4a420e3fe79967a00898cc1f4db7c8a49a9333a29f8a4bd76a253d5cd04"; };
+    edition = "2018";
+    libName = "hashbrown";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Amanieu d'Antras <amanieu@gmail.com>\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"A Rust port of Google's SwissTable hash map\"\nedition = \"2018\"\nexclude = [\".travis.yml\", \"bors.toml\", \"/ci/*\"]\nkeywords = [\"hash\", \"no_std\", \"hashmap\", \"swisstable\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"hashbrown\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/hashbrown\"\nversion = \"0.9.1\"\n[package.metadata.docs.rs]\nfeatures = [\"nightly\", \"rayon\", \"serde\", \"raw\"]\n";
+    meta = {
+      description = "A Rust port of Google's SwissTable hash map";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/rust-lang/hashbrown";
+    };
     features = builtins.concatLists [
       [ "raw" ]
     ];
@@ -321,6 +570,15 @@ in
     version = "0.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "28d569972648b2c512421b5f2a405ad6ac9666547189d0c5477a3f200f3e02f9"; };
+    edition = "2018";
+    libName = "http";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Carl Lerche <me@carllerche.com>\", \"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"web-programming\"]\ndescription = \"A set of types for representing HTTP requests and responses.\\n\"\ndocumentation = \"https://docs.rs/http\"\nedition = \"2018\"\nkeywords = [\"http\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"http\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hyperium/http\"\nversion = \"0.2.1\"\n";
+    meta = {
+      description = "A set of types for representing HTTP requests and responses.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/hyperium/http";
+    };
     dependencies = {
       bytes = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."0.5.6" { inherit profileName; };
       fnv = rustPackages."registry+https://github.com/rust-lang/crates.io-index".fnv."1.0.7" { inherit profileName; };
@@ -333,6 +591,15 @@ in
     version = "0.3.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "13d5ff830006f7646652e057693569bfe0d51760c0085a071769d142a205111b"; };
+    edition = "2018";
+    libName = "http_body";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\", \"Lucio Franco <luciofranco14@gmail.com>\", \"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"web-programming\"]\ndescription = \"Trait representing an asynchronous, streaming, HTTP request or response body.\\n\"\ndocumentation = \"https://docs.rs/http-body/0.3.0/http-body\"\nedition = \"2018\"\nkeywords = [\"http\"]\nlicense = \"MIT\"\nname = \"http-body\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hyperium/http-body\"\nversion = \"0.3.1\"\n";
+    meta = {
+      description = "Trait representing an asynchronous, streaming, HTTP request or response body.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/hyperium/http-body";
+    };
     dependencies = {
       bytes = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."0.5.6" { inherit profileName; };
       http = rustPackages."registry+https://github.com/rust-lang/crates.io-index".http."0.2.1" { inherit profileName; };
@@ -344,6 +611,16 @@ in
     version = "1.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cd179ae861f0c2e53da70d892f5f3029f9594be0c41dc5269cd371691b1dc2f9"; };
+    edition = "2015";
+    libName = "httparse";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\nbuild = \"build.rs\"\ncategories = [\"network-programming\", \"no-std\", \"parser-implementations\", \"web-programming\"]\ndescription = \"A tiny, safe, speedy, zero-copy HTTP/1.x parser.\"\ndocumentation = \"https://docs.rs/httparse\"\nkeywords = [\"http\", \"parser\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"httparse\"\nreadme = \"README.md\"\nrepository = \"https://github.com/seanmonstar/httparse\"\nversion = \"1.3.4\"\n";
+    meta = {
+      description = "A tiny, safe, speedy, zero-copy HTTP/1.x parser.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/seanmonstar/httparse";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -355,6 +632,15 @@ in
     version = "0.3.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "494b4d60369511e7dea41cf646832512a94e542f68bb9c49e54518e0f468eb47"; };
+    edition = "2015";
+    libName = "httpdate";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Pyfisch <pyfisch@gmail.com>\"]\ndescription = \"HTTP date parsing and formatting\"\ndocumentation = \"https://pyfisch.github.io/httpdate/httpdate/index.html\"\nkeywords = [\"http\", \"date\", \"time\", \"simple\", \"timestamp\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"httpdate\"\nreadme = \"README.md\"\nrepository = \"https://github.com/pyfisch/httpdate\"\nversion = \"0.3.2\"\n";
+    meta = {
+      description = "HTTP date parsing and formatting";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/pyfisch/httpdate";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".hyper."0.13.9" = overridableMkRustCrate (profileName: rec {
@@ -362,6 +648,15 @@ in
     version = "0.13.9";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f6ad767baac13b44d4529fcf58ba2cd0995e36e7b435bc5b039de6f47e880dbf"; };
+    edition = "2018";
+    libName = "hyper";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[example]]\nname = \"client\"\npath = \"examples/client.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"client_json\"\npath = \"examples/client_json.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"echo\"\npath = \"examples/echo.rs\"\nrequired-features = [\"runtime\", \"stream\"]\n\n[[example]]\nname = \"gateway\"\npath = \"examples/gateway.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"hello\"\npath = \"examples/hello.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"http_proxy\"\npath = \"examples/http_proxy.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"multi_server\"\npath = \"examples/multi_server.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"params\"\npath = \"examples/params.rs\"\nrequired-features = [\"runtime\", \"stream\"]\n\n[[example]]\nname = \"send_file\"\npath = \"examples/send_file.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"service_struct_impl\"\npath = \"examples/service_struct_impl.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"single_threaded\"\npath = \"examples/single_threaded.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"state\"\npath = \"examples/state.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"tower_client\"\npath = \"examples/tower_client.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"tower_server\"\npath = \"examples/tower_server.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"upgrades\"\npath = \"examples/upgrades.rs\"\nrequired-features = [\"runtime\"]\n\n[[example]]\nname = \"web_api\"\npath = \"examples/web_api.rs\"\nrequired-features = [\"runtime\", \"stream\"]\n\n[[test]]\nname = \"client\"\npath = \"tests/client.rs\"\nrequired-features = [\"runtime\", \"stream\"]\n\n[[test]]\nname = \"integration\"\npath = \"tests/integration.rs\"\nrequired-features = [\"runtime\", \"stream\"]\n\n[[test]]\nname = \"server\"\npath = \"tests/server.rs\"\nrequired-features = [\"runtime\"]\n\n[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"network-programming\", \"web-programming::http-client\", \"web-programming::http-server\"]\ndescription = \"A fast and correct HTTP library.\"\ndocumentation = \"https://docs.rs/hyper\"\nedition = \"2018\"\nhomepage = \"https://hyper.rs\"\ninclude = [\"Cargo.toml\", \"LICENSE\", \"src/**/*\"]\nkeywords = [\"http\", \"hyper\", \"hyperium\"]\nlicense = \"MIT\"\nname = \"hyper\"\nreadme = \"README.md\"\nrepository = \"https://github.com/hyperium/hyper\"\nversion = \"0.13.9\"\n[package.metadata.docs.rs]\nfeatures = [\"runtime\", \"stream\"]\n";
+    meta = {
+      description = "A fast and correct HTTP library.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://hyper.rs";
+    };
     features = builtins.concatLists [
       [ "socket2" ]
       [ "tcp" ]
@@ -391,6 +686,15 @@ in
     version = "0.4.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d979acc56dcb5b8dddba3917601745e877576475aa046df3226eabdecef78eed"; };
+    edition = "2018";
+    libName = "hyper_tls";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ndescription = \"Default TLS implementation for use with hyper\"\ndocumentation = \"https://docs.rs/hyper-tls\"\nedition = \"2018\"\nhomepage = \"https://hyper.rs\"\nkeywords = [\"hyper\", \"tls\", \"http\", \"https\", \"ssl\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"hyper-tls\"\nrepository = \"https://github.com/hyperium/hyper-tls\"\nversion = \"0.4.3\"\n";
+    meta = {
+      description = "Default TLS implementation for use with hyper";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://hyper.rs";
+    };
     dependencies = {
       bytes = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytes."0.5.6" { inherit profileName; };
       hyper = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hyper."0.13.9" { inherit profileName; };
@@ -405,6 +709,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "02e2673c30ee86b5b96a9cb52ad15718aa1f966f5ab9ad54a8b95d5ca33120a9"; };
+    edition = "2015";
+    libName = "idna";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"tests\"\n\n[[test]]\nname = \"unit\"\n\n[lib]\ndoctest = false\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\nautotests = false\ndescription = \"IDNA (Internationalizing Domain Names in Applications) and Punycode.\"\nlicense = \"MIT/Apache-2.0\"\nname = \"idna\"\nrepository = \"https://github.com/servo/rust-url/\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "IDNA (Internationalizing Domain Names in Applications) and Punycode.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url/";
+    };
     dependencies = {
       matches = rustPackages."registry+https://github.com/rust-lang/crates.io-index".matches."0.1.8" { inherit profileName; };
       unicode_bidi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".unicode-bidi."0.3.4" { inherit profileName; };
@@ -417,6 +730,16 @@ in
     version = "1.6.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "55e2e4c765aa53a0424761bf9f41aa7a6ac1efa87238f59560640e27fca028f2"; };
+    edition = "2018";
+    libName = "indexmap";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nbench = false\n\n[package]\nauthors = [\"bluss\", \"Josh Stone <cuviper@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"A hash table with consistent order and fast iteration.\\n\\nThe indexmap is a hash table where the iteration order of the key-value\\npairs is independent of the hash values of the keys. It has the usual\\nhash table functionality, it preserves insertion order except after\\nremovals, and it allows lookup of its elements by either hash table key\\nor numerical index. A corresponding hash set type is also provided.\\n\\nThis crate was initially published under the name ordermap, but it was renamed to\\nindexmap.\\n\"\ndocumentation = \"https://docs.rs/indexmap/\"\nedition = \"2018\"\nkeywords = [\"hashmap\", \"no_std\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"indexmap\"\nrepository = \"https://github.com/bluss/indexmap\"\nversion = \"1.6.0\"\n[package.metadata.docs.rs]\nfeatures = [\"serde-1\", \"rayon\"]\n\n[package.metadata.release]\nno-dev-version = true\ntag-name = \"{{version}}\"\n";
+    meta = {
+      description = "A hash table with consistent order and fast iteration.\n\nThe indexmap is a hash table where the iteration order of the key-value\npairs is independent of the hash values of the keys. It has the usual\nhash table functionality, it preserves insertion order except after\nremovals, and it allows lookup of its elements by either hash table key\nor numerical index. A corresponding hash set type is also provided.\n\nThis crate was initially published under the name ordermap, but it was renamed to\nindexmap.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/bluss/indexmap";
+    };
     dependencies = {
       hashbrown = rustPackages."registry+https://github.com/rust-lang/crates.io-index".hashbrown."0.9.1" { inherit profileName; };
     };
@@ -430,6 +753,15 @@ in
     version = "0.1.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b2b3ea6ff95e175473f8ffe6a7eb7c00d054240321b84c57051175fe3c1e075e"; };
+    edition = "2015";
+    libName = "iovec";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\"]\ncategories = [\"network-programming\", \"api-bindings\"]\ndescription = \"Portable buffer type for scatter/gather I/O operations\\n\"\ndocumentation = \"https://docs.rs/iovec\"\nhomepage = \"https://github.com/carllerche/iovec\"\nkeywords = [\"scatter\", \"gather\", \"vectored\", \"io\", \"networking\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"iovec\"\nreadme = \"README.md\"\nrepository = \"https://github.com/carllerche/iovec\"\nversion = \"0.1.4\"\n";
+    meta = {
+      description = "Portable buffer type for scatter/gather I/O operations";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/carllerche/iovec";
+    };
     dependencies = {
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -440,6 +772,15 @@ in
     version = "2.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "47be2f14c678be2fdcab04ab1171db51b2762ce6f0a8ee87c8dd4a04ed216135"; };
+    edition = "2015";
+    libName = "ipnet";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Kris Price <kris@krisprice.nz>\"]\ncategories = [\"network-programming\"]\ndescription = \"Provides types and useful methods for working with IPv4 and IPv6 network addresses, commonly called IP prefixes. The new `IpNet`, `Ipv4Net`, and `Ipv6Net` types build on the existing `IpAddr`, `Ipv4Addr`, and `Ipv6Addr` types already provided in Rust's standard library and align to their design to stay consistent. The module also provides useful traits that extend `Ipv4Addr` and `Ipv6Addr` with methods for `Add`, `Sub`, `BitAnd`, and `BitOr` operations. The module only uses stable feature so it is guaranteed to compile using the stable toolchain.\"\ndocumentation = \"https://docs.rs/ipnet\"\nkeywords = [\"IP\", \"CIDR\", \"network\", \"prefix\", \"subnet\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"ipnet\"\nreadme = \"README.md\"\nrepository = \"https://github.com/krisprice/ipnet\"\nversion = \"2.3.0\"\n";
+    meta = {
+      description = "Provides types and useful methods for working with IPv4 and IPv6 network addresses, commonly called IP prefixes. The new `IpNet`, `Ipv4Net`, and `Ipv6Net` types build on the existing `IpAddr`, `Ipv4Addr`, and `Ipv6Addr` types already provided in Rust's standard library and align to their design to stay consistent. The module also provides useful traits that extend `Ipv4Addr` and `Ipv6Addr` with methods for `Add`, `Sub`, `BitAnd`, and `BitOr` operations. The module only uses stable feature so it is guaranteed to compile using the stable toolchain.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/krisprice/ipnet";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".itoa."0.4.6" = overridableMkRustCrate (profileName: rec {
@@ -447,6 +788,15 @@ in
     version = "0.4.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "dc6f3ad7b9d11a0c00842ff8de1b60ee58661048eb8049ed33c73594f359d7e6"; };
+    edition = "2015";
+    libName = "itoa";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"value-formatting\"]\ndescription = \"Fast functions for printing integer primitives to an io::Write\"\ndocumentation = \"https://github.com/dtolnay/itoa\"\nexclude = [\"performance.png\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"itoa\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/itoa\"\nversion = \"0.4.6\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Fast functions for printing integer primitives to an io::Write";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/itoa";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -458,6 +808,15 @@ in
     version = "0.3.46";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf3d7383929f7c9c7c2d0fa596f325832df98c3704f2c60553080f7127a58175"; };
+    edition = "2018";
+    libName = "js_sys";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ndoctest = false\ntest = false\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ncategories = [\"wasm\"]\ndescription = \"Bindings for all JS global objects and functions in all JS environments like\\nNode.js and browsers, built on `#[wasm_bindgen]` using the `wasm-bindgen` crate.\\n\"\ndocumentation = \"https://docs.rs/js-sys\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"js-sys\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/js-sys\"\nversion = \"0.3.46\"\n";
+    meta = {
+      description = "Bindings for all JS global objects and functions in all JS environments like\nNode.js and browsers, built on `#[wasm_bindgen]` using the `wasm-bindgen` crate.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
     dependencies = {
       wasm_bindgen = rustPackages."registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen."0.2.69" { inherit profileName; };
     };
@@ -468,6 +827,16 @@ in
     version = "0.2.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7507624b29483431c0ba2d82aece8ca6cdba9382bff4ddd0f7490560c056098d"; };
+    edition = "2015";
+    libName = "kernel32";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"kernel32\"\n\n[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Contains function definitions for the Windows API library kernel32. See winapi for types and constants.\"\ndocumentation = \"https://retep998.github.io/doc/kernel32/\"\nkeywords = [\"windows\", \"ffi\", \"win32\"]\nlicense = \"MIT\"\nname = \"kernel32-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.2.2\"\n";
+    meta = {
+      description = "Contains function definitions for the Windows API library kernel32. See winapi for types and constants.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
     dependencies = {
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.2.8" { inherit profileName; };
     };
@@ -481,6 +850,15 @@ in
     version = "1.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e2abad23fbc42b3700f2f279844dc832adb2b2eb069b2df918f455c4e18cc646"; };
+    edition = "2015";
+    libName = "lazy_static";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Marvin Löbel <loebel.marvin@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\", \"memory-management\"]\ndescription = \"A macro for declaring lazily evaluated statics in Rust.\"\ndocumentation = \"https://docs.rs/lazy_static\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nkeywords = [\"macro\", \"lazy\", \"static\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"lazy_static\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang-nursery/lazy-static.rs\"\nversion = \"1.4.0\"\n";
+    meta = {
+      description = "A macro for declaring lazily evaluated statics in Rust.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang-nursery/lazy-static.rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" = overridableMkRustCrate (profileName: rec {
@@ -488,6 +866,16 @@ in
     version = "0.2.81";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1482821306169ec4d07f6aca392a4681f66c75c9918aa49641a2595db64053cb"; };
+    edition = "2015";
+    libName = "libc";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"external-ffi-bindings\", \"no-std\", \"os\"]\ndescription = \"Raw FFI bindings to platform libraries like libc.\\n\"\ndocumentation = \"https://docs.rs/libc/\"\nexclude = [\"/ci/*\", \"/.github/*\", \"/.cirrus.yml\", \"/triagebot.toml\"]\nhomepage = \"https://github.com/rust-lang/libc\"\nkeywords = [\"libc\", \"ffi\", \"bindings\", \"operating\", \"system\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"libc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/libc\"\nversion = \"0.2.81\"\n";
+    meta = {
+      description = "Raw FFI bindings to platform libraries like libc.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/libc";
+    };
     features = builtins.concatLists [
       [ "align" ]
       [ "default" ]
@@ -500,6 +888,16 @@ in
     version = "0.4.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4fabed175da42fed1fa0746b0ea71f412aa9d35e76e95e59b192c64b9dc2bf8b"; };
+    edition = "2015";
+    libName = "log";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[test]]\nharness = false\nname = \"filters\"\n\n[[test]]\nharness = true\nname = \"macros\"\n\n[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"development-tools::debugging\"]\ndescription = \"A lightweight logging facade for Rust\\n\"\ndocumentation = \"https://docs.rs/log\"\nexclude = [\"rfcs/**/*\", \"/.travis.yml\", \"/appveyor.yml\"]\nkeywords = [\"logging\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"log\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/log\"\nversion = \"0.4.11\"\n[package.metadata.docs.rs]\nfeatures = [\"std\", \"serde\", \"kv_unstable_sval\"]\n";
+    meta = {
+      description = "A lightweight logging facade for Rust";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/log";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
     };
@@ -510,6 +908,15 @@ in
     version = "0.1.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7ffc5c5338469d4d3ea17d269fa8ea3512ad247247c30bd2df69e68309ed0a08"; };
+    edition = "2015";
+    libName = "matches";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"matches\"\npath = \"lib.rs\"\n\n[package]\nauthors = [\"Simon Sapin <simon.sapin@exyr.org>\"]\ndescription = \"A macro to evaluate, as a boolean, whether an expression matches a pattern.\"\ndocumentation = \"https://docs.rs/matches/\"\nlicense = \"MIT\"\nname = \"matches\"\nrepository = \"https://github.com/SimonSapin/rust-std-candidates\"\nversion = \"0.1.8\"\n";
+    meta = {
+      description = "A macro to evaluate, as a boolean, whether an expression matches a pattern.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/SimonSapin/rust-std-candidates";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".memchr."2.3.4" = overridableMkRustCrate (profileName: rec {
@@ -517,6 +924,16 @@ in
     version = "2.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0ee1c47aaa256ecabcaea351eae4a9b01ef39ed810004e298d2511ed284b1525"; };
+    edition = "2015";
+    libName = "memchr";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"memchr\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\", \"bluss\"]\ndescription = \"Safe interface to memchr.\"\ndocumentation = \"https://docs.rs/memchr/\"\nexclude = [\"/ci/*\", \"/.travis.yml\", \"/Makefile\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/BurntSushi/rust-memchr\"\nkeywords = [\"memchr\", \"char\", \"scan\", \"strchr\", \"string\"]\nlicense = \"Unlicense/MIT\"\nname = \"memchr\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/rust-memchr\"\nversion = \"2.3.4\"\n";
+    meta = {
+      description = "Safe interface to memchr.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/rust-memchr";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -528,6 +945,15 @@ in
     version = "0.3.16";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2a60c7ce501c71e03a9c9c0d35b861413ae925bd979cc7a4e30d060069aaac8d"; };
+    edition = "2015";
+    libName = "mime";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ndescription = \"Strongly Typed Mimes\"\ndocumentation = \"https://docs.rs/mime\"\nkeywords = [\"mime\", \"media-extensions\", \"media-types\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"mime\"\nrepository = \"https://github.com/hyperium/mime\"\nversion = \"0.3.16\"\n";
+    meta = {
+      description = "Strongly Typed Mimes";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/hyperium/mime";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".mime_guess."2.0.3" = overridableMkRustCrate (profileName: rec {
@@ -535,6 +961,16 @@ in
     version = "2.0.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2684d4c2e97d99848d30b324b00c8fcc7e5c897b7cbb5819b09e7c90e8baf212"; };
+    edition = "2015";
+    libName = "mime_guess";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[example]]\nname = \"rev_map\"\nrequired-features = [\"rev-mappings\"]\n\n[package]\nauthors = [\"Austin Bonander <austin.bonander@gmail.com>\"]\ndescription = \"A simple crate for detection of a file's MIME type by its extension.\"\ndocumentation = \"https://docs.rs/mime_guess/\"\nkeywords = [\"mime\", \"filesystem\", \"extension\"]\nlicense = \"MIT\"\nname = \"mime_guess\"\nreadme = \"README.md\"\nrepository = \"https://github.com/abonander/mime_guess\"\nversion = \"2.0.3\"\n";
+    meta = {
+      description = "A simple crate for detection of a file's MIME type by its extension.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/abonander/mime_guess";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "rev-mappings" ]
@@ -553,6 +989,15 @@ in
     version = "0.6.23";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4afd66f5b91bf2a3bc13fad0e21caedac168ca4c707504e75585648ae80e4cc4"; };
+    edition = "2015";
+    libName = "mio";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nname = \"test\"\npath = \"test/mod.rs\"\n\n[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\"]\ncategories = [\"asynchronous\"]\ndescription = \"Lightweight non-blocking IO\"\ndocumentation = \"https://docs.rs/mio/0.6.23/mio/\"\nhomepage = \"https://github.com/tokio-rs/mio\"\ninclude = [\"Cargo.toml\", \"LICENSE\", \"README.md\", \"CHANGELOG.md\", \"src/**/*.rs\"]\nkeywords = [\"io\", \"async\", \"non-blocking\"]\nlicense = \"MIT\"\nname = \"mio\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/mio\"\nversion = \"0.6.23\"\n";
+    meta = {
+      description = "Lightweight non-blocking IO";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/tokio-rs/mio";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "with-deprecated" ]
@@ -577,6 +1022,15 @@ in
     version = "0.2.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ebd808424166322d4a38da87083bfddd3ac4c131334ed55856112eb06d46944d"; };
+    edition = "2015";
+    libName = "miow";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A zero overhead I/O library for Windows, focusing on IOCP and Async I/O\\nabstractions.\\n\"\ndocumentation = \"https://docs.rs/miow/0.1/x86_64-pc-windows-msvc/miow/\"\nhomepage = \"https://github.com/alexcrichton/miow\"\nkeywords = [\"iocp\", \"windows\", \"io\", \"overlapped\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"miow\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/miow\"\nversion = \"0.2.2\"\n";
+    meta = {
+      description = "A zero overhead I/O library for Windows, focusing on IOCP and Async I/O\nabstractions.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/miow";
+    };
     dependencies = {
       kernel32 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".kernel32-sys."0.2.2" { inherit profileName; };
       net2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".net2."0.2.37" { inherit profileName; };
@@ -590,17 +1044,27 @@ in
     version = "0.2.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6fcc7939b5edc4e4f86b1b4a04bb1498afaaf871b1a6691838ed06fcb48d3a3f"; };
-    dependencies = {
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "lazy_static" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if !(hostPlatform.parsed.kernel.name == "windows" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "log" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.11" { inherit profileName; };
-      ${ if !(hostPlatform.parsed.kernel.name == "windows" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "openssl" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl."0.10.31" { inherit profileName; };
-      ${ if !(hostPlatform.parsed.kernel.name == "windows" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
-      ${ if !(hostPlatform.parsed.kernel.name == "windows" || hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios") then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "windows" then "schannel" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".schannel."0.1.19" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "security_framework" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".security-framework."2.0.0" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "security_framework_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".security-framework-sys."2.0.0" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "darwin" || hostPlatform.parsed.kernel.name == "ios" then "tempfile" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.1.0" { inherit profileName; };
+    edition = "2015";
+    libName = "native_tls";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ndescription = \"A wrapper over a platform's native TLS implementation\"\nlicense = \"MIT/Apache-2.0\"\nname = \"native-tls\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/rust-native-tls\"\nversion = \"0.2.6\"\n";
+    meta = {
+      description = "A wrapper over a platform's native TLS implementation";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/rust-native-tls";
+    };
+    dependencies = {
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "lazy_static" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
+      ${ if !(hostPlatform.isMacOS || hostPlatform.isWindows || hostPlatform.isiOS) then "log" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.11" { inherit profileName; };
+      ${ if !(hostPlatform.isMacOS || hostPlatform.isWindows || hostPlatform.isiOS) then "openssl" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl."0.10.31" { inherit profileName; };
+      ${ if !(hostPlatform.isMacOS || hostPlatform.isWindows || hostPlatform.isiOS) then "openssl_probe" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-probe."0.1.2" { inherit profileName; };
+      ${ if !(hostPlatform.isMacOS || hostPlatform.isWindows || hostPlatform.isiOS) then "openssl_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" { inherit profileName; };
+      ${ if hostPlatform.isWindows then "schannel" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".schannel."0.1.19" { inherit profileName; };
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "security_framework" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".security-framework."2.0.0" { inherit profileName; };
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "security_framework_sys" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".security-framework-sys."2.0.0" { inherit profileName; };
+      ${ if hostPlatform.isMacOS || hostPlatform.isiOS then "tempfile" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tempfile."3.1.0" { inherit profileName; };
     };
   });
   
@@ -609,13 +1073,22 @@ in
     version = "0.2.37";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "391630d12b68002ae1e25e8f974306474966550ad82dac6886fb8910c19568ae"; };
+    edition = "2015";
+    libName = "net2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Extensions to the standard library's networking types as proposed in RFC 1158.\\n\"\ndocumentation = \"https://docs.rs/net2/~0.2\"\nhomepage = \"https://github.com/deprecrated/net2-rs\"\ninclude = [\"Cargo.toml\", \"LICENSE-APACHE\", \"LICENSE-MIT\", \"README.md\", \"src/**/*.rs\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"net2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/deprecrated/net2-rs\"\nversion = \"0.2.37\"\n";
+    meta = {
+      description = "Extensions to the standard library's networking types as proposed in RFC 1158.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/deprecrated/net2-rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "duration" ]
     ];
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" || hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "wasi" then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
+      ${ if hostPlatform.isRedox || hostPlatform.isUnix || hostPlatform.isWasi then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -625,6 +1098,16 @@ in
     version = "0.10.31";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8d008f51b1acffa0d3450a68606e6a51c123012edaacb0f4e1426bd978869187"; };
+    edition = "2015";
+    libName = "openssl";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\"]\ncategories = [\"cryptography\", \"api-bindings\"]\ndescription = \"OpenSSL bindings\"\nkeywords = [\"crypto\", \"tls\", \"ssl\", \"dtls\"]\nlicense = \"Apache-2.0\"\nname = \"openssl\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/rust-openssl\"\nversion = \"0.10.31\"\n";
+    meta = {
+      description = "OpenSSL bindings";
+      license = [ lib.licenses.asl20 ];
+      homepage = "https://github.com/sfackler/rust-openssl";
+    };
     dependencies = {
       bitflags = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bitflags."1.2.1" { inherit profileName; };
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
@@ -640,6 +1123,15 @@ in
     version = "0.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "77af24da69f9d9341038eba93a073b1fdaaa1b788221b00a69bce9e762cb32de"; };
+    edition = "2015";
+    libName = "openssl_probe";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Tool for helping to find SSL certificate locations on the system for OpenSSL\\n\"\nhomepage = \"https://github.com/alexcrichton/openssl-probe\"\nlicense = \"MIT/Apache-2.0\"\nname = \"openssl-probe\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/openssl-probe\"\nversion = \"0.1.2\"\n";
+    meta = {
+      description = "Tool for helping to find SSL certificate locations on the system for OpenSSL";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/openssl-probe";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".openssl-sys."0.9.59" = overridableMkRustCrate (profileName: rec {
@@ -647,6 +1139,17 @@ in
     version = "0.9.59";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "de52d8eabd217311538a39bba130d7dea1f1e118010fee7a033d966845e7d5fe"; };
+    edition = "2015";
+    libName = "openssl_sys";
+    libCrateTypes = [ "lib" ];
+    links = "openssl";
+    buildScript = "build/main.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"Steven Fackler <sfackler@gmail.com>\"]\nbuild = \"build/main.rs\"\ncategories = [\"cryptography\", \"external-ffi-bindings\"]\ndescription = \"FFI bindings to OpenSSL\"\nlicense = \"MIT\"\nlinks = \"openssl\"\nname = \"openssl-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/sfackler/rust-openssl\"\nversion = \"0.9.59\"\n[package.metadata.pkg-config]\nopenssl = \"1.0.1\"\n";
+    meta = {
+      description = "FFI bindings to OpenSSL";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/sfackler/rust-openssl";
+    };
     dependencies = {
       libc = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
     };
@@ -663,6 +1166,15 @@ in
     version = "2.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d4fd5641d01c8f18a23da7b6fe29298ff4b55afcccdf78973b24cf3175fee32e"; };
+    edition = "2015";
+    libName = "percent_encoding";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\npath = \"lib.rs\"\ntest = false\n\n[package]\nauthors = [\"The rust-url developers\"]\ndescription = \"Percent encoding and decoding\"\nlicense = \"MIT/Apache-2.0\"\nname = \"percent-encoding\"\nrepository = \"https://github.com/servo/rust-url/\"\nversion = \"2.1.0\"\n";
+    meta = {
+      description = "Percent encoding and decoding";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url/";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".pin-project."0.4.27" = overridableMkRustCrate (profileName: rec {
@@ -670,6 +1182,15 @@ in
     version = "0.4.27";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2ffbc8e94b38ea3d2d8ba92aea2983b503cd75d0888d75b86bb37970b5698e15"; };
+    edition = "2018";
+    libName = "pin_project";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"A crate for safe and ergonomic pin-projection.\\n\"\ndocumentation = \"https://docs.rs/pin-project\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project\"\nkeywords = [\"pin\", \"macros\", \"attribute\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project\"\nreadme = \"README.md\"\nrepository = \"https://github.com/taiki-e/pin-project\"\nversion = \"0.4.27\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "A crate for safe and ergonomic pin-projection.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project";
+    };
     dependencies = {
       pin_project_internal = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-internal."0.4.27" { profileName = "__noProfile"; };
     };
@@ -680,6 +1201,15 @@ in
     version = "1.0.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9ccc2237c2c489783abd8c4c80e5450fc0e98644555b1364da68cc29aa151ca7"; };
+    edition = "2018";
+    libName = "pin_project";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"A crate for safe and ergonomic pin-projection.\\n\"\ndocumentation = \"https://docs.rs/pin-project\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project\"\nkeywords = [\"pin\", \"macros\", \"attribute\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project\"\nreadme = \"README.md\"\nrepository = \"https://github.com/taiki-e/pin-project\"\nversion = \"1.0.2\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "A crate for safe and ergonomic pin-projection.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project";
+    };
     dependencies = {
       pin_project_internal = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".pin-project-internal."1.0.2" { profileName = "__noProfile"; };
     };
@@ -690,6 +1220,16 @@ in
     version = "0.4.27";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "65ad2ae56b6abe3a1ee25f15ee605bacadb9a764edaba9c2bf4103800d4a1895"; };
+    edition = "2018";
+    libName = "pin_project_internal";
+    libCrateTypes = [ "proc-macro" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"An internal crate to support pin_project - do not use directly\\n\"\ndocumentation = \"https://docs.rs/pin-project-internal\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project\"\nkeywords = [\"pin\", \"macros\", \"attribute\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project-internal\"\nrepository = \"https://github.com/taiki-e/pin-project\"\nversion = \"0.4.27\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "An internal crate to support pin_project - do not use directly";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project";
+    };
     dependencies = {
       proc_macro2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.24" { inherit profileName; };
       quote = rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.7" { inherit profileName; };
@@ -702,6 +1242,15 @@ in
     version = "1.0.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f8e8d2bf0b23038a4424865103a4df472855692821aab4e4f5c3312d461d9e5f"; };
+    edition = "2018";
+    libName = "pin_project_internal";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"Implementation detail of the `pin-project` crate.\\n\"\ndocumentation = \"https://docs.rs/pin-project-internal\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project\"\nkeywords = [\"pin\", \"macros\", \"attribute\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project-internal\"\nrepository = \"https://github.com/taiki-e/pin-project\"\nversion = \"1.0.2\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Implementation detail of the `pin-project` crate.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project";
+    };
     dependencies = {
       proc_macro2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.24" { inherit profileName; };
       quote = rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.7" { inherit profileName; };
@@ -714,6 +1263,15 @@ in
     version = "0.1.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c917123afa01924fc84bb20c4c03f004d9c38e5127e3c039bbf7f4b9c76a2f6b"; };
+    edition = "2018";
+    libName = "pin_project_lite";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"A lightweight version of pin-project written with declarative macros.\\n\"\ndocumentation = \"https://docs.rs/pin-project-lite\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project-lite\"\nkeywords = [\"pin\", \"macros\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project-lite\"\nreadme = \"README.md\"\nrepository = \"https://github.com/taiki-e/pin-project-lite\"\nversion = \"0.1.11\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "A lightweight version of pin-project written with declarative macros.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project-lite";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".pin-project-lite."0.2.0" = overridableMkRustCrate (profileName: rec {
@@ -721,6 +1279,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6b063f57ec186e6140e2b8b6921e5f1bd89c7356dda5b33acc5401203ca6131c"; };
+    edition = "2018";
+    libName = "pin_project_lite";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Taiki Endo <te316e89@gmail.com>\"]\ncategories = [\"no-std\", \"rust-patterns\"]\ndescription = \"A lightweight version of pin-project written with declarative macros.\\n\"\ndocumentation = \"https://docs.rs/pin-project-lite\"\nedition = \"2018\"\nhomepage = \"https://github.com/taiki-e/pin-project-lite\"\nkeywords = [\"pin\", \"macros\"]\nlicense = \"Apache-2.0 OR MIT\"\nname = \"pin-project-lite\"\nreadme = \"README.md\"\nrepository = \"https://github.com/taiki-e/pin-project-lite\"\nversion = \"0.2.0\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "A lightweight version of pin-project written with declarative macros.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/taiki-e/pin-project-lite";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".pin-utils."0.1.0" = overridableMkRustCrate (profileName: rec {
@@ -728,6 +1295,15 @@ in
     version = "0.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8b870d8c151b6f2fb93e84a13146138f05d02ed11c7e7c54f8826aaaf7c9f184"; };
+    edition = "2018";
+    libName = "pin_utils";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Josef Brandl <mail@josefbrandl.de>\"]\ndescription = \"Utilities for pinning\\n\"\ndocumentation = \"https://docs.rs/pin-utils\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"pin-utils\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang-nursery/pin-utils\"\nversion = \"0.1.0\"\n";
+    meta = {
+      description = "Utilities for pinning";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang-nursery/pin-utils";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.19" = overridableMkRustCrate (profileName: rec {
@@ -735,6 +1311,15 @@ in
     version = "0.3.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3831453b3449ceb48b6d9c7ad7c96d5ea673e9b470a1dc578c2ce6521230884c"; };
+    edition = "2015";
+    libName = "pkg_config";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A library to run the pkg-config system tool at build time in order to be used in\\nCargo build scripts.\\n\"\ndocumentation = \"https://docs.rs/pkg-config\"\nkeywords = [\"build-dependencies\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"pkg-config\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-lang/pkg-config-rs\"\nversion = \"0.3.19\"\n";
+    meta = {
+      description = "A library to run the pkg-config system tool at build time in order to be used in\nCargo build scripts.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rust-lang/pkg-config-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".ppv-lite86."0.2.10" = overridableMkRustCrate (profileName: rec {
@@ -742,6 +1327,15 @@ in
     version = "0.2.10";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ac74c624d6b2d21f425f752262f42188365d7b8ff1aff74c82e45136510a4857"; };
+    edition = "2018";
+    libName = "ppv_lite86";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The CryptoCorrosion Contributors\"]\ncategories = [\"cryptography\", \"no-std\"]\ndescription = \"Implementation of the crypto-simd API for x86\"\nedition = \"2018\"\nkeywords = [\"crypto\", \"simd\", \"x86\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"ppv-lite86\"\nrepository = \"https://github.com/cryptocorrosion/cryptocorrosion\"\nversion = \"0.2.10\"\n";
+    meta = {
+      description = "Implementation of the crypto-simd API for x86";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/cryptocorrosion/cryptocorrosion";
+    };
     features = builtins.concatLists [
       [ "simd" ]
       [ "std" ]
@@ -753,6 +1347,16 @@ in
     version = "1.0.24";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1e0704ee1a7e00d7bb417d0770ea303c1bccbabf0ef1667dae92b5967f5f8a71"; };
+    edition = "2018";
+    libName = "proc_macro2";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"A substitute implementation of the compiler's `proc_macro` API to decouple\\ntoken-based libraries from the procedural macro use case.\\n\"\ndocumentation = \"https://docs.rs/proc-macro2\"\nedition = \"2018\"\nkeywords = [\"macros\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"proc-macro2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/proc-macro2\"\nversion = \"1.0.24\"\n[package.metadata.docs.rs]\nrustc-args = [\"--cfg\", \"procmacro2_semver_exempt\"]\nrustdoc-args = [\"--cfg\", \"procmacro2_semver_exempt\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"span-locations\"]\n";
+    meta = {
+      description = "A substitute implementation of the compiler's `proc_macro` API to decouple\ntoken-based libraries from the procedural macro use case.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/proc-macro2";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "proc-macro" ]
@@ -767,6 +1371,15 @@ in
     version = "1.0.7";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "aa563d17ecb180e500da1cfd2b028310ac758de548efdd203e18f283af693f37"; };
+    edition = "2018";
+    libName = "quote";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"Quasi-quoting macro quote!(...)\"\ndocumentation = \"https://docs.rs/quote/\"\nedition = \"2018\"\ninclude = [\"Cargo.toml\", \"src/**/*.rs\", \"tests/**/*.rs\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"syn\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"quote\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/quote\"\nversion = \"1.0.7\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Quasi-quoting macro quote!(...)";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/quote";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "proc-macro" ]
@@ -781,6 +1394,15 @@ in
     version = "0.7.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "6a6b1679d49b24bbfe0c803429aa1874472f50d9b363131f0e89fc356b544d03"; };
+    edition = "2018";
+    libName = "rand";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\"]\nautobenches = true\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"Random number generators and other randomness functionality.\\n\"\ndocumentation = \"https://rust-random.github.io/rand/\"\nedition = \"2018\"\nexclude = [\"/utils/*\", \"/.travis.yml\", \"/appveyor.yml\", \".gitignore\"]\nhomepage = \"https://crates.io/crates/rand\"\nkeywords = [\"random\", \"rng\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.7.3\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Random number generators and other randomness functionality.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -803,6 +1425,15 @@ in
     version = "0.2.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f4c8ed856279c9737206bf725bf36935d8666ead7aa69b52be55af369d193402"; };
+    edition = "2018";
+    libName = "rand_chacha";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\", \"The CryptoCorrosion Contributors\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"ChaCha random number generator\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_chacha/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_chacha\"\nkeywords = [\"random\", \"rng\", \"chacha\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand_chacha\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.2.2\"\n";
+    meta = {
+      description = "ChaCha random number generator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_chacha";
+    };
     features = builtins.concatLists [
       [ "std" ]
     ];
@@ -817,6 +1448,15 @@ in
     version = "0.5.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "90bde5296fc891b0cef12a6d03ddccc162ce7b2aff54160af9338f8d40df6d19"; };
+    edition = "2018";
+    libName = "rand_core";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\", \"The Rust Project Developers\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"Core random number generator traits and tools for implementation.\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_core/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_core\"\nkeywords = [\"random\", \"rng\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"rand_core\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.5.1\"\n";
+    meta = {
+      description = "Core random number generator traits and tools for implementation.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_core";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "getrandom" ]
@@ -832,6 +1472,15 @@ in
     version = "0.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ca3129af7b92a17112d59ad498c6f81eaf463253766b90396d39ea7a39d6613c"; };
+    edition = "2018";
+    libName = "rand_hc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rand Project Developers\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"HC128 random number generator\\n\"\ndocumentation = \"https://rust-random.github.io/rand/rand_hc/\"\nedition = \"2018\"\nhomepage = \"https://crates.io/crates/rand_hc\"\nkeywords = [\"random\", \"rng\", \"hc128\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"rand_hc\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rust-random/rand\"\nversion = \"0.2.0\"\n";
+    meta = {
+      description = "HC128 random number generator";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://crates.io/crates/rand_hc";
+    };
     dependencies = {
       rand_core = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand_core."0.5.1" { inherit profileName; };
     };
@@ -842,6 +1491,15 @@ in
     version = "0.1.57";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "41cc0f7e4d5d4544e8861606a285bb08d3e70712ccc7d2b84d7c0ccfaf4b05ce"; };
+    edition = "2015";
+    libName = "syscall";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"syscall\"\n\n[package]\nauthors = [\"Jeremy Soller <jackpot51@gmail.com>\"]\ndescription = \"A Rust library to access raw Redox system calls\"\ndocumentation = \"https://docs.rs/redox_syscall\"\nlicense = \"MIT\"\nname = \"redox_syscall\"\nrepository = \"https://gitlab.redox-os.org/redox-os/syscall\"\nversion = \"0.1.57\"\n";
+    meta = {
+      description = "A Rust library to access raw Redox system calls";
+      license = [ lib.licenses.mit ];
+      homepage = "https://gitlab.redox-os.org/redox-os/syscall";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".remove_dir_all."0.5.3" = overridableMkRustCrate (profileName: rec {
@@ -849,6 +1507,15 @@ in
     version = "0.5.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3acd125665422973a33ac9d3dd2df85edad0f4ae9b00dafb1a05e43a9f5ef8e7"; };
+    edition = "2015";
+    libName = "remove_dir_all";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Aaronepower <theaaronepower@gmail.com>\"]\ncategories = [\"filesystem\"]\ndescription = \"A safe, reliable implementation of remove_dir_all for Windows\"\ninclude = [\"Cargo.toml\", \"LICENCE-APACHE\", \"LICENCE-MIT\", \"src/**/*\", \"README.md\"]\nkeywords = [\"utility\", \"filesystem\", \"remove_dir\", \"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"remove_dir_all\"\nreadme = \"README.md\"\nrepository = \"https://github.com/XAMPPRocky/remove_dir_all.git\"\nversion = \"0.5.3\"\n";
+    meta = {
+      description = "A safe, reliable implementation of remove_dir_all for Windows";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/XAMPPRocky/remove_dir_all.git";
+    };
     dependencies = {
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -859,6 +1526,15 @@ in
     version = "0.10.9";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "fb15d6255c792356a0f578d8a645c677904dc02e862bebe2ecc18e0c01b9a0ce"; };
+    edition = "2018";
+    libName = "reqwest";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[example]]\nname = \"blocking\"\npath = \"examples/blocking.rs\"\nrequired-features = [\"blocking\"]\n\n[[example]]\nname = \"json_dynamic\"\npath = \"examples/json_dynamic.rs\"\nrequired-features = [\"json\"]\n\n[[example]]\nname = \"json_typed\"\npath = \"examples/json_typed.rs\"\nrequired-features = [\"json\"]\n\n[[example]]\nname = \"tor_socks\"\npath = \"examples/tor_socks.rs\"\nrequired-features = [\"socks\"]\n\n[[example]]\nname = \"form\"\npath = \"examples/form.rs\"\n\n[[example]]\nname = \"simple\"\npath = \"examples/simple.rs\"\n\n[[test]]\nname = \"blocking\"\npath = \"tests/blocking.rs\"\nrequired-features = [\"blocking\"]\n\n[[test]]\nname = \"cookie\"\npath = \"tests/cookie.rs\"\nrequired-features = [\"cookies\"]\n\n[[test]]\nname = \"gzip\"\npath = \"tests/gzip.rs\"\nrequired-features = [\"gzip\"]\n\n[[test]]\nname = \"brotli\"\npath = \"tests/brotli.rs\"\nrequired-features = [\"brotli\"]\n\n[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\nautotests = true\ncategories = [\"web-programming::http-client\", \"wasm\"]\ndescription = \"higher level HTTP client library\"\ndocumentation = \"https://docs.rs/reqwest\"\nedition = \"2018\"\nkeywords = [\"http\", \"request\", \"client\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"reqwest\"\nreadme = \"README.md\"\nrepository = \"https://github.com/seanmonstar/reqwest\"\nversion = \"0.10.9\"\n[package.metadata.docs.rs]\nall-features = true\ntargets = [\"x86_64-unknown-linux-gnu\", \"wasm32-unknown-unknown\"]\n\n[package.metadata.playground]\nfeatures = [\"blocking\", \"cookies\", \"json\"]\n";
+    meta = {
+      description = "higher level HTTP client library";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/seanmonstar/reqwest";
+    };
     features = builtins.concatLists [
       [ "__tls" ]
       [ "default" ]
@@ -904,6 +1580,16 @@ in
     version = "1.0.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "71d301d4193d031abdd79ff7e3dd721168a9572ef3fe51a1517aba235bd8f86e"; };
+    edition = "2018";
+    libName = "ryu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ndescription = \"Fast floating point to string conversion\"\ndocumentation = \"https://docs.rs/ryu\"\nedition = \"2018\"\nlicense = \"Apache-2.0 OR BSL-1.0\"\nname = \"ryu\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/ryu\"\nversion = \"1.0.5\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Fast floating point to string conversion";
+      license = [ lib.licenses.asl20 lib.licenses.boost ];
+      homepage = "https://github.com/dtolnay/ryu";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".schannel."0.1.19" = overridableMkRustCrate (profileName: rec {
@@ -911,6 +1597,15 @@ in
     version = "0.1.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "8f05ba609c234e60bee0d547fe94a4c7e9da733d1c962cf6e59efa4cd9c8bc75"; };
+    edition = "2015";
+    libName = "schannel";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\", \"Steffen Butzer <steffen.butzer@outlook.com>\"]\ndescription = \"Schannel bindings for rust, allowing SSL/TLS (e.g. https) without openssl\"\ndocumentation = \"https://docs.rs/schannel/0/x86_64-pc-windows-gnu/schannel/\"\nkeywords = [\"windows\", \"schannel\", \"tls\", \"ssl\", \"https\"]\nlicense = \"MIT\"\nname = \"schannel\"\nreadme = \"README.md\"\nrepository = \"https://github.com/steffengy/schannel-rs\"\nversion = \"0.1.19\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-pc-windows-msvc\"\n";
+    meta = {
+      description = "Schannel bindings for rust, allowing SSL/TLS (e.g. https) without openssl";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/steffengy/schannel-rs";
+    };
     dependencies = {
       lazy_static = rustPackages."registry+https://github.com/rust-lang/crates.io-index".lazy_static."1.4.0" { inherit profileName; };
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
@@ -922,6 +1617,15 @@ in
     version = "1.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ea6a9290e3c9cf0f18145ef7ffa62d68ee0bf5fcd651017e586dc7fd5da448c2"; };
+    edition = "2015";
+    libName = "scoped_tls";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Library implementation of the standard library's old `scoped_thread_local!`\\nmacro for providing scoped access to thread local storage (TLS) so any type can\\nbe stored into TLS.\\n\"\ndocumentation = \"https://docs.rs/scoped-tls\"\nhomepage = \"https://github.com/alexcrichton/scoped-tls\"\nlicense = \"MIT/Apache-2.0\"\nname = \"scoped-tls\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/scoped-tls\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "Library implementation of the standard library's old `scoped_thread_local!`\nmacro for providing scoped access to thread local storage (TLS) so any type can\nbe stored into TLS.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/scoped-tls";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".security-framework."2.0.0" = overridableMkRustCrate (profileName: rec {
@@ -929,6 +1633,15 @@ in
     version = "2.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c1759c2e3c8580017a484a7ac56d3abc5a6c1feadf88db2f3633f12ae4268c69"; };
+    edition = "2018";
+    libName = "security_framework";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[example]]\nname = \"client\"\n\n[[example]]\nname = \"find_internet_password\"\n\n[[example]]\nname = \"set_internet_password\"\n\n[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\", \"Kornel <kornel@geekhood.net>\"]\ncategories = [\"os::macos-apis\", \"cryptography\", \"api-bindings\"]\ndescription = \"Security.framework bindings for macOS and iOS\"\ndocumentation = \"https://kornelski.github.io/rust-security-framework/doc/security_framework/\"\nedition = \"2018\"\nexclude = [\"test/*\"]\nhomepage = \"https://lib.rs/crates/security_framework\"\nkeywords = [\"iOS\", \"TLS\", \"SSL\", \"crypto\", \"keychain\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"security-framework\"\nreadme = \"README.md\"\nrepository = \"https://github.com/kornelski/rust-security-framework\"\nversion = \"2.0.0\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-apple-darwin\", \"aarch64-apple-ios\"]\n";
+    meta = {
+      description = "Security.framework bindings for macOS and iOS";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://lib.rs/crates/security_framework";
+    };
     features = builtins.concatLists [
       [ "OSX_10_9" ]
       [ "default" ]
@@ -947,6 +1660,15 @@ in
     version = "2.0.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f99b9d5e26d2a71633cc4f2ebae7cc9f874044e0c351a27e17892d76dce5678b"; };
+    edition = "2018";
+    libName = "security_framework_sys";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Fackler <sfackler@gmail.com>\", \"Kornel <kornel@geekhood.net>\"]\ncategories = [\"os::macos-apis\", \"external-ffi-bindings\"]\ndescription = \"Apple `Security.framework` low-level FFI bindings\"\ndocumentation = \"https://kornelski.github.io/rust-security-framework/doc/security_framework_sys\"\nedition = \"2018\"\nhomepage = \"https://lib.rs/crates/security-framework-sys\"\nkeywords = [\"ffi\", \"iOS\", \"TLS\", \"SSL\", \"crypto\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"security-framework-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/kornelski/rust-security-framework\"\nversion = \"2.0.0\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-apple-darwin\", \"aarch64-apple-ios\"]\n";
+    meta = {
+      description = "Apple `Security.framework` low-level FFI bindings";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://lib.rs/crates/security-framework-sys";
+    };
     features = builtins.concatLists [
       [ "OSX_10_9" ]
       [ "default" ]
@@ -962,6 +1684,16 @@ in
     version = "1.0.118";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "06c64263859d87aa2eb554587e2d23183398d617427327cf2b3d0ed8c69e4800"; };
+    edition = "2015";
+    libName = "serde";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Erick Tryzelaar <erick.tryzelaar@gmail.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"encoding\"]\ndescription = \"A generic serialization/deserialization framework\"\ndocumentation = \"https://docs.serde.rs/serde/\"\nhomepage = \"https://serde.rs\"\ninclude = [\"build.rs\", \"src/**/*.rs\", \"crates-io.md\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"serde\", \"serialization\", \"no_std\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde\"\nreadme = \"crates-io.md\"\nrepository = \"https://github.com/serde-rs/serde\"\nversion = \"1.0.118\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"derive\", \"rc\"]\n";
+    meta = {
+      description = "A generic serialization/deserialization framework";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://serde.rs";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -973,6 +1705,16 @@ in
     version = "1.0.60";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1500e84d27fe482ed1dc791a56eddc2f230046a040fa908c08bda1d9fb615779"; };
+    edition = "2018";
+    libName = "serde_json";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Erick Tryzelaar <erick.tryzelaar@gmail.com>\", \"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"encoding\"]\ndescription = \"A JSON serialization file format\"\ndocumentation = \"https://docs.serde.rs/serde_json/\"\nedition = \"2018\"\ninclude = [\"build.rs\", \"src/**/*.rs\", \"README.md\", \"LICENSE-APACHE\", \"LICENSE-MIT\"]\nkeywords = [\"json\", \"serde\", \"serialization\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"serde_json\"\nreadme = \"README.md\"\nrepository = \"https://github.com/serde-rs/json\"\nversion = \"1.0.60\"\n[package.metadata.docs.rs]\nfeatures = [\"raw_value\", \"unbounded_depth\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"raw_value\"]\n";
+    meta = {
+      description = "A JSON serialization file format";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/serde-rs/json";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -989,6 +1731,15 @@ in
     version = "0.7.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "edfa57a7f8d9c1d260a549e7224100f6c43d43f9103e06dd8b4095a9b2b43ce9"; };
+    edition = "2018";
+    libName = "serde_urlencoded";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ntest = false\n\n[package]\nauthors = [\"Anthony Ramine <n.oxyde@gmail.com>\"]\ncategories = [\"encoding\", \"web-programming\"]\ndescription = \"`x-www-form-urlencoded` meets Serde\"\ndocumentation = \"https://docs.rs/serde_urlencoded/0.7.0/serde_urlencoded/\"\nedition = \"2018\"\nexclude = [\"/.travis.yml\", \"/bors.toml\"]\nkeywords = [\"serde\", \"serialization\", \"urlencoded\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"serde_urlencoded\"\nrepository = \"https://github.com/nox/serde_urlencoded\"\nversion = \"0.7.0\"\n";
+    meta = {
+      description = "`x-www-form-urlencoded` meets Serde";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/nox/serde_urlencoded";
+    };
     dependencies = {
       form_urlencoded = rustPackages."registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.0.0" { inherit profileName; };
       itoa = rustPackages."registry+https://github.com/rust-lang/crates.io-index".itoa."0.4.6" { inherit profileName; };
@@ -1002,6 +1753,15 @@ in
     version = "0.4.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c111b5bd5695e56cffe5129854aa230b39c93a305372fdbb2668ca2394eea9f8"; };
+    edition = "2015";
+    libName = "slab";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Carl Lerche <me@carllerche.com>\"]\ncategories = [\"memory-management\", \"data-structures\"]\ndescription = \"Pre-allocated storage for a uniform data type\"\ndocumentation = \"https://docs.rs/slab/0.4.2/slab/\"\nhomepage = \"https://github.com/carllerche/slab\"\nkeywords = [\"slab\", \"allocator\"]\nlicense = \"MIT\"\nname = \"slab\"\nreadme = \"README.md\"\nrepository = \"https://github.com/carllerche/slab\"\nversion = \"0.4.2\"\n";
+    meta = {
+      description = "Pre-allocated storage for a uniform data type";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/carllerche/slab";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".socket2."0.3.17" = overridableMkRustCrate (profileName: rec {
@@ -1009,10 +1769,19 @@ in
     version = "0.3.17";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2c29947abdee2a218277abeca306f25789c938e500ea5a9d4b12a5a504466902"; };
-    dependencies = {
-      ${ if hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox" then "cfg_if" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
-      ${ if hostPlatform.isUnix || hostPlatform.parsed.kernel.name == "redox" then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
+    edition = "2018";
+    libName = "socket2";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"Utilities for handling networking sockets with a maximal amount of configuration\\npossible intended.\\n\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/socket2-rs\"\nlicense = \"MIT/Apache-2.0\"\nname = \"socket2\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/socket2-rs\"\nversion = \"0.3.17\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Utilities for handling networking sockets with a maximal amount of configuration\npossible intended.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/socket2-rs";
+    };
+    dependencies = {
+      ${ if hostPlatform.isRedox || hostPlatform.isUnix then "cfg_if" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
+      ${ if hostPlatform.isRedox || hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -1022,6 +1791,16 @@ in
     version = "1.0.54";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9a2af957a63d6bd42255c359c93d9bfdb97076bd3b820897ce55ffbfbf107f44"; };
+    edition = "2018";
+    libName = "syn";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ncategories = [\"development-tools::procedural-macro-helpers\"]\ndescription = \"Parser for Rust source code\"\ndocumentation = \"https://docs.rs/syn\"\nedition = \"2018\"\ninclude = [\"/benches/**\", \"/build.rs\", \"/Cargo.toml\", \"/LICENSE-APACHE\", \"/LICENSE-MIT\", \"/README.md\", \"/src/**\", \"/tests/**\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"syn\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/syn\"\nversion = \"1.0.54\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"doc_cfg\"]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n\n[package.metadata.playground]\nfeatures = [\"full\", \"visit\", \"visit-mut\", \"fold\", \"extra-traits\"]\n";
+    meta = {
+      description = "Parser for Rust source code";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/dtolnay/syn";
+    };
     features = builtins.concatLists [
       [ "clone-impls" ]
       [ "default" ]
@@ -1046,12 +1825,21 @@ in
     version = "3.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7a6e24d9338a0a5be79593e2fa15a648add6138caa803e2d5bc782c371732ca9"; };
+    edition = "2018";
+    libName = "tempfile";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Steven Allen <steven@stebalien.com>\", \"The Rust Project Developers\", \"Ashley Mannix <ashleymannix@live.com.au>\", \"Jason White <jasonaw0@gmail.com>\"]\ndescription = \"A library for managing temporary files and directories.\"\ndocumentation = \"https://docs.rs/tempfile\"\nedition = \"2018\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nhomepage = \"http://stebalien.com/projects/tempfile-rs\"\nkeywords = [\"tempfile\", \"tmpfile\", \"filesystem\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"tempfile\"\nrepository = \"https://github.com/Stebalien/tempfile\"\nversion = \"3.1.0\"\n";
+    meta = {
+      description = "A library for managing temporary files and directories.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "http://stebalien.com/projects/tempfile-rs";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" { inherit profileName; };
       ${ if hostPlatform.isUnix then "libc" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".libc."0.2.81" { inherit profileName; };
       rand = rustPackages."registry+https://github.com/rust-lang/crates.io-index".rand."0.7.3" { inherit profileName; };
-      ${ if hostPlatform.parsed.kernel.name == "redox" then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       remove_dir_all = rustPackages."registry+https://github.com/rust-lang/crates.io-index".remove_dir_all."0.5.3" { inherit profileName; };
+      ${ if hostPlatform.isRedox then "syscall" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".redox_syscall."0.1.57" { inherit profileName; };
       ${ if hostPlatform.isWindows then "winapi" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
   });
@@ -1061,6 +1849,15 @@ in
     version = "1.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ccf8dbc19eb42fba10e8feaaec282fb50e2c14b2726d6301dbfeed0f73306a6f"; };
+    edition = "2018";
+    libName = "tinyvec";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[test]]\nname = \"tinyvec\"\nrequired-features = [\"alloc\"]\n\n[package]\nauthors = [\"Lokathor <zefria@gmail.com>\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"`tinyvec` provides 100% safe vec-like data structures.\"\nedition = \"2018\"\nkeywords = [\"vec\", \"no_std\", \"no-std\"]\nlicense = \"Zlib OR Apache-2.0 OR MIT\"\nname = \"tinyvec\"\nrepository = \"https://github.com/Lokathor/tinyvec\"\nversion = \"1.1.0\"\n[package.metadata.docs.rs]\nfeatures = [\"alloc\", \"grab_spare_slice\", \"rustc_1_40\", \"serde\"]\nrustdoc-args = [\"--cfg\", \"docs_rs\"]\n\n[package.metadata.playground]\nfeatures = [\"alloc\", \"grab_spare_slice\", \"rustc_1_40\", \"serde\"]\n";
+    meta = {
+      description = "`tinyvec` provides 100% safe vec-like data structures.";
+      license = [ lib.licenses.zlib lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/Lokathor/tinyvec";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -1076,6 +1873,15 @@ in
     version = "0.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cda74da7e1a664f795bb1f8a87ec406fb89a02522cf6e50620d016add6dbbf5c"; };
+    edition = "2018";
+    libName = "tinyvec_macros";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Soveu <marx.tomasz@gmail.com>\"]\ndescription = \"Some macros for tiny containers\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0 OR Zlib\"\nname = \"tinyvec_macros\"\nrepository = \"https://github.com/Soveu/tinyvec_macros\"\nversion = \"0.1.0\"\n";
+    meta = {
+      description = "Some macros for tiny containers";
+      license = [ lib.licenses.mit lib.licenses.asl20 lib.licenses.zlib ];
+      homepage = "https://github.com/Soveu/tinyvec_macros";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".tokio."0.2.24" = overridableMkRustCrate (profileName: rec {
@@ -1083,6 +1889,15 @@ in
     version = "0.2.24";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "099837d3464c16a808060bb3f02263b412f6fafcb5d01c533d309985fbeebe48"; };
+    edition = "2018";
+    libName = "tokio";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"asynchronous\", \"network-programming\"]\ndescription = \"An event-driven, non-blocking I/O platform for writing asynchronous I/O\\nbacked applications.\\n\"\ndocumentation = \"https://docs.rs/tokio/0.2.24/tokio/\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nkeywords = [\"io\", \"async\", \"non-blocking\", \"futures\"]\nlicense = \"MIT\"\nname = \"tokio\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/tokio\"\nversion = \"0.2.24\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n\n[package.metadata.playground]\nfeatures = [\"full\"]\n";
+    meta = {
+      description = "An event-driven, non-blocking I/O platform for writing asynchronous I/O\nbacked applications.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     features = builtins.concatLists [
       [ "blocking" ]
       [ "default" ]
@@ -1122,6 +1937,15 @@ in
     version = "0.2.6";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e44da00bfc73a25f814cd8d7e57a68a5c31b74b3152a0a1d1f590c97ed06265a"; };
+    edition = "2018";
+    libName = "tokio_macros";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nauthors = [\"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"asynchronous\"]\ndescription = \"Tokio's proc macros.\\n\"\ndocumentation = \"https://docs.rs/tokio-macros/0.2.6/tokio_macros\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nlicense = \"MIT\"\nname = \"tokio-macros\"\nrepository = \"https://github.com/tokio-rs/tokio\"\nversion = \"0.2.6\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Tokio's proc macros.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     dependencies = {
       proc_macro2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.24" { inherit profileName; };
       quote = rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.7" { inherit profileName; };
@@ -1134,6 +1958,15 @@ in
     version = "0.3.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9a70f4fcd7b3b24fb194f837560168208f669ca8cb70d0c4b862944452396343"; };
+    edition = "2018";
+    libName = "tokio_tls";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"asynchronous\", \"network-programming\"]\ndescription = \"Deprecated in favor of `tokio-naitve-tls`.\\n\\nAn implementation of TLS/SSL streams for Tokio giving an implementation of TLS\\nfor nonblocking I/O streams.\\n\"\ndocumentation = \"https://docs.rs/tokio-tls/0.3.1/tokio_tls/\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nlicense = \"MIT\"\nname = \"tokio-tls\"\nrepository = \"https://github.com/tokio-rs/tokio\"\nversion = \"0.3.1\"\n[package.metadata.docs.rs]\nall-features = true\n";
+    meta = {
+      description = "Deprecated in favor of `tokio-naitve-tls`.\n\nAn implementation of TLS/SSL streams for Tokio giving an implementation of TLS\nfor nonblocking I/O streams.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     dependencies = {
       native_tls = rustPackages."registry+https://github.com/rust-lang/crates.io-index".native-tls."0.2.6" { inherit profileName; };
       tokio = rustPackages."registry+https://github.com/rust-lang/crates.io-index".tokio."0.2.24" { inherit profileName; };
@@ -1145,6 +1978,15 @@ in
     version = "0.3.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "be8242891f2b6cbef26a2d7e8605133c2c554cd35b3e4948ea892d6d68436499"; };
+    edition = "2018";
+    libName = "tokio_util";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"asynchronous\"]\ndescription = \"Additional utilities for working with Tokio.\\n\"\ndocumentation = \"https://docs.rs/tokio-util/0.3.1/tokio_util\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nlicense = \"MIT\"\nname = \"tokio-util\"\nrepository = \"https://github.com/tokio-rs/tokio\"\nversion = \"0.3.1\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Additional utilities for working with Tokio.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     features = builtins.concatLists [
       [ "codec" ]
       [ "default" ]
@@ -1164,6 +2006,15 @@ in
     version = "0.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e987b6bf443f4b5b3b6f38704195592cca41c5bb7aedd3c3693c7081f8289860"; };
+    edition = "2018";
+    libName = "tower_service";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Tower Maintainers <team@tower-rs.com>\"]\ncategories = [\"asynchronous\", \"network-programming\"]\ndescription = \"Trait representing an asynchronous, request / response based, client or server.\\n\"\ndocumentation = \"https://docs.rs/tower-service/0.3.0\"\nedition = \"2018\"\nhomepage = \"https://github.com/tower-rs/tower\"\nlicense = \"MIT\"\nname = \"tower-service\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tower-rs/tower\"\nversion = \"0.3.0\"\n";
+    meta = {
+      description = "Trait representing an asynchronous, request / response based, client or server.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/tower-rs/tower";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".tracing."0.1.22" = overridableMkRustCrate (profileName: rec {
@@ -1171,6 +2022,15 @@ in
     version = "0.1.22";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "9f47026cdc4080c07e49b37087de021820269d996f581aac150ef9e5583eefe3"; };
+    edition = "2018";
+    libName = "tracing";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Eliza Weisman <eliza@buoyant.io>\", \"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"development-tools::debugging\", \"development-tools::profiling\", \"asynchronous\", \"no-std\"]\ndescription = \"Application-level tracing for Rust.\\n\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nkeywords = [\"logging\", \"tracing\", \"metrics\", \"async\"]\nlicense = \"MIT\"\nname = \"tracing\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/tracing\"\nversion = \"0.1.22\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Application-level tracing for Rust.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     features = builtins.concatLists [
       [ "log" ]
       [ "std" ]
@@ -1188,6 +2048,15 @@ in
     version = "0.1.17";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f50de3927f93d202783f4513cda820ab47ef17f624b03c096e86ef00c67e6b5f"; };
+    edition = "2018";
+    libName = "tracing_core";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"development-tools::debugging\", \"development-tools::profiling\", \"asynchronous\"]\ndescription = \"Core primitives for application-level tracing.\\n\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nkeywords = [\"logging\", \"tracing\", \"profiling\"]\nlicense = \"MIT\"\nname = \"tracing-core\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/tracing\"\nversion = \"0.1.17\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Core primitives for application-level tracing.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     features = builtins.concatLists [
       [ "lazy_static" ]
       [ "std" ]
@@ -1202,6 +2071,15 @@ in
     version = "0.2.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ab7bb6f14721aa00656086e9335d363c5c8747bae02ebe32ea2c7dece5689b4c"; };
+    edition = "2018";
+    libName = "tracing_futures";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Eliza Weisman <eliza@buoyant.io>\", \"Tokio Contributors <team@tokio.rs>\"]\ncategories = [\"development-tools::debugging\", \"development-tools::profiling\", \"asynchronous\"]\ndescription = \"Utilities for instrumenting `futures` with `tracing`.\\n\"\nedition = \"2018\"\nhomepage = \"https://tokio.rs\"\nkeywords = [\"logging\", \"profiling\", \"tracing\", \"futures\", \"async\"]\nlicense = \"MIT\"\nname = \"tracing-futures\"\nreadme = \"README.md\"\nrepository = \"https://github.com/tokio-rs/tracing\"\nversion = \"0.2.4\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg\", \"docsrs\"]\n";
+    meta = {
+      description = "Utilities for instrumenting `futures` with `tracing`.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://tokio.rs";
+    };
     features = builtins.concatLists [
       [ "pin-project" ]
       [ "std-future" ]
@@ -1217,6 +2095,15 @@ in
     version = "0.2.3";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "59547bce71d9c38b83d9c0e92b6066c4253371f15005def0c30d9657f50c7642"; };
+    edition = "2015";
+    libName = "try_lock";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ncategories = [\"concurrency\", \"no-std\"]\ndescription = \"A lightweight atomic lock.\"\ndocumentation = \"https://docs.rs/try-lock\"\nhomepage = \"https://github.com/seanmonstar/try-lock\"\nkeywords = [\"lock\", \"atomic\"]\nlicense = \"MIT\"\nname = \"try-lock\"\nreadme = \"README.md\"\nrepository = \"https://github.com/seanmonstar/try-lock\"\nversion = \"0.2.3\"\n";
+    meta = {
+      description = "A lightweight atomic lock.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/seanmonstar/try-lock";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".unicase."2.6.0" = overridableMkRustCrate (profileName: rec {
@@ -1224,6 +2111,16 @@ in
     version = "2.6.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "50f37be617794602aabbeee0be4f259dc1778fabe05e2d67ee8f79326d5cb4f6"; };
+    edition = "2015";
+    libName = "unicase";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\nbuild = \"build.rs\"\ncategories = [\"internationalization\", \"text-processing\", \"no-std\"]\ndescription = \"A case-insensitive wrapper around strings.\"\ndocumentation = \"https://docs.rs/unicase\"\nexclude = [\"scripts/*\"]\nkeywords = [\"lowercase\", \"case\", \"case-insensitive\", \"case-folding\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"unicase\"\nreadme = \"README.md\"\nrepository = \"https://github.com/seanmonstar/unicase\"\nversion = \"2.6.0\"\n";
+    meta = {
+      description = "A case-insensitive wrapper around strings.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/seanmonstar/unicase";
+    };
     buildDependencies = {
       version_check = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.2" { profileName = "__noProfile"; };
     };
@@ -1234,6 +2131,15 @@ in
     version = "0.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "49f2bd0c6468a8230e1db229cff8029217cf623c767ea5d60bfbd42729ea54d5"; };
+    edition = "2015";
+    libName = "unicode_bidi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"unicode_bidi\"\n\n[package]\nauthors = [\"The Servo Project Developers\"]\ndescription = \"Implementation of the Unicode Bidirectional Algorithm\"\ndocumentation = \"http://doc.servo.org/unicode_bidi/\"\nexclude = [\"benches/**\", \"data/**\", \"examples/**\", \"tests/**\", \"tools/**\"]\nkeywords = [\"rtl\", \"unicode\", \"text\", \"layout\", \"bidi\"]\nlicense = \"MIT / Apache-2.0\"\nname = \"unicode-bidi\"\nrepository = \"https://github.com/servo/unicode-bidi\"\nversion = \"0.3.4\"\n";
+    meta = {
+      description = "Implementation of the Unicode Bidirectional Algorithm";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/unicode-bidi";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1247,6 +2153,15 @@ in
     version = "0.1.16";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a13e63ab62dbe32aeee58d1c5408d35c36c392bba5d9d3142287219721afe606"; };
+    edition = "2018";
+    libName = "unicode_normalization";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"kwantam <kwantam@gmail.com>\", \"Manish Goregaokar <manishsmail@gmail.com>\"]\ndescription = \"This crate provides functions for normalization of\\nUnicode strings, including Canonical and Compatible\\nDecomposition and Recomposition, as described in\\nUnicode Standard Annex #15.\\n\"\ndocumentation = \"https://docs.rs/unicode-normalization/\"\nedition = \"2018\"\nexclude = [\"target/*\", \"Cargo.lock\", \"scripts/tmp\", \"*.txt\", \"tests/*\"]\nhomepage = \"https://github.com/unicode-rs/unicode-normalization\"\nkeywords = [\"text\", \"unicode\", \"normalization\", \"decomposition\", \"recomposition\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"unicode-normalization\"\nreadme = \"README.md\"\nrepository = \"https://github.com/unicode-rs/unicode-normalization\"\nversion = \"0.1.16\"\n";
+    meta = {
+      description = "This crate provides functions for normalization of\nUnicode strings, including Canonical and Compatible\nDecomposition and Recomposition, as described in\nUnicode Standard Annex #15.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/unicode-rs/unicode-normalization";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1261,6 +2176,15 @@ in
     version = "0.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f7fe0bb3479651439c9112f72b6c505038574c9fbb575ed1bf3b797fa39dd564"; };
+    edition = "2015";
+    libName = "unicode_xid";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"erick.tryzelaar <erick.tryzelaar@gmail.com>\", \"kwantam <kwantam@gmail.com>\"]\ndescription = \"Determine whether characters have the XID_Start\\nor XID_Continue properties according to\\nUnicode Standard Annex #31.\\n\"\ndocumentation = \"https://unicode-rs.github.io/unicode-xid\"\nexclude = [\"/scripts/*\", \"/.travis.yml\"]\nhomepage = \"https://github.com/unicode-rs/unicode-xid\"\nkeywords = [\"text\", \"unicode\", \"xid\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"unicode-xid\"\nreadme = \"README.md\"\nrepository = \"https://github.com/unicode-rs/unicode-xid\"\nversion = \"0.2.1\"\n";
+    meta = {
+      description = "Determine whether characters have the XID_Start\nor XID_Continue properties according to\nUnicode Standard Annex #31.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/unicode-rs/unicode-xid";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -1271,6 +2195,15 @@ in
     version = "2.2.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5909f2b0817350449ed73e8bcd81c8c3c8d9a7a5d8acba4b27db277f1868976e"; };
+    edition = "2018";
+    libName = "url";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The rust-url developers\"]\ncategories = [\"parser-implementations\", \"web-programming\", \"encoding\"]\ndescription = \"URL library for Rust, based on the WHATWG URL Standard\"\ndocumentation = \"https://docs.rs/url\"\nedition = \"2018\"\ninclude = [\"src/**/*\", \"LICENSE-*\", \"README.md\"]\nkeywords = [\"url\", \"parser\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"url\"\nreadme = \"../README.md\"\nrepository = \"https://github.com/servo/rust-url\"\nversion = \"2.2.0\"\n";
+    meta = {
+      description = "URL library for Rust, based on the WHATWG URL Standard";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/servo/rust-url";
+    };
     dependencies = {
       form_urlencoded = rustPackages."registry+https://github.com/rust-lang/crates.io-index".form_urlencoded."1.0.0" { inherit profileName; };
       idna = rustPackages."registry+https://github.com/rust-lang/crates.io-index".idna."0.2.0" { inherit profileName; };
@@ -1284,6 +2217,15 @@ in
     version = "0.2.11";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b00bca6106a5e23f3eee943593759b7fcddb00554332e856d990c893966879fb"; };
+    edition = "2015";
+    libName = "vcpkg";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Jim McGrath <jimmc2@gmail.com>\"]\ncategories = [\"development-tools::build-utils\"]\ndescription = \"A library to find native dependencies in a vcpkg tree at build\\ntime in order to be used in Cargo build scripts.\\n\"\ndocumentation = \"https://docs.rs/vcpkg\"\nkeywords = [\"build-dependencies\", \"windows\", \"macos\", \"linux\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"vcpkg\"\nreadme = \"../README.md\"\nrepository = \"https://github.com/mcgoo/vcpkg-rs\"\nversion = \"0.2.11\"\n";
+    meta = {
+      description = "A library to find native dependencies in a vcpkg tree at build\ntime in order to be used in Cargo build scripts.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/mcgoo/vcpkg-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.2" = overridableMkRustCrate (profileName: rec {
@@ -1291,6 +2233,15 @@ in
     version = "0.9.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b5a972e5669d67ba988ce3dc826706fb0a8b01471c088cb0b6110b805cc36aed"; };
+    edition = "2015";
+    libName = "version_check";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sergio Benitez <sb@sergio.bz>\"]\ndescription = \"Tiny crate to check the version of the installed/running rustc.\"\ndocumentation = \"https://docs.rs/version_check/\"\nkeywords = [\"version\", \"rustc\", \"minimum\", \"check\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"version_check\"\nreadme = \"README.md\"\nrepository = \"https://github.com/SergioBenitez/version_check\"\nversion = \"0.9.2\"\n";
+    meta = {
+      description = "Tiny crate to check the version of the installed/running rustc.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/SergioBenitez/version_check";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".want."0.3.0" = overridableMkRustCrate (profileName: rec {
@@ -1298,6 +2249,15 @@ in
     version = "0.3.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1ce8a968cb1cd110d136ff8b819a556d6fb6d919363c61534f6860c7eb172ba0"; };
+    edition = "2018";
+    libName = "want";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sean McArthur <sean@seanmonstar.com>\"]\ndescription = \"Detect when another Future wants a result.\"\ndocumentation = \"https://docs.rs/want\"\nedition = \"2018\"\nkeywords = [\"futures\", \"channel\", \"async\"]\nlicense = \"MIT\"\nname = \"want\"\nrepository = \"https://github.com/seanmonstar/want\"\nversion = \"0.3.0\"\n";
+    meta = {
+      description = "Detect when another Future wants a result.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/seanmonstar/want";
+    };
     dependencies = {
       log = rustPackages."registry+https://github.com/rust-lang/crates.io-index".log."0.4.11" { inherit profileName; };
       try_lock = rustPackages."registry+https://github.com/rust-lang/crates.io-index".try-lock."0.2.3" { inherit profileName; };
@@ -1309,6 +2269,15 @@ in
     version = "0.9.0+wasi-snapshot-preview1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cccddf32554fecc6acb585f82a32a72e28b48f8c4c1883ddfeeeaa96f7d8e519"; };
+    edition = "2018";
+    libName = "wasi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Cranelift Project Developers\"]\ncategories = [\"no-std\", \"wasm\"]\ndescription = \"Experimental WASI API bindings for Rust\"\ndocumentation = \"https://docs.rs/wasi\"\nedition = \"2018\"\nkeywords = [\"webassembly\", \"wasm\"]\nlicense = \"Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT\"\nname = \"wasi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/bytecodealliance/wasi\"\nversion = \"0.9.0+wasi-snapshot-preview1\"\n";
+    meta = {
+      description = "Experimental WASI API bindings for Rust";
+      license = "Apache-2.0 WITH LLVM-exception OR Apache-2.0 OR MIT";
+      homepage = "https://github.com/bytecodealliance/wasi";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -1320,6 +2289,16 @@ in
     version = "0.2.69";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "3cd364751395ca0f68cafb17666eee36b63077fb5ecd972bbcd74c90c4bf736e"; };
+    edition = "2018";
+    libName = "wasm_bindgen";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\ntest = false\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ncategories = [\"wasm\"]\ndescription = \"Easy support for interacting between JS and Rust.\\n\"\ndocumentation = \"https://docs.rs/wasm-bindgen\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen\"\nreadme = \"README.md\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen\"\nversion = \"0.2.69\"\n[package.metadata.docs.rs]\nfeatures = [\"serde-serialize\"]\n";
+    meta = {
+      description = "Easy support for interacting between JS and Rust.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "serde" ]
@@ -1341,6 +2320,15 @@ in
     version = "0.2.69";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1114f89ab1f4106e5b55e688b828c0ab0ea593a1ea7c094b141b14cbaaec2d62"; };
+    edition = "2018";
+    libName = "wasm_bindgen_backend";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Backend code generation of the wasm-bindgen tool\\n\"\ndocumentation = \"https://docs.rs/wasm-bindgen-backend\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-backend\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/backend\"\nversion = \"0.2.69\"\n";
+    meta = {
+      description = "Backend code generation of the wasm-bindgen tool";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
     features = builtins.concatLists [
       [ "spans" ]
     ];
@@ -1360,6 +2348,15 @@ in
     version = "0.4.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "1fe9756085a84584ee9457a002b7cdfe0bfff169f45d2591d8be1345a6780e35"; };
+    edition = "2018";
+    libName = "wasm_bindgen_futures";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Bridging the gap between Rust Futures and JavaScript Promises\"\ndocumentation = \"https://docs.rs/wasm-bindgen-futures\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-futures\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/futures\"\nversion = \"0.4.19\"\n";
+    meta = {
+      description = "Bridging the gap between Rust Futures and JavaScript Promises";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
     dependencies = {
       cfg_if = rustPackages."registry+https://github.com/rust-lang/crates.io-index".cfg-if."1.0.0" { inherit profileName; };
       js_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.46" { inherit profileName; };
@@ -1373,6 +2370,15 @@ in
     version = "0.2.69";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7a6ac8995ead1f084a8dea1e65f194d0973800c7f571f6edd70adf06ecf77084"; };
+    edition = "2018";
+    libName = "wasm_bindgen_macro";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Definition of the `#[wasm_bindgen]` attribute, an internal dependency\\n\"\ndocumentation = \"https://docs.rs/wasm-bindgen\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-macro\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/macro\"\nversion = \"0.2.69\"\n";
+    meta = {
+      description = "Definition of the `#[wasm_bindgen]` attribute, an internal dependency";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
     features = builtins.concatLists [
       [ "spans" ]
     ];
@@ -1387,6 +2393,15 @@ in
     version = "0.2.69";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b5a48c72f299d80557c7c62e37e7225369ecc0c963964059509fbafe917c7549"; };
+    edition = "2018";
+    libName = "wasm_bindgen_macro_support";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"The part of the implementation of the `#[wasm_bindgen]` attribute that is not in the shared backend crate\\n\"\ndocumentation = \"https://docs.rs/wasm-bindgen\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-macro-support\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/macro-support\"\nversion = \"0.2.69\"\n";
+    meta = {
+      description = "The part of the implementation of the `#[wasm_bindgen]` attribute that is not in the shared backend crate";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
     features = builtins.concatLists [
       [ "spans" ]
     ];
@@ -1404,6 +2419,17 @@ in
     version = "0.2.69";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "7e7811dd7f9398f14cc76efd356f98f03aa30419dea46aa810d71e819fc97158"; };
+    edition = "2018";
+    libName = "wasm_bindgen_shared";
+    libCrateTypes = [ "lib" ];
+    links = "wasm_bindgen";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Shared support between wasm-bindgen and wasm-bindgen cli, an internal\\ndependency.\\n\"\ndocumentation = \"https://docs.rs/wasm-bindgen-shared\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/\"\nlicense = \"MIT/Apache-2.0\"\nlinks = \"wasm_bindgen\"\nname = \"wasm-bindgen-shared\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/shared\"\nversion = \"0.2.69\"\n";
+    meta = {
+      description = "Shared support between wasm-bindgen and wasm-bindgen cli, an internal\ndependency.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".wasm-bindgen-test."0.3.19" = overridableMkRustCrate (profileName: rec {
@@ -1411,6 +2437,15 @@ in
     version = "0.3.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0355fa0c1f9b792a09b6dcb6a8be24d51e71e6d74972f9eb4a44c4c004d24a25"; };
+    edition = "2018";
+    libName = "wasm_bindgen_test";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ntest = false\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Internal testing crate for wasm-bindgen\"\nedition = \"2018\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-test\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen\"\nversion = \"0.3.19\"\n";
+    meta = {
+      description = "Internal testing crate for wasm-bindgen";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rustwasm/wasm-bindgen";
+    };
     dependencies = {
       console_error_panic_hook = rustPackages."registry+https://github.com/rust-lang/crates.io-index".console_error_panic_hook."0.1.6" { inherit profileName; };
       js_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".js-sys."0.3.46" { inherit profileName; };
@@ -1426,6 +2461,15 @@ in
     version = "0.3.19";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "27e07b46b98024c2ba2f9e83a10c2ef0515f057f2da299c1762a2017de80438b"; };
+    edition = "2018";
+    libName = "wasm_bindgen_test_macro";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Internal testing macro for wasm-bindgen\"\nedition = \"2018\"\nlicense = \"MIT/Apache-2.0\"\nname = \"wasm-bindgen-test-macro\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen\"\nversion = \"0.3.19\"\n";
+    meta = {
+      description = "Internal testing macro for wasm-bindgen";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/rustwasm/wasm-bindgen";
+    };
     dependencies = {
       proc_macro2 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".proc-macro2."1.0.24" { inherit profileName; };
       quote = rustPackages."registry+https://github.com/rust-lang/crates.io-index".quote."1.0.7" { inherit profileName; };
@@ -1437,6 +2481,15 @@ in
     version = "0.3.46";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "222b1ef9334f92a21d3fb53dc3fd80f30836959a90f9274a626d7e06315ba3c3"; };
+    edition = "2018";
+    libName = "web_sys";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\ndoctest = false\ntest = false\n\n[package]\nauthors = [\"The wasm-bindgen Developers\"]\ndescription = \"Bindings for all Web APIs, a procedurally generated crate from WebIDL\\n\"\ndocumentation = \"https://rustwasm.github.io/wasm-bindgen/api/web_sys/\"\nedition = \"2018\"\nhomepage = \"https://rustwasm.github.io/wasm-bindgen/web-sys/index.html\"\nlicense = \"MIT/Apache-2.0\"\nname = \"web-sys\"\nreadme = \"./README.md\"\nrepository = \"https://github.com/rustwasm/wasm-bindgen/tree/master/crates/web-sys\"\nversion = \"0.3.46\"\n[package.metadata.docs.rs]\nall-features = true\nrustdoc-args = [\"--cfg=web_sys_unstable_apis\"]\n";
+    meta = {
+      description = "Bindings for all Web APIs, a procedurally generated crate from WebIDL";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://rustwasm.github.io/wasm-bindgen/web-sys/index.html";
+    };
     features = builtins.concatLists [
       [ "Blob" ]
       [ "BlobPropertyBag" ]
@@ -1465,6 +2518,15 @@ in
     version = "0.2.8";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "167dc9d6949a9b857f3451275e911c3f44255842c1f7a76f33c55103a909087a"; };
+    edition = "2015";
+    libName = "winapi";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\ndescription = \"Types and constants for WinAPI bindings. See README for list of crates providing function bindings.\"\ndocumentation = \"https://retep998.github.io/doc/winapi/\"\ninclude = [\"src/**/*\", \"Cargo.toml\", \"LICENSE.md\"]\nkeywords = [\"windows\", \"ffi\", \"win32\", \"com\", \"directx\"]\nlicense = \"MIT\"\nname = \"winapi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.2.8\"\n";
+    meta = {
+      description = "Types and constants for WinAPI bindings. See README for list of crates providing function bindings.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" = overridableMkRustCrate (profileName: rec {
@@ -1472,6 +2534,16 @@ in
     version = "0.3.9";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "5c839a674fcd7a98952e593242ea400abe93992746761e38641405d28b00f419"; };
+    edition = "2015";
+    libName = "winapi";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ncategories = [\"external-ffi-bindings\", \"no-std\", \"os::windows-apis\"]\ndescription = \"Raw FFI bindings for all of Windows API.\"\ndocumentation = \"https://docs.rs/winapi/\"\ninclude = [\"/src/**/*\", \"/Cargo.toml\", \"/LICENSE-MIT\", \"/LICENSE-APACHE\", \"/build.rs\", \"/README.md\"]\nkeywords = [\"windows\", \"ffi\", \"win32\", \"com\", \"directx\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi\"\nreadme = \"README.md\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.3.9\"\n[package.metadata.docs.rs]\ndefault-target = \"x86_64-pc-windows-msvc\"\nfeatures = [\"everything\", \"impl-debug\", \"impl-default\"]\ntargets = [\"aarch64-pc-windows-msvc\", \"i686-pc-windows-msvc\", \"x86_64-pc-windows-msvc\"]\n";
+    meta = {
+      description = "Raw FFI bindings for all of Windows API.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
     features = builtins.concatLists [
       [ "errhandlingapi" ]
       [ "fileapi" ]
@@ -1499,8 +2571,8 @@ in
       [ "ws2tcpip" ]
     ];
     dependencies = {
-      ${ if hostPlatform.config == "i686-pc-windows-gnu" then "winapi_i686_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" { inherit profileName; };
-      ${ if hostPlatform.config == "x86_64-pc-windows-gnu" then "winapi_x86_64_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-x86_64-pc-windows-gnu."0.4.0" { inherit profileName; };
+      ${ if hostPlatform.isGnu && hostPlatform.isWindows && hostPlatform.parsed.cpu.name == "i686" then "winapi_i686_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" { inherit profileName; };
+      ${ if hostPlatform.isGnu && hostPlatform.isWindows && hostPlatform.isx86_64 then "winapi_x86_64_pc_windows_gnu" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi-x86_64-pc-windows-gnu."0.4.0" { inherit profileName; };
     };
   });
   
@@ -1509,6 +2581,15 @@ in
     version = "0.1.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "2d315eee3b34aca4797b2da6b13ed88266e6d612562a0c46390af8299fc699bc"; };
+    edition = "2015";
+    libName = "build";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nname = \"build\"\n\n[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\ndescription = \"Common code for build.rs in WinAPI -sys crates.\"\nkeywords = [\"Windows\", \"FFI\", \"WinSDK\"]\nlicense = \"MIT\"\nname = \"winapi-build\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.1.1\"\n";
+    meta = {
+      description = "Common code for build.rs in WinAPI -sys crates.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".winapi-i686-pc-windows-gnu."0.4.0" = overridableMkRustCrate (profileName: rec {
@@ -1516,6 +2597,16 @@ in
     version = "0.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ac3b87c63620426dd9b991e5ce0329eff545bccbbb34f3be09ff6fb6ab51b7b6"; };
+    edition = "2015";
+    libName = "winapi_i686_pc_windows_gnu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Import libraries for the i686-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.\"\ninclude = [\"src/*\", \"lib/*\", \"Cargo.toml\", \"build.rs\"]\nkeywords = [\"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi-i686-pc-windows-gnu\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.4.0\"\n";
+    meta = {
+      description = "Import libraries for the i686-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".winapi-x86_64-pc-windows-gnu."0.4.0" = overridableMkRustCrate (profileName: rec {
@@ -1523,6 +2614,16 @@ in
     version = "0.4.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "712e227841d057c1ee1cd2fb22fa7e5a5461ae8e48fa2ca79ec42cfc1931183f"; };
+    edition = "2015";
+    libName = "winapi_x86_64_pc_windows_gnu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Import libraries for the x86_64-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.\"\ninclude = [\"src/*\", \"lib/*\", \"Cargo.toml\", \"build.rs\"]\nkeywords = [\"windows\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"winapi-x86_64-pc-windows-gnu\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.4.0\"\n";
+    meta = {
+      description = "Import libraries for the x86_64-pc-windows-gnu target. Please don't use this crate directly, depend on winapi instead.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".winreg."0.7.0" = overridableMkRustCrate (profileName: rec {
@@ -1530,6 +2631,15 @@ in
     version = "0.7.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0120db82e8a1e0b9fb3345a539c478767c0048d842860994d96113d5b667bd69"; };
+    edition = "2015";
+    libName = "winreg";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[[example]]\nname = \"basic_usage\"\nrequired-features = [\"chrono\"]\n\n[[example]]\nname = \"enum\"\n\n[[example]]\nname = \"transactions\"\nrequired-features = [\"transactions\"]\n\n[[example]]\nname = \"serialization\"\nrequired-features = [\"serialization-serde\"]\n\n[[example]]\nname = \"installed_apps\"\nrequired-features = [\"serialization-serde\"]\n\n[package]\nauthors = [\"Igor Shaula <gentoo90@gmail.com>\"]\ncategories = [\"api-bindings\", \"os::windows-apis\"]\ndescription = \"Rust bindings to MS Windows Registry API\"\ndocumentation = \"https://docs.rs/winreg\"\nkeywords = [\"Windows\", \"WinSDK\", \"Registry\"]\nlicense = \"MIT\"\nname = \"winreg\"\nreadme = \"README.md\"\nrepository = \"https://github.com/gentoo90/winreg-rs\"\nversion = \"0.7.0\"\n[package.metadata.docs.rs]\nall-features = true\ndefault-target = \"x86_64-pc-windows-msvc\"\n";
+    meta = {
+      description = "Rust bindings to MS Windows Registry API";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/gentoo90/winreg-rs";
+    };
     dependencies = {
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.3.9" { inherit profileName; };
     };
@@ -1540,6 +2650,16 @@ in
     version = "0.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "d59cefebd0c892fa2dd6de581e937301d8552cb44489cdff035c6187cb63fa5e"; };
+    edition = "2015";
+    libName = "ws2_32";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nname = \"ws2_32\"\n\n[package]\nauthors = [\"Peter Atashian <retep998@gmail.com>\"]\nbuild = \"build.rs\"\ndescription = \"Contains function definitions for the Windows API library ws2_32. See winapi for types and constants.\"\ndocumentation = \"https://retep998.github.io/doc/ws2_32/\"\nkeywords = [\"windows\", \"ffi\", \"win32\"]\nlicense = \"MIT\"\nname = \"ws2_32-sys\"\nreadme = \"README.md\"\nrepository = \"https://github.com/retep998/winapi-rs\"\nversion = \"0.2.1\"\n";
+    meta = {
+      description = "Contains function definitions for the Windows API library ws2_32. See winapi for types and constants.";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/retep998/winapi-rs";
+    };
     dependencies = {
       winapi = rustPackages."registry+https://github.com/rust-lang/crates.io-index".winapi."0.2.8" { inherit profileName; };
     };
@@ -11,6 +11,9 @@ args@{
   hostPlatform,
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
   mkRustCrate,
   rustLib,
   lib,
@@ -25,9 +28,12 @@ in let
   rootFeatures' = expandFeatures rootFeatures;
   overridableMkRustCrate = f:
     let
-      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures; } // (f profileName)));
-    in { compileMode ? null, profileName ? decideProfile compileMode release }:
-      let drv = drvs.${profileName}; in if compileMode == null then drv else drv.override { inherit compileMode; };
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
 in
 {
   cargo2nixVersion = "0.9.0";
@@ -39,6 +45,15 @@ in
     version = "0.5.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "23b62fc65de8e4e7f52534fb52b0f3ed04746ae267519eef2a83941e8085068b"; };
+    edition = "2018";
+    libName = "arrayvec";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"bluss\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"A vector with fixed capacity, backed by an array (it can be stored on the stack too). Implements fixed capacity ArrayVec and ArrayString.\"\ndocumentation = \"https://docs.rs/arrayvec/\"\nedition = \"2018\"\nkeywords = [\"stack\", \"vector\", \"array\", \"data-structure\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"arrayvec\"\nrepository = \"https://github.com/bluss/arrayvec\"\nversion = \"0.5.2\"\n[package.metadata.docs.rs]\nfeatures = [\"serde\"]\n\n[package.metadata.release]\nno-dev-version = true\ntag-name = \"{{version}}\"\n";
+    meta = {
+      description = "A vector with fixed capacity, backed by an array (it can be stored on the stack too). Implements fixed capacity ArrayVec and ArrayString.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bluss/arrayvec";
+    };
     features = builtins.concatLists [
       [ "array-sizes-33-128" ]
       [ "default" ]
@@ -51,6 +66,15 @@ in
     version = "0.11.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b41b7ea54a0c9d92199de89e20e58d49f02f8e699814ef3fdf266f6f748d15c7"; };
+    edition = "2018";
+    libName = "base64";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alice Maz <alice@alicemaz.com>\", \"Marshall Pierce <marshall@mpierce.org>\"]\ncategories = [\"encoding\"]\ndescription = \"encodes and decodes base64 as bytes or utf8\"\ndocumentation = \"https://docs.rs/base64\"\nedition = \"2018\"\nkeywords = [\"base64\", \"utf8\", \"encode\", \"decode\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"base64\"\nreadme = \"README.md\"\nrepository = \"https://github.com/marshallpierce/rust-base64\"\nversion = \"0.11.0\"\n";
+    meta = {
+      description = "encodes and decodes base64 as bytes or utf8";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/marshallpierce/rust-base64";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -62,6 +86,16 @@ in
     version = "1.2.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "cf1de2fe8c75bc145a2f577add951f8134889b4795d47466a54a5c846d691693"; };
+    edition = "2015";
+    libName = "bitflags";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"The Rust Project Developers\"]\nbuild = \"build.rs\"\ncategories = [\"no-std\"]\ndescription = \"A macro to generate structures which behave like bitflags.\\n\"\ndocumentation = \"https://docs.rs/bitflags\"\nexclude = [\".travis.yml\", \"appveyor.yml\", \"bors.toml\"]\nhomepage = \"https://github.com/bitflags/bitflags\"\nkeywords = [\"bit\", \"bitmask\", \"bitflags\", \"flags\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"bitflags\"\nreadme = \"README.md\"\nrepository = \"https://github.com/bitflags/bitflags\"\nversion = \"1.2.1\"\n[package.metadata.docs.rs]\nfeatures = [\"example_generated\"]\n";
+    meta = {
+      description = "A macro to generate structures which behave like bitflags.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bitflags/bitflags";
+    };
     features = builtins.concatLists [
       [ "default" ]
     ];
@@ -72,6 +106,15 @@ in
     version = "0.6.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "72feb31ffc86498dacdbd0fcebb56138e7177a8cc5cea4516031d15ae85a742e"; };
+    edition = "2018";
+    libName = "bytecount";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\n\n[package]\nauthors = [\"Andre Bogus <bogusandre@gmail.de>\", \"Joshua Landau <joshua@landau.ws>\"]\ncategories = [\"algorithms\", \"no-std\"]\ndescription = \"count occurrences of a given byte, or the number of UTF-8 code points, in a byte slice, fast\"\nedition = \"2018\"\nexclude = [\"/.travis.yml\", \"/appveyor.yml\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"bytecount\"\nreadme = \"README.md\"\nrepository = \"https://github.com/llogiq/bytecount\"\nversion = \"0.6.2\"\n";
+    meta = {
+      description = "count occurrences of a given byte, or the number of UTF-8 code points, in a byte slice, fast";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/llogiq/bytecount";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".cfg-if."0.1.10" = overridableMkRustCrate (profileName: rec {
@@ -79,6 +122,15 @@ in
     version = "0.1.10";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "4785bdd1c96b2a846b2bd7cc02e86b6b3dbf14e7e53446c4f54c92a361040822"; };
+    edition = "2018";
+    libName = "cfg_if";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Crichton <alex@alexcrichton.com>\"]\ndescription = \"A macro to ergonomically define an item depending on a large number of #[cfg]\\nparameters. Structured like an if-else chain, the first matching branch is the\\nitem that gets emitted.\\n\"\ndocumentation = \"https://docs.rs/cfg-if\"\nedition = \"2018\"\nhomepage = \"https://github.com/alexcrichton/cfg-if\"\nlicense = \"MIT/Apache-2.0\"\nname = \"cfg-if\"\nreadme = \"README.md\"\nrepository = \"https://github.com/alexcrichton/cfg-if\"\nversion = \"0.1.10\"\n";
+    meta = {
+      description = "A macro to ergonomically define an item depending on a large number of #[cfg]\nparameters. Structured like an if-else chain, the first matching branch is the\nitem that gets emitted.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/alexcrichton/cfg-if";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".either."1.6.1" = overridableMkRustCrate (profileName: rec {
@@ -86,6 +138,15 @@ in
     version = "1.6.1";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "e78d4f1cc4ae33bbfc157ed5d5a5ef3bc29227303d595861deb238fcec4e9457"; };
+    edition = "2015";
+    libName = "either";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"bluss\"]\ncategories = [\"data-structures\", \"no-std\"]\ndescription = \"The enum `Either` with variants `Left` and `Right` is a general purpose sum type with two cases.\\n\"\ndocumentation = \"https://docs.rs/either/1/\"\nkeywords = [\"data-structure\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"either\"\nreadme = \"README-crates.io.md\"\nrepository = \"https://github.com/bluss/either\"\nversion = \"1.6.1\"\n[package.metadata.docs.rs]\nfeatures = [\"serde\"]\n\n[package.metadata.release]\nno-dev-version = true\ntag-name = \"{{version}}\"\n";
+    meta = {
+      description = "The enum `Either` with variants `Left` and `Right` is a general purpose sum type with two cases.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bluss/either";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".itertools."0.8.2" = overridableMkRustCrate (profileName: rec {
@@ -93,6 +154,15 @@ in
     version = "0.8.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "f56a2d0bc861f9165be4eb3442afd3c236d8a98afd426f65d92324ae1091a484"; };
+    edition = "2015";
+    libName = "itertools";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[lib]\nbench = false\ntest = false\n\n[package]\nauthors = [\"bluss\"]\ncategories = [\"algorithms\", \"rust-patterns\"]\ndescription = \"Extra iterator adaptors, iterator methods, free functions, and macros.\"\ndocumentation = \"https://docs.rs/itertools/\"\nexclude = [\"/bors.toml\"]\nkeywords = [\"iterator\", \"data-structure\", \"zip\", \"product\", \"group-by\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"itertools\"\nrepository = \"https://github.com/bluss/rust-itertools\"\nversion = \"0.8.2\"\n[package.metadata.release]\nno-dev-version = true\n";
+    meta = {
+      description = "Extra iterator adaptors, iterator methods, free functions, and macros.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/bluss/rust-itertools";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "use_std" ]
@@ -107,6 +177,16 @@ in
     version = "0.7.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "db65c6da02e61f55dae90a0ae427b2a5f6b3e8db09f58d10efab23af92592616"; };
+    edition = "2018";
+    libName = "lexical_core";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Alex Huszagh <ahuszagh@gmail.com>\"]\nautobenches = false\nbuild = \"build.rs\"\ncategories = [\"parsing\", \"encoding\", \"no-std\", \"value-formatting\"]\ndescription = \"Lexical, to- and from-string conversion routines.\"\ndocumentation = \"https://docs.rs/lexical-core\"\nedition = \"2018\"\nexclude = [\"fuzz/*\", \"scripts/*\", \"ffi/*\"]\nkeywords = [\"parsing\", \"lexical\", \"encoding\", \"no_std\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"lexical-core\"\nreadme = \"README.md\"\nrepository = \"https://github.com/Alexhuszagh/rust-lexical/tree/master/lexical-core\"\nversion = \"0.7.4\"\n";
+    meta = {
+      description = "Lexical, to- and from-string conversion routines.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/Alexhuszagh/rust-lexical/tree/master/lexical-core";
+    };
     features = builtins.concatLists [
       [ "arrayvec" ]
       [ "correct" ]
@@ -130,6 +210,15 @@ in
     version = "0.7.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "490cc448043f947bae3cbee9c203358d62dbee0db12107a74be5c30ccfd09771"; };
+    edition = "2015";
+    libName = "md5";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Ivan Ukhov <ivan.ukhov@gmail.com>\", \"Kamal Ahmad <shibe@openmailbox.org>\", \"Konstantin Stepanov <milezv@gmail.com>\", \"Lukas Kalbertodt <lukas.kalbertodt@gmail.com>\", \"Nathan Musoke <nathan.musoke@gmail.com>\", \"Scott Mabin <scott@mabez.dev>\", \"Tony Arcieri <bascule@gmail.com>\", \"Wim de With <register@dewith.io>\", \"Yosef Dinerstein <yosefdi@gmail.com>\"]\ncategories = [\"algorithms\", \"cryptography\"]\ndescription = \"The package provides the MD5 hash function.\"\ndocumentation = \"https://docs.rs/md5\"\nhomepage = \"https://github.com/stainless-steel/md5\"\nkeywords = [\"checksum\", \"digest\", \"hash\", \"md5\"]\nlicense = \"Apache-2.0/MIT\"\nname = \"md5\"\nreadme = \"README.md\"\nrepository = \"https://github.com/stainless-steel/md5\"\nversion = \"0.7.0\"\n";
+    meta = {
+      description = "The package provides the MD5 hash function.";
+      license = [ lib.licenses.asl20 lib.licenses.mit ];
+      homepage = "https://github.com/stainless-steel/md5";
+    };
     features = builtins.concatLists [
       [ "default" ]
       [ "std" ]
@@ -141,6 +230,16 @@ in
     version = "2.3.4";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "0ee1c47aaa256ecabcaea351eae4a9b01ef39ed810004e298d2511ed284b1525"; };
+    edition = "2015";
+    libName = "memchr";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[lib]\nbench = false\nname = \"memchr\"\n\n[package]\nauthors = [\"Andrew Gallant <jamslam@gmail.com>\", \"bluss\"]\ndescription = \"Safe interface to memchr.\"\ndocumentation = \"https://docs.rs/memchr/\"\nexclude = [\"/ci/*\", \"/.travis.yml\", \"/Makefile\", \"/appveyor.yml\"]\nhomepage = \"https://github.com/BurntSushi/rust-memchr\"\nkeywords = [\"memchr\", \"char\", \"scan\", \"strchr\", \"string\"]\nlicense = \"Unlicense/MIT\"\nname = \"memchr\"\nreadme = \"README.md\"\nrepository = \"https://github.com/BurntSushi/rust-memchr\"\nversion = \"2.3.4\"\n";
+    meta = {
+      description = "Safe interface to memchr.";
+      license = [ lib.licenses.unlicense lib.licenses.mit ];
+      homepage = "https://github.com/BurntSushi/rust-memchr";
+    };
     features = builtins.concatLists [
       [ "std" ]
       [ "use_std" ]
@@ -152,6 +251,16 @@ in
     version = "5.1.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "ffb4262d26ed83a1c0a33a38fe2bb15797329c85770da05e6b828ddb782627af"; };
+    edition = "2018";
+    libName = "nom";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[example]]\nname = \"json\"\npath = \"examples/json.rs\"\nrequired-features = [\"alloc\"]\n\n[[example]]\nname = \"s_expression\"\npath = \"examples/s_expression.rs\"\nrequired-features = [\"alloc\"]\n\n[[example]]\nname = \"string\"\npath = \"examples/string.rs\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"arithmetic\"\n\n[[test]]\nname = \"arithmetic_ast\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"blockbuf-arithmetic\"\n\n[[test]]\nname = \"css\"\n\n[[test]]\nname = \"custom_errors\"\n\n[[test]]\nname = \"float\"\n\n[[test]]\nname = \"inference\"\n\n[[test]]\nname = \"ini\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"ini_str\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"issues\"\nrequired-features = [\"alloc\", \"regexp_macros\"]\n\n[[test]]\nname = \"json\"\n\n[[test]]\nname = \"mp4\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"multiline\"\nrequired-features = [\"alloc\"]\n\n[[test]]\nname = \"named_args\"\n\n[[test]]\nname = \"overflow\"\n\n[[test]]\nname = \"reborrow_fold\"\n\n[[test]]\nname = \"test1\"\n\n[lib]\nbench = false\n\n[package]\nauthors = [\"contact@geoffroycouprie.com\"]\nautoexamples = false\ncategories = [\"parsing\"]\ndescription = \"A byte-oriented, zero-copy, parser combinators library\"\ndocumentation = \"https://docs.rs/nom\"\nedition = \"2018\"\ninclude = [\"CHANGELOG.md\", \"LICENSE\", \"README.md\", \".gitignore\", \".travis.yml\", \"Cargo.toml\", \"src/*.rs\", \"src/*/*.rs\", \"tests/*.rs\", \"build.rs\"]\nkeywords = [\"parser\", \"parser-combinators\", \"parsing\", \"streaming\", \"bit\"]\nlicense = \"MIT\"\nname = \"nom\"\nreadme = \"README.md\"\nrepository = \"https://github.com/Geal/nom\"\nversion = \"5.1.2\"\n[package.metadata.docs.rs]\nall-features = true\nfeatures = [\"alloc\", \"std\", \"regexp\", \"regexp_macros\", \"lexical\"]\n";
+    meta = {
+      description = "A byte-oriented, zero-copy, parser combinators library";
+      license = [ lib.licenses.mit ];
+      homepage = "https://github.com/Geal/nom";
+    };
     features = builtins.concatLists [
       [ "alloc" ]
       [ "default" ]
@@ -173,6 +282,15 @@ in
     version = "0.9.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "c85620b8046f88a870d93d90fa56904dec76cc79139bfcc22e71e87f0cd2169f"; };
+    edition = "2015";
+    libName = "ructe";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Rasmus Kaj <kaj@kth.se>\"]\ncategories = [\"template-engine\", \"web-programming\"]\ndescription = \"Rust Compiled Templates, efficient type-safe web page templates.\"\ndocumentation = \"https://docs.rs/ructe\"\nkeywords = [\"web\", \"templating\", \"template\", \"html\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"ructe\"\nreadme = \"README.md\"\nrepository = \"https://github.com/kaj/ructe\"\nversion = \"0.9.2\"\n";
+    meta = {
+      description = "Rust Compiled Templates, efficient type-safe web page templates.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/kaj/ructe";
+    };
     dependencies = {
       base64 = rustPackages."registry+https://github.com/rust-lang/crates.io-index".base64."0.11.0" { inherit profileName; };
       bytecount = rustPackages."registry+https://github.com/rust-lang/crates.io-index".bytecount."0.6.2" { inherit profileName; };
@@ -187,6 +305,16 @@ in
     version = "1.0.5";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "71d301d4193d031abdd79ff7e3dd721168a9572ef3fe51a1517aba235bd8f86e"; };
+    edition = "2018";
+    libName = "ryu";
+    libCrateTypes = [ "lib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"David Tolnay <dtolnay@gmail.com>\"]\ndescription = \"Fast floating point to string conversion\"\ndocumentation = \"https://docs.rs/ryu\"\nedition = \"2018\"\nlicense = \"Apache-2.0 OR BSL-1.0\"\nname = \"ryu\"\nreadme = \"README.md\"\nrepository = \"https://github.com/dtolnay/ryu\"\nversion = \"1.0.5\"\n[package.metadata.docs.rs]\ntargets = [\"x86_64-unknown-linux-gnu\"]\n";
+    meta = {
+      description = "Fast floating point to string conversion";
+      license = [ lib.licenses.asl20 lib.licenses.boost ];
+      homepage = "https://github.com/dtolnay/ryu";
+    };
   });
   
   "unknown".static-resources."0.1.0" = overridableMkRustCrate (profileName: rec {
@@ -194,9 +322,15 @@ in
     version = "0.1.0";
     registry = "unknown";
     src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Valentin Brandl <vbrandl@riseup.net>\"]\nbuild = \"build.rs\"\nedition = \"2018\"\nname = \"static-resources\"\nversion = \"0.1.0\"\n";
     buildDependencies = {
       ructe = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".ructe."0.9.2" { profileName = "__noProfile"; };
     };
+    targets = [
+      { kind = "bin"; name = "static-resources"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".static_assertions."1.1.0" = overridableMkRustCrate (profileName: rec {
@@ -204,6 +338,15 @@ in
     version = "1.1.0";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "a2eb9349b6444b326872e140eb1cf5e7c522154d69e7a0ffb0fb81c06b37543f"; };
+    edition = "2015";
+    libName = "static_assertions";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Nikolai Vazquez\"]\ncategories = [\"no-std\", \"rust-patterns\", \"development-tools::testing\"]\ndescription = \"Compile-time assertions to ensure that invariants are met.\"\ndocumentation = \"https://docs.rs/static_assertions/\"\nhomepage = \"https://github.com/nvzqz/static-assertions-rs\"\ninclude = [\"Cargo.toml\", \"src/**/*.rs\", \"README.md\", \"CHANGELOG.md\", \"LICENSE*\"]\nkeywords = [\"assert\", \"static\", \"testing\"]\nlicense = \"MIT OR Apache-2.0\"\nname = \"static_assertions\"\nreadme = \"README.md\"\nrepository = \"https://github.com/nvzqz/static-assertions-rs\"\nversion = \"1.1.0\"\n";
+    meta = {
+      description = "Compile-time assertions to ensure that invariants are met.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/nvzqz/static-assertions-rs";
+    };
   });
   
   "registry+https://github.com/rust-lang/crates.io-index".version_check."0.9.2" = overridableMkRustCrate (profileName: rec {
@@ -211,6 +354,15 @@ in
     version = "0.9.2";
     registry = "registry+https://github.com/rust-lang/crates.io-index";
     src = fetchCratesIo { inherit name version; sha256 = "b5a972e5669d67ba988ce3dc826706fb0a8b01471c088cb0b6110b805cc36aed"; };
+    edition = "2015";
+    libName = "version_check";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Sergio Benitez <sb@sergio.bz>\"]\ndescription = \"Tiny crate to check the version of the installed/running rustc.\"\ndocumentation = \"https://docs.rs/version_check/\"\nkeywords = [\"version\", \"rustc\", \"minimum\", \"check\"]\nlicense = \"MIT/Apache-2.0\"\nname = \"version_check\"\nreadme = \"README.md\"\nrepository = \"https://github.com/SergioBenitez/version_check\"\nversion = \"0.9.2\"\n";
+    meta = {
+      description = "Tiny crate to check the version of the installed/running rustc.";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/SergioBenitez/version_check";
+    };
   });
   
 }
@@ -19,6 +19,9 @@
   rootFeatures ? null,
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? null,
+  hostPlatformDebugAssertions ? null,
 }:
 lib.fix' (self:
   let
@@ -58,6 +61,9 @@ lib.fix' (self:
       ${ if rootFeatures == null then null else "rootFeatures" } = rootFeatures;
       ${ if hostPlatformCpu == null then null else "hostPlatformCpu" } = hostPlatformCpu;
       ${ if hostPlatformFeatures == null then null else "hostPlatformFeatures" } = hostPlatformFeatures;
+      ${ if hostPlatformCfgs == null then null else "hostPlatformCfgs" } = hostPlatformCfgs;
+      ${ if hostPlatformPanic == null then null else "hostPlatformPanic" } = hostPlatformPanic;
+      ${ if hostPlatformDebugAssertions == null then null else "hostPlatformDebugAssertions" } = hostPlatformDebugAssertions;
     });
 
   in packageFunWith { mkRustCrate = mkRustCrate'; buildRustPackages = buildRustPackages'; } // {
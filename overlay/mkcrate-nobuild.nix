@@ -1,5 +1,5 @@
 # Creates a derivation for a crate for inputs propagation only.
-{ lib, stdenv }:
+{ lib, pkgs, stdenv }:
 {
   release, # Compiling in release mode?
   name,
@@ -17,6 +17,19 @@
   rustcBuildFlags ? [ ],
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  edition ? null,
+  libName ? null,
+  libCrateTypes ? [ ],
+  links ? null,
+  buildScript ? null,
+  manifest ? { },
+  targets ? [ ],
+  target ? null,
+  nativeBuildInputAttrs ? [ ],
+  buildInputAttrs ? [ ],
+  env ? { },
+  patches ? [ ],
 }:
 with lib; with builtins;
 let
@@ -31,7 +44,8 @@ let
     runtimeDependencies buildtimeDependencies;
 in stdenv.mkDerivation {
   name = "crate-${name}-${version}";
-  propagatedBuildInputs = concatMap (drv: drv.propagatedBuildInputs) runtimeDependencies;
+  propagatedBuildInputs = concatMap (drv: drv.propagatedBuildInputs) runtimeDependencies
+    ++ map (attr: getAttrFromPath (splitString "." attr) pkgs) buildInputAttrs;
   phases = "installPhase fixupPhase";
   installPhase = "mkdir -p $out";
   preferLocalBuild = true;
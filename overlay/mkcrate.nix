@@ -25,6 +25,19 @@
   rustcBuildFlags ? [ ],
   hostPlatformCpu ? null,
   hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  edition ? null,
+  libName ? null, # The name the library is linked as, `name` with dashes replaced by default.
+  libCrateTypes ? [ ],
+  links ? null, # The `links` key of the manifest.
+  buildScript ? null, # The path of the build script relative to `src`.
+  manifest ? null, # The manifest reduced to the package and its targets, reduced from `Cargo.toml` if null.
+  targets ? [ ], # The targets of a workspace member, as `{ kind, name, requiredFeatures, crateTypes, harness, tested }`.
+  target ? null, # One of `targets` to build on its own, e.g. a single binary or example.
+  nativeBuildInputAttrs ? [ ], # Attribute paths into the build platform's nixpkgs, e.g. "cmake".
+  buildInputAttrs ? [ ], # Attribute paths into nixpkgs, propagated to the crates linking this one.
+  env ? { }, # Environment variables of the build.
+  patches ? [ ], # Patches applied to `src` before building it.
   NIX_DEBUG ? 0,
 }:
 with builtins; with lib;
@@ -60,6 +73,7 @@ let
   ccForHost="${cc}/bin/${targetPrefix}cc";
   cxxForHost="${cc}/bin/${targetPrefix}c++";
   host-triple = realHostTriple stdenv.hostPlatform;
+  fromAttrPaths = pkgs: map (attr: getAttrFromPath (splitString "." attr) pkgs);
   depMapToList = deps:
     flatten
       (sort (a: b: elemAt a 0 < elemAt b 0)
@@ -70,10 +84,12 @@ let
       featuresWithoutDefault = if hasDefaultFeature
         then filter (feature: feature != "default") features
         else features;
-      buildMode = {
-        "test" = "--tests";
-        "bench" = "--benches";
-      }.${compileMode} or "";
+      buildMode = if target != null
+        then (if target.kind == "lib" then "--lib" else "--${target.kind} ${target.name}")
+        else {
+          "test" = "--tests";
+          "bench" = "--benches";
+        }.${compileMode} or "";
       featuresArg = if featuresWithoutDefault == [ ]
         then ""
         else "--features ${concatStringsSep "," featuresWithoutDefault}";
@@ -94,12 +110,13 @@ let
         ])))
     runtimeDependencies buildtimeDependencies;
 
-  drvAttrs = {
-    inherit src version meta NIX_DEBUG;
-    name = "crate-${name}-${version}${optionalString (compileMode != "build") "-${compileMode}"}";
+  drvAttrs = env // {
+    inherit src version meta NIX_DEBUG patches;
+    name = "crate-${name}-${version}${optionalString (compileMode != "build") "-${compileMode}"}${optionalString (target != null) "-${target.kind}-${target.name}"}";
     buildInputs = runtimeDependencies;
-    propagatedBuildInputs = concatMap (drv: drv.propagatedBuildInputs) runtimeDependencies;
-    nativeBuildInputs = [ cargo ] ++ buildtimeDependencies;
+    propagatedBuildInputs = concatMap (drv: drv.propagatedBuildInputs) runtimeDependencies
+      ++ fromAttrPaths pkgs buildInputAttrs;
+    nativeBuildInputs = [ cargo ] ++ buildtimeDependencies ++ fromAttrPaths buildPackages nativeBuildInputAttrs;
 
     depsBuildBuild =
       let inherit (buildPackages.buildPackages) stdenv jq remarshal;
@@ -119,7 +136,13 @@ let
         dependencies
         devDependencies
         buildDependencies
-        features;
+        features
+        edition
+        libName
+        libCrateTypes
+        links
+        buildScript
+        targets;
       shell = pkgs.mkShell (removeAttrs drvAttrs ["src"]);
     };
 
@@ -130,6 +153,7 @@ let
     extraRustcFlags =
       optionals (hostPlatformCpu != null) ([("-Ctarget-cpu=" + hostPlatformCpu)]) ++
       optionals (hostPlatformFeatures != []) [("-Ctarget-feature=" + (concatMapStringsSep "," (feature: "+" + feature) hostPlatformFeatures))] ++
+      concatMap (cfg: [ "--cfg" cfg ]) hostPlatformCfgs ++
       rustcflags;
 
     extraRustcBuildFlags = rustcBuildFlags;
@@ -153,10 +177,13 @@ let
       runHook postConfigure
     '';
 
+    # Cargo.nix files generated before the reduced manifest was passed in have no `manifest`, so
+    # theirs is reduced from the original one while building.
     manifestPatch = toJSON {
       features = genAttrs features (_: [ ]);
       profile.${ decideProfile compileMode release } = profile;
     };
+    reducedManifest = optionalString (manifest != null) (toJSON (manifest // fromJSON manifestPatch));
 
     overrideCargoManifest = ''
       echo "[[package]]" > Cargo.lock
@@ -167,6 +194,9 @@ let
         echo source = \"registry+''${registry}\" >> Cargo.lock
       fi
       mv Cargo.toml Cargo.original.toml
+    '' + (if manifest != null then ''
+      remarshal -if json -of toml <<< "$reducedManifest" > Cargo.toml
+    '' else ''
       # Remarshal was failing on table names of the form:
       # [key."cfg(foo = \"a\", bar = \"b\"))".path]
       # The regex to find or deconstruct these strings must find, in order,
@@ -191,11 +221,19 @@ let
               + $manifestPatch" \
         | jq "del(.[][] | nulls)" \
         | remarshal -if json -of toml > Cargo.toml
-    '';
+    '');
 
     setBuildEnv = ''
       MINOR_RUSTC_VERSION="$(${rustc}/bin/rustc --version | cut -d . -f 2)"
 
+    '' + (if manifest != null then ''
+      if (( MINOR_RUSTC_VERSION < 41 )); then
+        isProcMacro="${optionalString (elem "proc-macro" libCrateTypes) "1"}"
+      fi
+
+      crateName="${if libName != null then libName else replaceChars ["-"] ["_"] name}"
+    '' else ''
+      # Cargo.nix files without `manifest` don't pass the lib target or `links` either.
       if (( MINOR_RUSTC_VERSION < 41 )); then
         isProcMacro="$(
           remarshal -if toml -of json Cargo.original.toml \
@@ -207,6 +245,7 @@ let
         remarshal -if toml -of json Cargo.original.toml \
         | jq -r 'if .lib."name" then .lib."name" else "${replaceChars ["-"] ["_"] name}" end' \
       )"
+    '') + ''
 
       . ${./utils.sh}
 
@@ -262,7 +301,11 @@ let
 
     installPhase = ''
       mkdir -p $out/lib
-      cargo_links="$(remarshal -if toml -of json Cargo.original.toml | jq -r '.package.links | select(. != null)')"
+      ${if manifest != null then ''
+        cargo_links="${optionalString (links != null) links}"
+      '' else ''
+        cargo_links="$(remarshal -if toml -of json Cargo.original.toml | jq -r '.package.links | select(. != null)')"
+      ''}
       if (( MINOR_RUSTC_VERSION < 41 )); then
         install_crate ${host-triple} ${if release then "release" else "debug"}
       else
@@ -1,26 +1,57 @@
-# Run all tests for a crate.
-{ stdenvNoCC }:
+# Run all tests for a workspace crate.
+{ lib, stdenvNoCC }:
 crate:
 env@{
   testCommand ? bin: "${bin}",
+  # Runs the tests of targets with `harness = false`, which don't take libtest's arguments.
+  customHarnessTestCommand ? bin: "${bin}",
   ...
 }:
 let
   testBins = crate { compileMode = "test"; };
+  # The targets `cargo test` would run, leaving out those whose required features are disabled.
+  testTargets = lib.filter
+    (target: target.tested && lib.all (feature: lib.elem feature testBins.features) target.requiredFeatures)
+    testBins.targets;
+  # Test binaries are named after the target's crate name followed by a hash.
+  testBinNames = lib.unique (map (target: {
+    name = lib.replaceStrings [ "-" ] [ "_" ] target.name;
+    inherit (target) harness;
+  }) testTargets);
+  # Cargo.nix files from before targets were emitted list none, so test binaries are found by
+  # their libtest help text instead.
+  runAll = testBins.targets == [ ];
 in
-  stdenvNoCC.mkDerivation ((removeAttrs env [ "testCommand" ]) // {
+  stdenvNoCC.mkDerivation ((removeAttrs env [ "testCommand" "customHarnessTestCommand" ]) // {
     name = "test-${testBins.name}";
     inherit (testBins) src;
     CARGO_MANIFEST_DIR = testBins.src;
     phases = [ "unpackPhase" "buildPhase" ];
     buildPhase = ''
+      ran=0
       for f in ${testBins}/bin/*; do
-        # HACK: cargo produces the crate's main binary in the bin directory if the crate contains example tests.
-        # The `grep` filters out the main binary, which doesn't contain the help string found in test binaries.
-        if [[ -x "$f" ]] && grep "By default, all tests are run in parallel" "$f"; then
-          ${testCommand "$f"}
-        fi
+        [ -e "$f" ] || continue
+        name="$(basename "$f")"
+        ${if runAll then ''
+          # HACK: cargo produces the crate's main binary in the bin directory if the crate contains example tests.
+          # The `grep` filters out the main binary, which doesn't contain the help string found in test binaries.
+          if [[ -x "$f" ]] && grep "By default, all tests are run in parallel" "$f"; then
+            ${testCommand "$f"}
+            ran=$((ran + 1))
+          fi
+        '' else lib.concatMapStrings (bin: ''
+          if [[ "$name" =~ ^${bin.name}-[0-9a-f]{16}$ ]]; then
+            ${(if bin.harness then testCommand else customHarnessTestCommand) "$f"}
+            ran=$((ran + 1))
+          fi
+        '') testBinNames}
       done
+      ${lib.optionalString (runAll || testTargets != [ ]) ''
+        if [ "$ran" -eq 0 ]; then
+          echo "error: no test binary of ${testBins.name} was found to run in ${testBins}/bin" >&2
+          exit 1
+        fi
+      ''}
       touch $out
     '';
   })
@@ -0,0 +1,406 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Result};
+use cargo_platform::Platform;
+use semver::{Version, VersionReq};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::eval::Env;
+use crate::explain::{Explanation, PackageSet};
+use crate::platform;
+use crate::template::{BuildPlan, Crate, Source};
+
+/// An advisory of the RustSec advisory database.
+#[derive(Debug)]
+pub struct Advisory {
+    pub id: String,
+    pub package: String,
+    pub title: String,
+    pub date: String,
+    pub url: Option<String>,
+    pub aliases: Vec<String>,
+    /// The kind of an informational advisory, such as `unmaintained`, which is not a vulnerability.
+    pub informational: Option<String>,
+    patched: Vec<VersionReq>,
+    unaffected: Vec<VersionReq>,
+    /// Whether the platform a crate is built for is affected, or `None` if all are.
+    platform: Option<Platform>,
+}
+
+#[derive(Deserialize)]
+struct Metadata {
+    advisory: AdvisoryMetadata,
+    #[serde(default)]
+    versions: VersionsMetadata,
+    #[serde(default)]
+    affected: AffectedMetadata,
+}
+
+#[derive(Deserialize)]
+struct AdvisoryMetadata {
+    id: String,
+    package: String,
+    date: String,
+    url: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    informational: Option<String>,
+    withdrawn: Option<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct VersionsMetadata {
+    #[serde(default)]
+    patched: Vec<String>,
+    #[serde(default)]
+    unaffected: Vec<String>,
+}
+
+#[derive(Default, Deserialize)]
+struct AffectedMetadata {
+    #[serde(default)]
+    arch: Vec<String>,
+    #[serde(default)]
+    os: Vec<String>,
+}
+
+impl Advisory {
+    /// Parses an advisory in the Markdown format of the database: a TOML code block with the
+    /// metadata, followed by a heading with the title. Returns `None` for withdrawn advisories.
+    fn parse(advisory: &str) -> Result<Option<Self>> {
+        let advisory = advisory.trim_start();
+        let no_front_matter = || anyhow!("the advisory does not start with a TOML code block");
+        let mut parts = advisory
+            .strip_prefix("```toml")
+            .ok_or_else(no_front_matter)?
+            .splitn(2, "\n```");
+        let front_matter = parts.next().ok_or_else(no_front_matter)?;
+        let body = parts.next().ok_or_else(no_front_matter)?;
+        let metadata: Metadata = toml::from_str(front_matter)?;
+        if metadata.advisory.withdrawn.is_some() {
+            return Ok(None);
+        }
+
+        let title = body
+            .lines()
+            .find_map(|line| line.strip_prefix("# "))
+            .ok_or_else(|| anyhow!("the advisory has no title"))?;
+        let requirements = |reqs: &[String]| -> Result<Vec<_>> {
+            reqs.iter()
+                .map(|req| {
+                    VersionReq::parse(req).context(format!("invalid version requirement {}", req))
+                })
+                .collect()
+        };
+
+        // The names of `affected` are the values of the `target_os` and `target_arch` cfgs.
+        let any = |key: &str, values: &[String]| {
+            let values: Vec<_> = values
+                .iter()
+                .map(|v| format!("{} = {:?}", key, v))
+                .collect();
+            format!("any({})", values.join(", "))
+        };
+        let mut conditions = Vec::new();
+        if !metadata.affected.os.is_empty() {
+            conditions.push(any("target_os", &metadata.affected.os));
+        }
+        if !metadata.affected.arch.is_empty() {
+            conditions.push(any("target_arch", &metadata.affected.arch));
+        }
+        let platform = if conditions.is_empty() {
+            None
+        } else {
+            let cfg = format!("cfg(all({}))", conditions.join(", "));
+            Some(Platform::from_str(&cfg)?)
+        };
+
+        Ok(Some(Advisory {
+            id: metadata.advisory.id,
+            package: metadata.advisory.package,
+            title: title.trim().to_string(),
+            date: metadata.advisory.date,
+            url: metadata.advisory.url,
+            aliases: metadata.advisory.aliases,
+            informational: metadata.advisory.informational,
+            patched: requirements(&metadata.versions.patched)?,
+            unaffected: requirements(&metadata.versions.unaffected)?,
+            platform,
+        }))
+    }
+
+    fn affects(&self, version: &Version) -> bool {
+        !self
+            .patched
+            .iter()
+            .chain(self.unaffected.iter())
+            .any(|req| req.matches(version))
+    }
+
+    fn affects_platform(&self, env: &Env) -> Result<bool> {
+        match &self.platform {
+            None => Ok(true),
+            Some(platform) => env.eval(&platform::to_expr(platform, "hostPlatform")),
+        }
+    }
+}
+
+/// Reads the advisories for crates.io crates from a checkout of
+/// https://github.com/rustsec/advisory-db, skipping withdrawn ones.
+pub fn load_advisories(db: &Path) -> Result<Vec<Advisory>> {
+    let crates_dir = db.join("crates");
+    let entries = fs::read_dir(&crates_dir).context(format!(
+        "no advisory database found at {}, check out https://github.com/rustsec/advisory-db there",
+        db.display()
+    ))?;
+
+    let mut advisories = Vec::new();
+    for entry in entries {
+        for file in fs::read_dir(entry?.path())? {
+            let path = file?.path();
+            if path.extension() != Some("md".as_ref()) {
+                continue;
+            }
+            let advisory = fs::read_to_string(&path)?;
+            let advisory = Advisory::parse(&advisory)
+                .context(format!("could not parse {}", path.display()))?;
+            advisories.extend(advisory);
+        }
+    }
+    advisories.sort_by(|a, b| a.id.cmp(&b.id));
+    Ok(advisories)
+}
+
+/// A target triple and what `Cargo.nix` builds for it.
+pub struct Target<'a> {
+    pub triple: String,
+    pub host: Env,
+    pub build: Env,
+    pub explanation: Explanation<'a>,
+}
+
+/// Whether a crate with an advisory is affected when building for a target.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Status {
+    Affected,
+    /// The crate is built, but only for platforms the advisory does not apply to.
+    UnaffectedPlatform,
+    NotBuilt,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Affected => "affected",
+            Status::UnaffectedPlatform => "unaffected-platform",
+            Status::NotBuilt => "not-built",
+        }
+    }
+}
+
+struct Finding<'a> {
+    advisory: &'a Advisory,
+    krate: &'a Crate,
+    statuses: Vec<(&'a str, Status)>,
+}
+
+impl Finding<'_> {
+    /// Whether this is a vulnerability in a crate that is built on one of the targets.
+    fn fails(&self) -> bool {
+        self.advisory.informational.is_none()
+            && self.statuses.iter().any(|(_, s)| *s == Status::Affected)
+    }
+}
+
+/// The advisories that apply to the crates.io crates of a plan, along with whether the conditions
+/// of `Cargo.nix` build them for each target.
+pub struct Report<'a> {
+    findings: Vec<Finding<'a>>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(
+        plan: &'a BuildPlan,
+        advisories: &'a [Advisory],
+        targets: &'a [Target<'a>],
+    ) -> Result<Self> {
+        let mut findings = Vec::new();
+        for krate in plan.crates.iter() {
+            if !matches!(krate.source, Source::CratesIo { .. }) {
+                continue;
+            }
+            let version = Version::parse(&krate.version)?;
+            for advisory in advisories.iter() {
+                if advisory.package != krate.name || !advisory.affects(&version) {
+                    continue;
+                }
+
+                let mut statuses = Vec::new();
+                for target in targets.iter() {
+                    let mut status = Status::NotBuilt;
+                    for (set, env) in [
+                        (PackageSet::Host, &target.host),
+                        (PackageSet::Build, &target.build),
+                    ]
+                    .iter()
+                    {
+                        if !target.explanation.crates.contains_key(&(*set, krate.id())) {
+                            continue;
+                        }
+                        if advisory.affects_platform(env)? {
+                            status = Status::Affected;
+                        } else if status == Status::NotBuilt {
+                            status = Status::UnaffectedPlatform;
+                        }
+                    }
+                    statuses.push((target.triple.as_str(), status));
+                }
+                findings.push(Finding {
+                    advisory,
+                    krate,
+                    statuses,
+                });
+            }
+        }
+        findings.sort_by(|a, b| a.advisory.id.cmp(&b.advisory.id));
+        Ok(Report { findings })
+    }
+
+    pub fn write(&self, mut out: impl io::Write) -> Result<()> {
+        if self.findings.is_empty() {
+            writeln!(out, "No advisories apply to the crates in Cargo.nix.")?;
+        }
+        for (i, finding) in self.findings.iter().enumerate() {
+            let advisory = finding.advisory;
+            if i > 0 {
+                writeln!(out)?;
+            }
+            write!(
+                out,
+                "{} {} {}: {}",
+                advisory.id, finding.krate.name, finding.krate.version, advisory.title
+            )?;
+            match &advisory.informational {
+                Some(kind) => writeln!(out, " (informational: {})", kind)?,
+                None => writeln!(out)?,
+            }
+            if !advisory.aliases.is_empty() {
+                writeln!(out, "  aliases: {}", advisory.aliases.join(", "))?;
+            }
+            let patched: Vec<_> = advisory.patched.iter().map(|r| r.to_string()).collect();
+            if patched.is_empty() {
+                writeln!(out, "  patched: no patched versions")?;
+            } else {
+                writeln!(out, "  patched: {}", patched.join(" or "))?;
+            }
+            if let Some(url) = &advisory.url {
+                writeln!(out, "  {}", url)?;
+            }
+            for (triple, status) in finding.statuses.iter() {
+                let status = match status {
+                    Status::Affected => "built",
+                    Status::UnaffectedPlatform => "built, but the advisory does not apply to it",
+                    Status::NotBuilt => "never built",
+                };
+                writeln!(out, "  {}: {}", triple, status)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_json(&self, out: impl io::Write) -> Result<()> {
+        let findings: Vec<_> = self
+            .findings
+            .iter()
+            .map(|finding| {
+                let advisory = finding.advisory;
+                let statuses: BTreeMap<_, _> = finding
+                    .statuses
+                    .iter()
+                    .map(|(triple, status)| (*triple, status.as_str()))
+                    .collect();
+                let reqs = |reqs: &[VersionReq]| -> Vec<_> {
+                    reqs.iter().map(|r| r.to_string()).collect()
+                };
+                json!({
+                    "id": advisory.id,
+                    "package": finding.krate.name,
+                    "version": finding.krate.version,
+                    "title": advisory.title,
+                    "date": advisory.date,
+                    "url": advisory.url,
+                    "aliases": advisory.aliases,
+                    "informational": advisory.informational,
+                    "patched": reqs(&advisory.patched),
+                    "unaffected": reqs(&advisory.unaffected),
+                    "targets": statuses,
+                })
+            })
+            .collect();
+        serde_json::to_writer_pretty(out, &json!({ "findings": findings }))?;
+        Ok(())
+    }
+
+    /// Fails if any vulnerability applies to a crate that is built for one of the targets.
+    pub fn check(&self) -> Result<()> {
+        match self.findings.iter().filter(|f| f.fails()).count() {
+            0 => Ok(()),
+            1 => Err(anyhow!("1 vulnerability affects the crates that are built")),
+            n => Err(anyhow!(
+                "{} vulnerabilities affect the crates that are built",
+                n
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advisories_parse() {
+        let advisory = Advisory::parse(
+            r#"```toml
+[advisory]
+id = "RUSTSEC-2020-0071"
+package = "time"
+date = "2020-11-18"
+url = "https://github.com/time-rs/time/issues/293"
+aliases = ["CVE-2020-26235"]
+
+[affected]
+os = ["linux", "macos"]
+
+[affected.functions]
+"time::at_utc" = ["< 0.2.23"]
+
+[versions]
+patched = [">= 0.2.23"]
+unaffected = ["= 0.2.0", "= 0.2.1"]
+```
+
+# Potential segfault in the time crate
+
+Description.
+"#,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(advisory.id, "RUSTSEC-2020-0071");
+        assert_eq!(advisory.title, "Potential segfault in the time crate");
+        assert!(advisory.affects(&Version::parse("0.1.43").unwrap()));
+        assert!(!advisory.affects(&Version::parse("0.2.1").unwrap()));
+        assert!(!advisory.affects(&Version::parse("0.2.23").unwrap()));
+        assert_eq!(
+            advisory.platform.unwrap().to_string(),
+            r#"cfg(all(any(target_os = "linux", target_os = "macos")))"#
+        );
+    }
+}
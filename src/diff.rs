@@ -0,0 +1,414 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use anyhow::{anyhow, Context, Result};
+use serde::Deserialize;
+use tempfile::TempDir;
+
+use crate::template::{BuildPlan, Source};
+
+/// The parts of a build plan that are compared, as dumped by `cargo2nix plan`. Conditions are kept
+/// as the Nix expressions written to `Cargo.nix`.
+#[derive(Debug, Deserialize)]
+pub struct Plan {
+    pub crates: Vec<Crate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Crate {
+    pub name: String,
+    pub version: String,
+    pub registry: String,
+    pub source: Source,
+    pub features: Vec<Feature>,
+    pub dependencies: Vec<Dependency>,
+    pub dev_dependencies: Vec<Dependency>,
+    pub build_dependencies: Vec<Dependency>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Feature {
+    pub name: String,
+    pub activated_by: Option<String>,
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct Dependency {
+    pub name: String,
+    pub extern_name: String,
+    pub version: String,
+    pub registry: String,
+    pub cfg_condition: Option<String>,
+}
+
+impl Plan {
+    pub fn from_build_plan(plan: &BuildPlan) -> Result<Self> {
+        Ok(serde_json::from_value(serde_json::to_value(plan)?)?)
+    }
+
+    fn crates_by_name(&self) -> BTreeMap<&str, Vec<&Crate>> {
+        let mut crates: BTreeMap<&str, Vec<&Crate>> = BTreeMap::new();
+        for krate in self.crates.iter() {
+            crates.entry(&krate.name).or_default().push(krate);
+        }
+        crates
+    }
+}
+
+/// A git revision of the repository containing a directory, exported into a temporary directory
+/// so that a plan can be generated for it without touching the working tree.
+pub struct Export {
+    dir: TempDir,
+    /// The path of the directory within the repository.
+    prefix: PathBuf,
+}
+
+impl Export {
+    /// Exports the revision `rev` of the repository containing `dir`.
+    pub fn new(dir: &Path, rev: &str) -> Result<Self> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(&["rev-parse", "--show-toplevel", "--show-prefix"])
+            .output()
+            .context("could not run git")?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "{} is not in a git repository: {}",
+                dir.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        let output = String::from_utf8(output.stdout)?;
+        let mut lines = output.lines();
+        let toplevel = lines.next().unwrap_or_default();
+        let prefix = PathBuf::from(lines.next().unwrap_or_default());
+
+        let export = tempfile::tempdir()?;
+        let mut archive = Command::new("git")
+            .arg("-C")
+            .arg(toplevel)
+            .args(&["archive", "--format=tar", rev])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("could not run git")?;
+        let unpacked = Command::new("tar")
+            .arg("-x")
+            .arg("-C")
+            .arg(export.path())
+            .stdin(archive.stdout.take().unwrap())
+            .status()
+            .context("could not run tar")?;
+        let archived = archive.wait_with_output()?;
+        if !archived.status.success() {
+            return Err(anyhow!(
+                "could not export revision {}: {}",
+                rev,
+                String::from_utf8_lossy(&archived.stderr).trim()
+            ));
+        }
+        if !unpacked.success() {
+            return Err(anyhow!("could not unpack revision {}", rev));
+        }
+
+        Ok(Export {
+            dir: export,
+            prefix,
+        })
+    }
+
+    /// The directory the revision was exported for, within the export.
+    pub fn path(&self) -> PathBuf {
+        self.dir.path().join(&self.prefix)
+    }
+}
+
+/// Writes the changes from `old` to `new`, grouped by crate name.
+pub fn write_changelog(old: &Plan, new: &Plan, mut out: impl io::Write) -> Result<()> {
+    let (old_crates, new_crates) = (old.crates_by_name(), new.crates_by_name());
+    let names: BTreeSet<_> = old_crates.keys().chain(new_crates.keys()).collect();
+
+    let mut any = false;
+    for name in names {
+        let old = old_crates.get(name).map_or(&[][..], |c| &c[..]);
+        let new = new_crates.get(name).map_or(&[][..], |c| &c[..]);
+        let changes = crate_changes(old, new);
+        if !changes.is_empty() {
+            any = true;
+            writeln!(out, "{}", name)?;
+            for change in changes {
+                writeln!(out, "  {}", change)?;
+            }
+        }
+    }
+
+    if !any {
+        writeln!(out, "no changes")?;
+    }
+
+    Ok(())
+}
+
+fn crate_changes(old: &[&Crate], new: &[&Crate]) -> Vec<String> {
+    let id = |c: &Crate| (c.version.clone(), c.registry.clone());
+    let old_ids: BTreeSet<_> = old.iter().map(|c| id(c)).collect();
+    let new_ids: BTreeSet<_> = new.iter().map(|c| id(c)).collect();
+    fn find<'a>(crates: &[&'a Crate], (version, registry): &(String, String)) -> Option<&'a Crate> {
+        crates
+            .iter()
+            .copied()
+            .find(|c| &c.version == version && &c.registry == registry)
+    }
+
+    let mut pairs = Vec::new();
+    for both in old_ids.intersection(&new_ids) {
+        pairs.push((find(old, both).unwrap(), find(new, both).unwrap()));
+    }
+
+    let removed: Vec<_> = old_ids.difference(&new_ids).collect();
+    let added: Vec<_> = new_ids.difference(&old_ids).collect();
+    let mut changes = Vec::new();
+    if removed.len() == 1 && added.len() == 1 {
+        // A single version replaced by another is an update, which is compared in detail.
+        let (old, new) = (find(old, removed[0]).unwrap(), find(new, added[0]).unwrap());
+        changes.push(format!("updated {} -> {}", old.version, new.version));
+        pairs.push((old, new));
+    } else {
+        changes.extend(removed.iter().map(|id| format!("removed {}", id.0)));
+        changes.extend(added.iter().map(|id| format!("added {}", id.0)));
+    }
+
+    for (old, new) in pairs {
+        // Without the version, details of several versions of a crate would be ambiguous.
+        let prefix = if old_ids.len() > 1 || new_ids.len() > 1 {
+            format!("{}: ", new.version)
+        } else {
+            String::new()
+        };
+        changes.extend(
+            details(old, new)
+                .into_iter()
+                .map(|change| format!("{}{}", prefix, change)),
+        );
+    }
+
+    changes
+}
+
+fn details(old: &Crate, new: &Crate) -> Vec<String> {
+    let mut changes = Vec::new();
+    if describe_source(&old.source) != describe_source(&new.source) {
+        changes.push(format!(
+            "source {} -> {}",
+            describe_source(&old.source),
+            describe_source(&new.source)
+        ));
+    }
+
+    let old_features: BTreeMap<_, _> = old
+        .features
+        .iter()
+        .map(|f| (&f.name, &f.activated_by))
+        .collect();
+    let new_features: BTreeMap<_, _> = new
+        .features
+        .iter()
+        .map(|f| (&f.name, &f.activated_by))
+        .collect();
+    for (name, change) in compare(&old_features, &new_features) {
+        changes.push(match change {
+            Change::Added(cond) => format!("feature {} added{}", name, describe_condition(cond)),
+            Change::Removed(_) => format!("feature {} removed", name),
+            Change::Changed(old, new) => format!(
+                "feature {} activated by {} -> {}",
+                name,
+                condition_or_always(old),
+                condition_or_always(new)
+            ),
+        });
+    }
+
+    let kinds = [
+        ("dependency", &old.dependencies, &new.dependencies),
+        (
+            "dev-dependency",
+            &old.dev_dependencies,
+            &new.dev_dependencies,
+        ),
+        (
+            "build-dependency",
+            &old.build_dependencies,
+            &new.build_dependencies,
+        ),
+    ];
+    for (kind, old_deps, new_deps) in kinds.iter() {
+        // An extern name may stand for several dependencies, e.g. one per target.
+        fn with_extern_name<'a>(deps: &'a [Dependency], extern_name: &str) -> Vec<&'a Dependency> {
+            deps.iter()
+                .filter(|d| d.extern_name == extern_name)
+                .collect()
+        }
+        let extern_names: BTreeSet<_> = old_deps
+            .iter()
+            .chain(new_deps.iter())
+            .map(|d| &d.extern_name)
+            .collect();
+        for extern_name in extern_names {
+            let (old, new) = (
+                with_extern_name(old_deps, extern_name),
+                with_extern_name(new_deps, extern_name),
+            );
+            let removed: Vec<_> = old.iter().filter(|d| !new.contains(d)).collect();
+            let added: Vec<_> = new.iter().filter(|d| !old.contains(d)).collect();
+            if removed.len() == 1 && added.len() == 1 {
+                // A single dependency replaced by another is compared in detail.
+                let (old, new) = (removed[0], added[0]);
+                let mut parts = Vec::new();
+                if (&old.name, &old.version, &old.registry)
+                    != (&new.name, &new.version, &new.registry)
+                {
+                    parts.push(format!(
+                        "{} {} -> {} {}",
+                        old.name, old.version, new.name, new.version
+                    ));
+                }
+                if old.cfg_condition != new.cfg_condition {
+                    parts.push(format!(
+                        "condition {} -> {}",
+                        condition_or_always(&old.cfg_condition),
+                        condition_or_always(&new.cfg_condition)
+                    ));
+                }
+                changes.push(format!("{} {}: {}", kind, extern_name, parts.join(", ")));
+            } else {
+                changes.extend(removed.iter().map(|dep| {
+                    format!(
+                        "{} {} {} removed{}",
+                        kind,
+                        extern_name,
+                        dep.version,
+                        describe_condition(&dep.cfg_condition)
+                    )
+                }));
+                changes.extend(added.iter().map(|dep| {
+                    format!(
+                        "{} {} {} added{}",
+                        kind,
+                        extern_name,
+                        dep.version,
+                        describe_condition(&dep.cfg_condition)
+                    )
+                }));
+            }
+        }
+    }
+
+    changes
+}
+
+enum Change<T> {
+    Added(T),
+    Removed(T),
+    Changed(T, T),
+}
+
+fn compare<K: Ord + Clone, V: PartialEq + Clone>(
+    old: &BTreeMap<K, V>,
+    new: &BTreeMap<K, V>,
+) -> Vec<(K, Change<V>)> {
+    let keys: BTreeSet<_> = old.keys().chain(new.keys()).collect();
+    keys.into_iter()
+        .filter_map(|key| {
+            let change = match (old.get(key), new.get(key)) {
+                (Some(o), Some(n)) if o == n => return None,
+                (Some(o), Some(n)) => Change::Changed(o.clone(), n.clone()),
+                (Some(o), None) => Change::Removed(o.clone()),
+                (None, Some(n)) => Change::Added(n.clone()),
+                (None, None) => return None,
+            };
+            Some((key.clone(), change))
+        })
+        .collect()
+}
+
+fn describe_source(source: &Source) -> String {
+    match source {
+        Source::CratesIo { sha256 } => format!("crates.io (sha256 {})", sha256),
+        Source::Git { url, rev, .. } => format!("{}#{}", url, rev),
+        Source::Local { path } => format!("path {}", path.display()),
+        Source::Registry { index, sha256 } => format!("{} (sha256 {})", index, sha256),
+    }
+}
+
+fn describe_condition(condition: &Option<String>) -> String {
+    match condition {
+        None => String::new(),
+        Some(condition) => format!(" if `{}`", condition),
+    }
+}
+
+fn condition_or_always(condition: &Option<String>) -> String {
+    match condition {
+        None => "always".to_string(),
+        Some(condition) => format!("`{}`", condition),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn krate(dependencies: Vec<Dependency>) -> Crate {
+        Crate {
+            name: "app".to_string(),
+            version: "0.1.0".to_string(),
+            registry: "unknown".to_string(),
+            source: Source::Local {
+                path: PathBuf::from("."),
+            },
+            features: Vec::new(),
+            dependencies,
+            dev_dependencies: Vec::new(),
+            build_dependencies: Vec::new(),
+        }
+    }
+
+    fn dep(version: &str, cfg_condition: &str) -> Dependency {
+        Dependency {
+            name: "winapi".to_string(),
+            extern_name: "winapi".to_string(),
+            version: version.to_string(),
+            registry: "registry+https://github.com/rust-lang/crates.io-index".to_string(),
+            cfg_condition: Some(cfg_condition.to_string()),
+        }
+    }
+
+    #[test]
+    fn dependencies_sharing_an_extern_name_are_compared_separately() {
+        let old = krate(vec![
+            dep("0.2.8", "hostPlatform.isWindows"),
+            dep("0.3.8", "hostPlatform.isUnix"),
+        ]);
+        let new = krate(vec![
+            dep("0.2.8", "hostPlatform.isWindows"),
+            dep("0.3.9", "hostPlatform.isUnix"),
+        ]);
+        assert_eq!(
+            details(&old, &new),
+            ["dependency winapi: winapi 0.3.8 -> winapi 0.3.9"]
+        );
+
+        let new = krate(vec![dep("0.3.9", "hostPlatform.isUnix")]);
+        assert_eq!(
+            details(&old, &new),
+            [
+                "dependency winapi 0.2.8 removed if `hostPlatform.isWindows`",
+                "dependency winapi 0.3.8 removed if `hostPlatform.isUnix`",
+                "dependency winapi 0.3.9 added if `hostPlatform.isUnix`",
+            ]
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Evaluation of the conditions in a generated `Cargo.nix` without Nix.
+//!
+//! The platform model mirrors the parts of `lib.systems.parse` and `lib.systems.inspect` that the
+//! predicates produced by `platform::to_expr` inspect.
+
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::expr::{Atom, BoolExpr};
+
+/// The fields of `lib.systems.parse` output that the generated predicates inspect.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NixPlatform {
+    cpu: &'static str,
+    vendor: &'static str,
+    /// The attribute name in `lib.systems.parse.kernels`, e.g. `macos`.
+    kernel: &'static str,
+    abi: &'static str,
+}
+
+/// Rust target triples and the Nix platform that builds for them.
+const NIX_PLATFORMS: &[(&str, NixPlatform)] = &[
+    (
+        "x86_64-unknown-linux-gnu",
+        nix("x86_64", "unknown", "linux", "gnu"),
+    ),
+    (
+        "x86_64-unknown-linux-musl",
+        nix("x86_64", "unknown", "linux", "musl"),
+    ),
+    (
+        "i686-unknown-linux-gnu",
+        nix("i686", "unknown", "linux", "gnu"),
+    ),
+    (
+        "i686-unknown-linux-musl",
+        nix("i686", "unknown", "linux", "musl"),
+    ),
+    (
+        "i586-unknown-linux-gnu",
+        nix("i586", "unknown", "linux", "gnu"),
+    ),
+    (
+        "aarch64-unknown-linux-gnu",
+        nix("aarch64", "unknown", "linux", "gnu"),
+    ),
+    (
+        "aarch64-unknown-linux-musl",
+        nix("aarch64", "unknown", "linux", "musl"),
+    ),
+    (
+        "arm-unknown-linux-gnueabi",
+        nix("armv6l", "unknown", "linux", "gnueabi"),
+    ),
+    (
+        "arm-unknown-linux-gnueabihf",
+        nix("armv6l", "unknown", "linux", "gnueabihf"),
+    ),
+    (
+        "arm-unknown-linux-musleabi",
+        nix("armv6l", "unknown", "linux", "musleabi"),
+    ),
+    (
+        "arm-unknown-linux-musleabihf",
+        nix("armv6l", "unknown", "linux", "musleabihf"),
+    ),
+    (
+        "armv5te-unknown-linux-gnueabi",
+        nix("armv5tel", "unknown", "linux", "gnueabi"),
+    ),
+    (
+        "armv7-unknown-linux-gnueabi",
+        nix("armv7l", "unknown", "linux", "gnueabi"),
+    ),
+    (
+        "armv7-unknown-linux-gnueabihf",
+        nix("armv7l", "unknown", "linux", "gnueabihf"),
+    ),
+    (
+        "armv7-unknown-linux-musleabihf",
+        nix("armv7l", "unknown", "linux", "musleabihf"),
+    ),
+    (
+        "aarch64-linux-android",
+        nix("aarch64", "unknown", "linux", "android"),
+    ),
+    (
+        "armv7-linux-androideabi",
+        nix("armv7a", "unknown", "linux", "androideabi"),
+    ),
+    (
+        "i686-linux-android",
+        nix("i686", "unknown", "linux", "android"),
+    ),
+    (
+        "x86_64-linux-android",
+        nix("x86_64", "unknown", "linux", "android"),
+    ),
+    (
+        "x86_64-apple-darwin",
+        nix("x86_64", "apple", "macos", "unknown"),
+    ),
+    (
+        "aarch64-apple-darwin",
+        nix("aarch64", "apple", "macos", "unknown"),
+    ),
+    ("x86_64-apple-ios", nix("x86_64", "apple", "ios", "unknown")),
+    (
+        "aarch64-apple-ios",
+        nix("aarch64", "apple", "ios", "unknown"),
+    ),
+    (
+        "x86_64-pc-windows-gnu",
+        nix("x86_64", "w64", "windows", "gnu"),
+    ),
+    (
+        "x86_64-pc-windows-msvc",
+        nix("x86_64", "pc", "windows", "msvc"),
+    ),
+    ("i686-pc-windows-gnu", nix("i686", "w64", "windows", "gnu")),
+    ("i686-pc-windows-msvc", nix("i686", "pc", "windows", "msvc")),
+    (
+        "aarch64-pc-windows-msvc",
+        nix("aarch64", "pc", "windows", "msvc"),
+    ),
+    (
+        "wasm32-unknown-unknown",
+        nix("wasm32", "unknown", "none", "unknown"),
+    ),
+    ("wasm32-wasi", nix("wasm32", "unknown", "wasi", "unknown")),
+    ("wasm32-wasip1", nix("wasm32", "unknown", "wasi", "unknown")),
+    (
+        "riscv64gc-unknown-linux-gnu",
+        nix("riscv64", "unknown", "linux", "gnu"),
+    ),
+    (
+        "powerpc-unknown-linux-gnu",
+        nix("powerpc", "unknown", "linux", "gnu"),
+    ),
+    (
+        "powerpc64-unknown-linux-gnu",
+        nix("powerpc64", "unknown", "linux", "gnu"),
+    ),
+    (
+        "powerpc64le-unknown-linux-gnu",
+        nix("powerpc64le", "unknown", "linux", "gnu"),
+    ),
+    (
+        "mips-unknown-linux-gnu",
+        nix("mips", "unknown", "linux", "gnu"),
+    ),
+    (
+        "mipsel-unknown-linux-gnu",
+        nix("mipsel", "unknown", "linux", "gnu"),
+    ),
+    (
+        "mips64-unknown-linux-gnuabi64",
+        nix("mips64", "unknown", "linux", "gnuabi64"),
+    ),
+    (
+        "mips64el-unknown-linux-gnuabi64",
+        nix("mips64el", "unknown", "linux", "gnuabi64"),
+    ),
+    (
+        "s390x-unknown-linux-gnu",
+        nix("s390x", "unknown", "linux", "gnu"),
+    ),
+    (
+        "x86_64-unknown-freebsd",
+        nix("x86_64", "unknown", "freebsd", "unknown"),
+    ),
+    (
+        "x86_64-unknown-netbsd",
+        nix("x86_64", "unknown", "netbsd", "unknown"),
+    ),
+    (
+        "x86_64-unknown-openbsd",
+        nix("x86_64", "unknown", "openbsd", "unknown"),
+    ),
+    ("thumbv6m-none-eabi", nix("arm", "unknown", "none", "eabi")),
+    ("thumbv7m-none-eabi", nix("arm", "unknown", "none", "eabi")),
+    ("thumbv7em-none-eabi", nix("arm", "unknown", "none", "eabi")),
+    (
+        "thumbv7em-none-eabihf",
+        nix("arm", "unknown", "none", "eabihf"),
+    ),
+    (
+        "riscv32imac-unknown-none-elf",
+        nix("riscv32", "unknown", "none", "elf"),
+    ),
+];
+
+const fn nix(
+    cpu: &'static str,
+    vendor: &'static str,
+    kernel: &'static str,
+    abi: &'static str,
+) -> NixPlatform {
+    NixPlatform {
+        cpu,
+        vendor,
+        kernel,
+        abi,
+    }
+}
+
+impl NixPlatform {
+    pub fn for_triple(triple: &str) -> Result<Self> {
+        NIX_PLATFORMS
+            .iter()
+            .find(|(t, _)| *t == triple)
+            .map(|(_, nix)| nix.clone())
+            .ok_or_else(|| {
+                anyhow!(
+                    "there is no model of the Nix platform for target {}",
+                    triple
+                )
+            })
+    }
+
+    /// Family, bits, endianness and version of `lib.systems.parse.cpuTypes`.
+    fn cpu_type(&self) -> (&'static str, i64, &'static str, Option<&'static str>) {
+        match self.cpu {
+            "x86_64" => ("x86", 64, "littleEndian", None),
+            "i686" | "i586" => ("x86", 32, "littleEndian", None),
+            "aarch64" => ("arm", 64, "littleEndian", None),
+            "armv7l" | "armv7a" => ("arm", 32, "littleEndian", Some("7")),
+            "armv6l" => ("arm", 32, "littleEndian", Some("6")),
+            "armv5tel" => ("arm", 32, "littleEndian", Some("5")),
+            "arm" => ("arm", 32, "littleEndian", None),
+            "wasm32" => ("wasm", 32, "littleEndian", None),
+            "riscv32" => ("riscv", 32, "littleEndian", None),
+            "riscv64" => ("riscv", 64, "littleEndian", None),
+            "powerpc" => ("power", 32, "bigEndian", None),
+            "powerpc64" => ("power", 64, "bigEndian", None),
+            "powerpc64le" => ("power", 64, "littleEndian", None),
+            "mips" => ("mips", 32, "bigEndian", None),
+            "mipsel" => ("mips", 32, "littleEndian", None),
+            "mips64" => ("mips", 64, "bigEndian", None),
+            "mips64el" => ("mips", 64, "littleEndian", None),
+            "s390x" => ("s390", 64, "bigEndian", None),
+            cpu => unreachable!("unmodelled Nix cpu {}", cpu),
+        }
+    }
+
+    fn kernel_name(&self) -> &'static str {
+        match self.kernel {
+            "macos" => "darwin",
+            kernel => kernel,
+        }
+    }
+
+    fn predicate(&self, name: &str) -> Option<bool> {
+        let (family, bits, endian, _) = self.cpu_type();
+        let is_bsd = ["freebsd", "netbsd", "openbsd"].contains(&self.kernel);
+        let is_darwin = self.kernel == "macos" || self.kernel == "ios";
+        let is_linux = self.kernel == "linux";
+        Some(match name {
+            "isUnix" => is_bsd || is_darwin || is_linux || self.kernel == "redox",
+            "isWindows" => self.kernel == "windows",
+            "isLinux" => is_linux,
+            "isDarwin" => is_darwin,
+            "isMacOS" => self.kernel == "macos",
+            "isiOS" => self.kernel == "ios",
+            "isAndroid" => self.abi == "android" || self.abi == "androideabi",
+            "isWasi" => self.kernel == "wasi",
+            "isRedox" => self.kernel == "redox",
+            "isNone" => self.kernel == "none",
+            "isGnu" => [
+                "gnu",
+                "gnueabi",
+                "gnueabihf",
+                "gnuabi64",
+                "gnuabin32",
+                "gnuabielfv1",
+                "gnuabielfv2",
+            ]
+            .contains(&self.abi),
+            "isMusl" => self.abi.starts_with("musl"),
+            "isUClibc" => self.abi.starts_with("uclibc"),
+            "isWasm" => family == "wasm",
+            "isx86" => family == "x86",
+            "isx86_32" => family == "x86" && bits == 32,
+            "isx86_64" => family == "x86" && bits == 64,
+            "isAarch32" => family == "arm" && bits == 32,
+            "isAarch64" => family == "arm" && bits == 64,
+            "isMips32" => family == "mips" && bits == 32,
+            "isMips64" => family == "mips" && bits == 64,
+            "isPower" => family == "power",
+            "isPower64" => family == "power" && bits == 64,
+            "isRiscV" => family == "riscv",
+            "isAvr" => family == "avr",
+            "isMsp430" => family == "msp430",
+            "is32bit" => bits == 32,
+            "is64bit" => bits == 64,
+            "isLittleEndian" => endian == "littleEndian",
+            "isBigEndian" => endian == "bigEndian",
+            _ => return None,
+        })
+    }
+}
+
+/// The arguments of a generated `Cargo.nix` that its conditions depend on.
+#[derive(Clone, Debug)]
+pub struct Env {
+    pub host_platform: NixPlatform,
+    /// `rootFeatures'`, i.e. the root features together with the names of their packages.
+    root_features: BTreeSet<String>,
+    pub host_platform_cpu: Option<String>,
+    pub host_platform_features: Vec<String>,
+    pub host_platform_cfgs: Vec<String>,
+    pub host_platform_panic: Option<String>,
+    pub host_platform_debug_assertions: bool,
+}
+
+enum Value {
+    Bool(bool),
+    Int(i64),
+    Str(String),
+    List(Vec<String>),
+    Null,
+}
+
+impl Env {
+    /// An environment with the defaults of the template for everything but the root features.
+    pub fn new<S: AsRef<str>>(host_platform: NixPlatform, root_features: &[S]) -> Self {
+        // Mirrors `expandFeatures` in overlay/lib/features.nix.
+        let root_features = root_features
+            .iter()
+            .map(AsRef::as_ref)
+            .flat_map(|f| vec![f.to_string(), f.split('/').next().unwrap().to_string()])
+            .collect();
+
+        Env {
+            host_platform,
+            root_features,
+            host_platform_cpu: None,
+            host_platform_features: Vec::new(),
+            host_platform_cfgs: Vec::new(),
+            host_platform_panic: None,
+            host_platform_debug_assertions: true,
+        }
+    }
+
+    pub fn has_root_feature(&self, feature: &str) -> bool {
+        self.root_features.contains(feature)
+    }
+
+    pub fn eval(&self, expr: &BoolExpr) -> Result<bool> {
+        expr.eval(&|atom| self.eval_atom(atom))
+    }
+
+    fn lookup(&self, path: &str) -> Result<Value> {
+        let platform = &self.host_platform;
+        let (_, bits, _, version) = platform.cpu_type();
+        let string = |s: &str| Value::Str(s.to_string());
+        Ok(match path {
+            "hostPlatform.parsed.cpu.name" => string(platform.cpu),
+            "hostPlatform.parsed.cpu.bits" => Value::Int(bits),
+            "hostPlatform.parsed.cpu.version" => version.map_or(Value::Null, string),
+            "hostPlatform.parsed.vendor.name" => string(platform.vendor),
+            "hostPlatform.parsed.kernel.name" => string(platform.kernel_name()),
+            "hostPlatform.parsed.abi.name" => string(platform.abi),
+            "hostPlatformFeatures" => Value::List(self.host_platform_features.clone()),
+            "hostPlatformCfgs" => Value::List(self.host_platform_cfgs.clone()),
+            "hostPlatformCpu" => self
+                .host_platform_cpu
+                .as_deref()
+                .map_or(Value::Null, string),
+            "hostPlatformDebugAssertions" => Value::Bool(self.host_platform_debug_assertions),
+            // Mirrors the default of the `hostPlatformPanic` parameter in the template.
+            "hostPlatformPanic" => match &self.host_platform_panic {
+                Some(panic) => string(panic),
+                None if platform.predicate("isWasm") == Some(true)
+                    || platform.predicate("isNone") == Some(true) =>
+                {
+                    string("abort")
+                }
+                None => string("unwind"),
+            },
+            path => path
+                .strip_prefix("hostPlatform.")
+                .and_then(|predicate| platform.predicate(predicate))
+                .map(Value::Bool)
+                .ok_or_else(|| anyhow!("unknown Nix variable {}", path))?,
+        })
+    }
+
+    /// Evaluates one of the atoms produced by `platform::to_expr` and `Optionality::to_expr`.
+    fn eval_atom(&self, atom: &Atom) -> Result<bool> {
+        Ok(match atom {
+            Atom::Var(path) => match self.lookup(path)? {
+                Value::Bool(b) => b,
+                _ => bail!("`{}` is not a boolean", path),
+            },
+            Atom::Eq(path, value) => match self.lookup(path)? {
+                Value::Str(s) => &s == value,
+                _ => false,
+            },
+            Atom::EqInt(path, value) => match self.lookup(path)? {
+                Value::Int(n) => n == *value,
+                _ => false,
+            },
+            Atom::Elem(needle, path) => match self.lookup(path)? {
+                Value::List(items) => items.contains(needle),
+                _ => bail!("`{}` is not a list", path),
+            },
+            Atom::HasAttr(path, feature) if path == "rootFeatures'" => {
+                self.has_root_feature(feature)
+            }
+            Atom::HasAttr(path, _) => bail!("unknown Nix attribute set {}", path),
+            Atom::VersionAtLeast {
+                path,
+                default,
+                minimum,
+            } => match self.lookup(path)? {
+                Value::Str(version) => version_at_least(&version, minimum),
+                _ => version_at_least(default, minimum),
+            },
+        })
+    }
+}
+
+/// Compares dot-separated numeric versions like `lib.versionAtLeast`.
+fn version_at_least(version: &str, minimum: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> { v.split('.').map(|c| c.parse().unwrap_or(0)).collect() };
+    parse(version) >= parse(minimum)
+}
@@ -0,0 +1,127 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+
+use anyhow::{anyhow, Result};
+
+use crate::eval::Env;
+use crate::template::{BuildPlan, Crate, CrateId};
+
+/// Which package set a crate is built in. Build dependencies and procedural macros, as well as
+/// everything they depend on, come from `buildRustPackages`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum PackageSet {
+    Host,
+    Build,
+}
+
+/// A crate as built in one of the package sets.
+pub type Built<'a> = (PackageSet, CrateId<'a>);
+
+/// The crates of a plan that `Cargo.nix` builds in each package set, with their enabled features
+/// and the dependencies they are built with.
+pub struct Explanation<'a> {
+    pub crates: BTreeMap<Built<'a>, BTreeSet<&'a str>>,
+    pub dependencies: BTreeMap<Built<'a>, BTreeSet<Built<'a>>>,
+}
+
+/// Evaluates the conditions of `plan` the way `Cargo.nix` does when building the workspace members
+/// selected by the root features of `host` for `host`, with build dependencies built for `build`.
+pub fn explain<'a>(plan: &'a BuildPlan, host: &Env, build: &Env) -> Result<Explanation<'a>> {
+    let crates_by_id: BTreeMap<_, &Crate> = plan.crates.iter().map(|c| (c.id(), c)).collect();
+    let lookup = |id: CrateId| {
+        crates_by_id
+            .get(&id)
+            .copied()
+            .ok_or_else(|| anyhow!("{} {} is missing from the plan", id.name, id.version))
+    };
+
+    let mut queue = Vec::new();
+    for member in plan.workspace_members.iter() {
+        if host.has_root_feature(&member.name) {
+            let id = CrateId {
+                name: &member.name,
+                version: &member.version,
+                registry: "unknown",
+            };
+            queue.push((PackageSet::Host, lookup(id)?));
+        }
+    }
+
+    let mut crates = BTreeMap::new();
+    let mut dependencies = BTreeMap::new();
+    while let Some((set, krate)) = queue.pop() {
+        if crates.contains_key(&(set, krate.id())) {
+            continue;
+        }
+
+        let env = match set {
+            PackageSet::Host => host,
+            PackageSet::Build => build,
+        };
+        let enabled = |condition: &Option<_>| condition.as_ref().map_or(Ok(true), |c| env.eval(c));
+
+        let mut features = BTreeSet::new();
+        for feature in krate.features.iter() {
+            if enabled(&feature.activated_by)? {
+                features.insert(feature.name.as_str());
+            }
+        }
+
+        let deps = krate.dependencies.iter().map(|dep| {
+            let dep_set = if dep.is_proc_macro {
+                PackageSet::Build
+            } else {
+                set
+            };
+            (dep_set, dep)
+        });
+        let build_deps = krate
+            .build_dependencies
+            .iter()
+            .map(|dep| (PackageSet::Build, dep));
+        let mut built_deps = BTreeSet::new();
+        for (dep_set, dep) in deps.chain(build_deps) {
+            if enabled(&dep.cfg_condition)? {
+                let dep = lookup(dep.id())?;
+                built_deps.insert((dep_set, dep.id()));
+                queue.push((dep_set, dep));
+            }
+        }
+
+        crates.insert((set, krate.id()), features);
+        dependencies.insert((set, krate.id()), built_deps);
+    }
+
+    Ok(Explanation {
+        crates,
+        dependencies,
+    })
+}
+
+impl Explanation<'_> {
+    pub fn write(&self, mut out: impl io::Write, host: &str, build: &str) -> Result<()> {
+        for (set, platform) in [(PackageSet::Host, host), (PackageSet::Build, build)].iter() {
+            let crates: Vec<_> = self.crates.iter().filter(|((s, _), _)| s == set).collect();
+            if crates.is_empty() {
+                continue;
+            }
+
+            match set {
+                PackageSet::Host => writeln!(out, "Crates built for {}:", platform)?,
+                PackageSet::Build => {
+                    writeln!(out, "Crates built for the build platform {}:", platform)?
+                }
+            }
+            for ((_, id), features) in crates {
+                write!(out, "  {} {}", id.name, id.version)?;
+                if !features.is_empty() {
+                    let features: Vec<_> = features.iter().copied().collect();
+                    write!(out, " [{}]", features.join(", "))?;
+                }
+                writeln!(out)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,14 +1,80 @@
+use std::cmp::Ordering;
 use std::fmt;
 
+use serde::{Serialize, Serializer};
+
+use crate::template::to_nix_string;
+
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub enum BoolExpr {
     And(Box<BoolExpr>, Box<BoolExpr>),
     Or(Box<BoolExpr>, Box<BoolExpr>),
     Not(Box<BoolExpr>),
-    Single(String),
+    Single(Atom),
     True,
     False,
 }
 
+/// A condition on the arguments of `Cargo.nix`, which `BoolExpr`s combine. Paths are Nix
+/// attribute paths like `hostPlatform.parsed.cpu.name`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Atom {
+    /// A boolean, e.g. `hostPlatform.isUnix`.
+    Var(String),
+    /// A string comparison, e.g. `hostPlatform.parsed.kernel.name == "linux"`.
+    Eq(String, String),
+    /// An integer comparison, e.g. `hostPlatform.parsed.cpu.bits == 64`.
+    EqInt(String, i64),
+    /// Whether the list at the path contains the string, e.g. `builtins.elem "sse2"
+    /// hostPlatformFeatures`.
+    Elem(String, String),
+    /// Whether the attribute set at the path has the attribute, e.g. `rootFeatures' ? "foo/bar"`.
+    HasAttr(String, String),
+    /// `lib.versionAtLeast (path or "default") "minimum"`.
+    VersionAtLeast {
+        path: String,
+        default: String,
+        minimum: String,
+    },
+}
+
+impl fmt::Display for Atom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string = |s: &str| format!("\"{}\"", to_nix_string(s));
+        match self {
+            Atom::Var(path) => write!(f, "{}", path),
+            Atom::Eq(path, value) => write!(f, "{} == {}", path, string(value)),
+            Atom::EqInt(path, value) => write!(f, "{} == {}", path, value),
+            Atom::Elem(needle, path) => write!(f, "builtins.elem {} {}", string(needle), path),
+            Atom::HasAttr(path, attr) => write!(f, "{} ? {}", path, string(attr)),
+            Atom::VersionAtLeast {
+                path,
+                default,
+                minimum,
+            } => write!(
+                f,
+                "lib.versionAtLeast ({} or {}) {}",
+                path,
+                string(default),
+                string(minimum)
+            ),
+        }
+    }
+}
+
+/// Atoms are ordered by their rendering, which decides the order of operands in `Cargo.nix`.
+impl Ord for Atom {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_string().cmp(&other.to_string())
+    }
+}
+
+impl PartialOrd for Atom {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl BoolExpr {
     pub fn ors(iter: impl IntoIterator<Item = Self>) -> Self {
         use self::BoolExpr::*;
@@ -56,43 +122,163 @@ impl BoolExpr {
         }
     }
 
+    /// Evaluates the expression, deciding atoms with `atom`. Like Nix, operands are evaluated
+    /// lazily from left to right.
+    pub fn eval<E>(&self, atom: &impl Fn(&Atom) -> Result<bool, E>) -> Result<bool, E> {
+        use self::BoolExpr::*;
+
+        Ok(match self {
+            And(a, b) => a.eval(atom)? && b.eval(atom)?,
+            Or(a, b) => a.eval(atom)? || b.eval(atom)?,
+            Not(a) => !a.eval(atom)?,
+            Single(a) => atom(a)?,
+            True => true,
+            False => false,
+        })
+    }
+
+    /// Minimises the expression: folds constants, removes double negations, flattens nested
+    /// conjunctions and disjunctions, drops duplicate and absorbed operands (`a && (a || b)` is
+    /// `a`), detects complementary operands (`a && !a` is `false`) and sorts the remaining
+    /// operands, so that equivalent conditions render identically.
     pub fn simplify(self) -> Self {
         use self::BoolExpr::*;
 
         match self {
-            And(a, b) => {
-                let a = a.simplify();
-                let b = b.simplify();
-                match (a.as_bool(), b.as_bool()) {
-                    (Some(true), _) => b,
-                    (Some(false), _) => False,
-                    (_, Some(true)) => a,
-                    (_, Some(false)) => False,
-                    _ => a.and(b),
-                }
+            And(a, b) => simplify_junction(Junction::And, *a, *b),
+            Or(a, b) => simplify_junction(Junction::Or, *a, *b),
+            Not(a) => match a.simplify() {
+                False => True,
+                True => False,
+                Not(a) => *a,
+                a => a.not(),
+            },
+            a => a,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Junction {
+    And,
+    Or,
+}
+
+impl Junction {
+    /// The constant which decides the junction on its own, `false` for `&&`.
+    fn dominant(self) -> BoolExpr {
+        match self {
+            Junction::And => BoolExpr::False,
+            Junction::Or => BoolExpr::True,
+        }
+    }
+
+    fn dual(self) -> Self {
+        match self {
+            Junction::And => Junction::Or,
+            Junction::Or => Junction::And,
+        }
+    }
+
+    /// Appends the operands of `expr` to `out`, looking through nested junctions of this kind.
+    fn flatten_into(self, expr: BoolExpr, out: &mut Vec<BoolExpr>) {
+        match (self, expr) {
+            (Junction::And, BoolExpr::And(a, b)) | (Junction::Or, BoolExpr::Or(a, b)) => {
+                self.flatten_into(*a, out);
+                self.flatten_into(*b, out);
             }
-            Or(a, b) => {
-                let a = a.simplify();
-                let b = b.simplify();
-                match (a.as_bool(), b.as_bool()) {
-                    (Some(true), _) => True,
-                    (Some(false), _) => b,
-                    (_, Some(true)) => True,
-                    (_, Some(false)) => a,
-                    _ => a.or(b),
+            (_, expr) => out.push(expr),
+        }
+    }
+
+    fn operands(self, expr: &BoolExpr) -> Vec<&BoolExpr> {
+        fn collect<'a>(junction: Junction, expr: &'a BoolExpr, out: &mut Vec<&'a BoolExpr>) {
+            match (junction, expr) {
+                (Junction::And, BoolExpr::And(a, b)) | (Junction::Or, BoolExpr::Or(a, b)) => {
+                    collect(junction, a, out);
+                    collect(junction, b, out);
                 }
+                (_, expr) => out.push(expr),
             }
-            Not(a) => {
-                let a = a.simplify();
-                match a {
-                    False => True,
-                    True => False,
-                    a => a.not(),
-                }
+        }
+
+        let mut out = Vec::new();
+        collect(self, expr, &mut out);
+        out
+    }
+
+    fn join(self, a: BoolExpr, b: BoolExpr) -> BoolExpr {
+        match self {
+            Junction::And => a.and(b),
+            Junction::Or => a.or(b),
+        }
+    }
+}
+
+fn simplify_junction(junction: Junction, a: BoolExpr, b: BoolExpr) -> BoolExpr {
+    let dominant = junction.dominant();
+
+    let mut operands = Vec::new();
+    junction.flatten_into(a.simplify(), &mut operands);
+    junction.flatten_into(b.simplify(), &mut operands);
+    if operands.contains(&dominant) {
+        return dominant;
+    }
+    operands.retain(|expr| expr.as_bool().is_none());
+    operands.sort_by(canonical_order);
+    operands.dedup();
+
+    // `a && !a` is `false` and `a || !a` is `true`.
+    let complemented = operands.iter().any(|expr| match expr {
+        BoolExpr::Not(a) => operands.binary_search_by(|b| canonical_order(b, a)).is_ok(),
+        _ => false,
+    });
+    if complemented {
+        return dominant;
+    }
+
+    // Absorption: `a && (a || b)` is `a`, and `a || (a && b)` is `a`. An operand is redundant if
+    // its own operands include all operands of another one.
+    let dual = junction.dual();
+    let is_absorbed = |i: usize| {
+        let outer = dual.operands(&operands[i]);
+        operands.iter().enumerate().any(|(j, other)| {
+            j != i && {
+                let inner = dual.operands(other);
+                inner.len() < outer.len() && inner.iter().all(|e| outer.contains(e))
             }
-            a => a,
+        })
+    };
+    let absorbed: Vec<bool> = (0..operands.len()).map(is_absorbed).collect();
+    let mut operands = operands
+        .into_iter()
+        .zip(absorbed)
+        .filter(|(_, absorbed)| !absorbed)
+        .map(|(expr, _)| expr);
+    match operands.next() {
+        // Every operand was the neutral constant.
+        None => match junction {
+            Junction::And => BoolExpr::True,
+            Junction::Or => BoolExpr::False,
+        },
+        Some(first) => operands.fold(first, |a, b| junction.join(a, b)),
+    }
+}
+
+/// Orders plain and negated atoms by name ahead of compound expressions.
+fn canonical_order(a: &BoolExpr, b: &BoolExpr) -> Ordering {
+    fn key(expr: &BoolExpr) -> (bool, Option<&Atom>, bool) {
+        match expr {
+            BoolExpr::Single(atom) => (false, Some(atom), false),
+            BoolExpr::Not(a) => match &**a {
+                BoolExpr::Single(atom) => (false, Some(atom), true),
+                _ => (true, None, true),
+            },
+            _ => (true, None, false),
         }
     }
+
+    key(a).cmp(&key(b)).then_with(|| a.cmp(b))
 }
 
 #[derive(Eq, PartialEq)]
@@ -145,6 +331,13 @@ impl BoolExpr {
     }
 }
 
+/// Serializes to the Nix rendering, which is what the template embeds.
+impl Serialize for BoolExpr {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.to_nix())
+    }
+}
+
 fn parenthesize_if<F>(cond: bool, f: &mut fmt::Formatter, inner: F) -> fmt::Result
 where
     F: FnOnce(&mut fmt::Formatter) -> fmt::Result,
@@ -181,3 +374,123 @@ where
         (self.0)(f)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Atom;
+    use super::BoolExpr::{self, *};
+
+    const ATOMS: &[&str] = &["a", "b", "c", "d"];
+
+    fn atom(name: &str) -> BoolExpr {
+        Single(Atom::Var(name.to_string()))
+    }
+
+    fn eval(expr: &BoolExpr, assignment: u32) -> bool {
+        match expr {
+            And(a, b) => eval(a, assignment) && eval(b, assignment),
+            Or(a, b) => eval(a, assignment) || eval(b, assignment),
+            Not(a) => !eval(a, assignment),
+            Single(atom) => {
+                let i = ATOMS
+                    .iter()
+                    .position(|a| atom == &Atom::Var(a.to_string()))
+                    .unwrap();
+                assignment & (1 << i) != 0
+            }
+            True => true,
+            False => false,
+        }
+    }
+
+    /// A xorshift generator, so that failures are reproducible from the printed seed.
+    struct Rng(u64);
+
+    impl Rng {
+        fn below(&mut self, n: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % n
+        }
+    }
+
+    fn arbitrary(rng: &mut Rng, depth: u32) -> BoolExpr {
+        let leaf = depth == 0 || rng.below(4) == 0;
+        match rng.below(if leaf { 6 } else { 3 }) {
+            _ if leaf => match rng.below(12) {
+                0 => True,
+                1 => False,
+                i => atom(ATOMS[i as usize % ATOMS.len()]),
+            },
+            0 => arbitrary(rng, depth - 1).and(arbitrary(rng, depth - 1)),
+            1 => arbitrary(rng, depth - 1).or(arbitrary(rng, depth - 1)),
+            _ => arbitrary(rng, depth - 1).not(),
+        }
+    }
+
+    #[test]
+    fn simplify_preserves_meaning() {
+        let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+        for _ in 0..20_000 {
+            let expr = arbitrary(&mut rng, 6);
+            let simplified = expr.clone().simplify();
+            for assignment in 0..1 << ATOMS.len() {
+                assert_eq!(
+                    eval(&expr, assignment),
+                    eval(&simplified, assignment),
+                    "`{}` simplified to `{}` under assignment {:04b}",
+                    expr.to_nix(),
+                    simplified.to_nix(),
+                    assignment
+                );
+            }
+            assert_eq!(
+                simplified.clone().simplify(),
+                simplified,
+                "simplifying `{}` is not idempotent",
+                expr.to_nix()
+            );
+        }
+    }
+
+    #[test]
+    fn simplify_minimises() {
+        let (a, b, c) = (atom("a"), atom("b"), atom("c"));
+        let cases = vec![
+            (a.clone().or(a.clone()), "a"),
+            (a.clone().and(a.clone().or(b.clone())), "a"),
+            (a.clone().or(b.clone().and(a.clone())), "a"),
+            (a.clone().not().not(), "a"),
+            (a.clone().and(a.clone().not()), "false"),
+            (
+                b.clone().or(c.clone()).or(a.clone().not()).or(a.clone()),
+                "true",
+            ),
+            (
+                c.clone().and(a.clone()).and(b.clone().not()),
+                "a && !b && c",
+            ),
+            (
+                (b.clone().or(a.clone())).and(c.clone().or(a.clone().or(b.clone()))),
+                "a || b",
+            ),
+            (
+                (b.clone().and(c.clone()))
+                    .or(a.clone().and(c.clone()))
+                    .or(c.clone().and(b)),
+                "a && c || b && c",
+            ),
+        ];
+
+        for (expr, expected) in cases {
+            let rendered = expr.to_nix().to_string();
+            assert_eq!(
+                expr.simplify().to_nix().to_string(),
+                expected,
+                "simplifying `{}`",
+                rendered
+            );
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::Path;
+
+use anyhow::Result;
+use cargo::core::{dependency::DepKind, Package, PackageId, SourceId};
+use serde::Serialize;
+
+use crate::template::to_cfg_condition;
+use crate::ResolvedPackage;
+
+/// The resolved dependency graph as an adjacency list, keyed by node id.
+#[derive(Debug, Serialize)]
+pub struct Graph {
+    pub nodes: BTreeMap<String, Node>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Node {
+    pub name: String,
+    pub version: String,
+    /// The source id, with the paths of path packages relative to the workspace root.
+    pub source: String,
+    pub workspace_member: bool,
+    pub dependencies: Vec<Edge>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Edge {
+    /// The id of the dependency's node.
+    pub to: String,
+    pub kind: &'static str,
+    pub extern_name: String,
+    /// The condition in `Cargo.nix` under which the dependency is included.
+    pub condition: Option<String>,
+}
+
+impl Graph {
+    pub fn new(
+        ws_root: &Path,
+        root_pkgs: &[&Package],
+        rpkgs_by_id: &BTreeMap<PackageId, ResolvedPackage>,
+    ) -> Self {
+        let ids = node_ids(rpkgs_by_id.keys().copied(), ws_root);
+        let members: BTreeSet<_> = root_pkgs.iter().map(|pkg| pkg.package_id()).collect();
+
+        let nodes = rpkgs_by_id
+            .iter()
+            .map(|(id, rpkg)| {
+                let dependencies = rpkg
+                    .deps
+                    .iter()
+                    .map(|((dep_id, kind), dep)| Edge {
+                        to: ids[dep_id].clone(),
+                        kind: match kind {
+                            DepKind::Normal => "normal",
+                            DepKind::Development => "dev",
+                            DepKind::Build => "build",
+                        },
+                        extern_name: dep.extern_name.clone(),
+                        condition: to_cfg_condition(dep).map(|c| c.to_nix().to_string()),
+                    })
+                    .collect();
+
+                let node = Node {
+                    name: id.name().to_string(),
+                    version: id.version().to_string(),
+                    source: source(id.source_id(), ws_root),
+                    workspace_member: members.contains(id),
+                    dependencies,
+                };
+                (ids[id].clone(), node)
+            })
+            .collect();
+
+        Graph { nodes }
+    }
+
+    pub fn write_json(&self, out: impl io::Write) -> Result<()> {
+        serde_json::to_writer_pretty(out, self)?;
+        Ok(())
+    }
+
+    /// Writes Graphviz DOT. Build dependencies are dashed, dev dependencies dotted, and
+    /// conditional edges are labeled with their condition.
+    pub fn write_dot(&self, mut out: impl io::Write) -> Result<()> {
+        writeln!(out, "digraph dependencies {{")?;
+        writeln!(out, "  node [shape=box];")?;
+        for (id, node) in self.nodes.iter() {
+            let style = if node.workspace_member {
+                ", style=bold"
+            } else {
+                ""
+            };
+            writeln!(
+                out,
+                "  {:?} [label={:?}{}];",
+                id,
+                format!("{}\n{}", node.name, node.version),
+                style
+            )?;
+        }
+
+        for (id, node) in self.nodes.iter() {
+            for edge in node.dependencies.iter() {
+                let mut attrs = Vec::new();
+                match edge.kind {
+                    "build" => attrs.push("style=dashed".to_string()),
+                    "dev" => attrs.push("style=dotted".to_string()),
+                    _ => {}
+                }
+                if let Some(condition) = &edge.condition {
+                    attrs.push(format!("label={:?}", condition));
+                }
+
+                write!(out, "  {:?} -> {:?}", id, edge.to)?;
+                if !attrs.is_empty() {
+                    write!(out, " [{}]", attrs.join(", "))?;
+                }
+                writeln!(out, ";")?;
+            }
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+}
+
+/// Describes a source like its id does, but keeps the absolute paths of path sources out of the
+/// output, so that it does not depend on where the workspace is checked out.
+fn source(source_id: SourceId, ws_root: &Path) -> String {
+    if !source_id.is_path() {
+        return source_id.to_string();
+    }
+
+    let path = Path::new(source_id.url().path());
+    match pathdiff::diff_paths(path, ws_root) {
+        Some(relative) if relative.as_os_str().is_empty() => "path+.".to_string(),
+        Some(relative) => format!("path+{}", relative.display()),
+        None => source_id.to_string(),
+    }
+}
+
+/// Names nodes `<name> <version>`, adding the source only for packages that would clash.
+fn node_ids(
+    pkg_ids: impl Iterator<Item = PackageId>,
+    ws_root: &Path,
+) -> BTreeMap<PackageId, String> {
+    let pkg_ids: Vec<_> = pkg_ids.collect();
+    let short = |id: &PackageId| format!("{} {}", id.name(), id.version());
+    let mut counts = BTreeMap::new();
+    for id in pkg_ids.iter() {
+        *counts.entry(short(id)).or_insert(0) += 1;
+    }
+
+    pkg_ids
+        .iter()
+        .map(|id| {
+            let name = short(id);
+            if counts[&name] > 1 {
+                (
+                    *id,
+                    format!("{} ({})", name, source(id.source_id(), ws_root)),
+                )
+            } else {
+                (*id, name)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,262 @@
+use std::iter::Peekable;
+
+use anyhow::{anyhow, Result};
+
+use crate::template::to_nix_string;
+
+/// SPDX license identifiers and the attributes of `lib.licenses` in nixpkgs that they correspond to.
+const NIX_LICENSES: &[(&str, &str)] = &[
+    ("0BSD", "bsd0"),
+    ("AGPL-3.0-only", "agpl3Only"),
+    ("AGPL-3.0-or-later", "agpl3Plus"),
+    ("Apache-2.0", "asl20"),
+    ("Artistic-2.0", "artistic2"),
+    ("BSD-1-Clause", "bsd1"),
+    ("BSD-2-Clause", "bsd2"),
+    ("BSD-3-Clause", "bsd3"),
+    ("BSL-1.0", "boost"),
+    ("CC-BY-4.0", "cc-by-40"),
+    ("CC0-1.0", "cc0"),
+    ("CDDL-1.0", "cddl"),
+    ("EPL-2.0", "epl20"),
+    ("GPL-2.0", "gpl2Only"),
+    ("GPL-2.0+", "gpl2Plus"),
+    ("GPL-2.0-only", "gpl2Only"),
+    ("GPL-2.0-or-later", "gpl2Plus"),
+    ("GPL-3.0", "gpl3Only"),
+    ("GPL-3.0+", "gpl3Plus"),
+    ("GPL-3.0-only", "gpl3Only"),
+    ("GPL-3.0-or-later", "gpl3Plus"),
+    ("ISC", "isc"),
+    ("LGPL-2.1", "lgpl21Only"),
+    ("LGPL-2.1+", "lgpl21Plus"),
+    ("LGPL-2.1-only", "lgpl21Only"),
+    ("LGPL-2.1-or-later", "lgpl21Plus"),
+    ("LGPL-3.0", "lgpl3Only"),
+    ("LGPL-3.0+", "lgpl3Plus"),
+    ("LGPL-3.0-only", "lgpl3Only"),
+    ("LGPL-3.0-or-later", "lgpl3Plus"),
+    ("MIT", "mit"),
+    ("MPL-2.0", "mpl20"),
+    ("NCSA", "ncsa"),
+    ("OpenSSL", "openssl"),
+    ("PostgreSQL", "postgresql"),
+    ("Unicode-DFS-2016", "unicode-dfs-2016"),
+    ("Unlicense", "unlicense"),
+    ("WTFPL", "wtfpl"),
+    ("Zlib", "zlib"),
+];
+
+/// The license identifiers in an SPDX expression, also accepting the `MIT/Apache-2.0` form of
+/// older manifests. Returns `None` for expressions with exceptions (`WITH`), which `lib.licenses`
+/// cannot express.
+fn license_ids(expr: &str) -> Option<Vec<&str>> {
+    let mut ids = Vec::new();
+    for token in expr.split(|c: char| c.is_whitespace() || "/()".contains(c)) {
+        match token {
+            "" | "OR" | "AND" => {}
+            "WITH" => return None,
+            id => ids.push(id),
+        }
+    }
+    Some(ids)
+}
+
+/// Translates an SPDX license expression into a Nix expression for `meta.license`: a list of
+/// `lib.licenses` attributes if all licenses in it are known, or the expression as a string.
+pub fn to_nix(expr: &str) -> String {
+    let attrs: Option<Vec<_>> = license_ids(expr).and_then(|ids| {
+        ids.into_iter()
+            .map(|id| {
+                NIX_LICENSES
+                    .iter()
+                    .find(|(spdx, _)| spdx.eq_ignore_ascii_case(id))
+                    .map(|(_, attr)| *attr)
+            })
+            .collect()
+    });
+
+    match attrs {
+        Some(attrs) if !attrs.is_empty() => {
+            let attrs: Vec<_> = attrs
+                .iter()
+                .map(|attr| {
+                    if attr.contains('-') {
+                        format!("lib.licenses.{:?}", attr)
+                    } else {
+                        format!("lib.licenses.{}", attr)
+                    }
+                })
+                .collect();
+            format!("[ {} ]", attrs.join(" "))
+        }
+        _ => format!("\"{}\"", to_nix_string(expr)),
+    }
+}
+
+/// Rewrites the `MIT/Apache-2.0` form of older manifests into an SPDX expression.
+pub fn normalize(expr: &str) -> String {
+    expr.split('/')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// Consumes the next token if it is `keyword`.
+fn next_is<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>, keyword: &str) -> bool {
+    let is_keyword = tokens
+        .peek()
+        .map_or(false, |t| t.eq_ignore_ascii_case(keyword));
+    if is_keyword {
+        tokens.next();
+    }
+    is_keyword
+}
+
+fn is_operator(token: &str) -> bool {
+    ["AND", "OR", "WITH"]
+        .iter()
+        .any(|op| token.eq_ignore_ascii_case(op))
+}
+
+/// A parsed SPDX license expression.
+#[derive(Debug, PartialEq)]
+pub enum Expr {
+    /// A license identifier, with the exception it is granted with, if any.
+    License(String, Option<String>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Parses an SPDX license expression, where `AND` binds tighter than `OR`. The `MIT/Apache-2.0`
+    /// form of older manifests is read as `MIT OR Apache-2.0`.
+    pub fn parse(expr: &str) -> Result<Self> {
+        let normalized = normalize(expr).replace('(', " ( ").replace(')', " ) ");
+        let mut tokens = normalized.split_whitespace().peekable();
+        let parsed = Self::parse_or(&mut tokens)
+            .ok_or_else(|| anyhow!("invalid license expression `{}`", expr))?;
+        match tokens.next() {
+            None => Ok(parsed),
+            Some(token) => Err(anyhow!(
+                "unexpected `{}` in license expression `{}`",
+                token,
+                expr
+            )),
+        }
+    }
+
+    fn parse_or<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        let mut expr = Self::parse_and(tokens)?;
+        while next_is(tokens, "OR") {
+            expr = Expr::Or(Box::new(expr), Box::new(Self::parse_and(tokens)?));
+        }
+        Some(expr)
+    }
+
+    fn parse_and<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        let mut expr = Self::parse_license(tokens)?;
+        while next_is(tokens, "AND") {
+            expr = Expr::And(Box::new(expr), Box::new(Self::parse_license(tokens)?));
+        }
+        Some(expr)
+    }
+
+    fn parse_license<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<Self> {
+        match tokens.next()? {
+            "(" => {
+                let expr = Self::parse_or(tokens)?;
+                tokens.next().filter(|t| *t == ")")?;
+                Some(expr)
+            }
+            ")" => None,
+            id if is_operator(id) => None,
+            id => {
+                let exception = if next_is(tokens, "WITH") {
+                    Some(tokens.next().filter(|t| *t != "(" && *t != ")")?)
+                } else {
+                    None
+                };
+                Some(Expr::License(id.to_string(), exception.map(str::to_string)))
+            }
+        }
+    }
+
+    /// The licenses in the expression, with their exceptions.
+    pub fn licenses(&self) -> Vec<(&str, Option<&str>)> {
+        match self {
+            Expr::License(id, exception) => vec![(id, exception.as_deref())],
+            Expr::And(a, b) | Expr::Or(a, b) => {
+                let mut licenses = a.licenses();
+                licenses.extend(b.licenses());
+                licenses
+            }
+        }
+    }
+
+    /// Whether the licenses that `accepts` accepts are enough to use the crate under the expression.
+    pub fn is_satisfied_by(&self, accepts: &impl Fn(&str, Option<&str>) -> bool) -> bool {
+        match self {
+            Expr::License(id, exception) => accepts(id, exception.as_deref()),
+            Expr::And(a, b) => a.is_satisfied_by(accepts) && b.is_satisfied_by(accepts),
+            Expr::Or(a, b) => a.is_satisfied_by(accepts) || b.is_satisfied_by(accepts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn licenses_translate_to_nix() {
+        assert_eq!(to_nix("MIT"), "[ lib.licenses.mit ]");
+        assert_eq!(
+            to_nix("MIT OR Apache-2.0"),
+            "[ lib.licenses.mit lib.licenses.asl20 ]"
+        );
+        assert_eq!(
+            to_nix("MIT/Apache-2.0"),
+            "[ lib.licenses.mit lib.licenses.asl20 ]"
+        );
+        assert_eq!(
+            to_nix("(MIT OR Apache-2.0) AND Unicode-DFS-2016"),
+            "[ lib.licenses.mit lib.licenses.asl20 lib.licenses.\"unicode-dfs-2016\" ]"
+        );
+        assert_eq!(
+            to_nix("Apache-2.0 WITH LLVM-exception"),
+            "\"Apache-2.0 WITH LLVM-exception\""
+        );
+        assert_eq!(to_nix("MIT OR Custom"), "\"MIT OR Custom\"");
+    }
+
+    #[test]
+    fn expressions_parse_with_precedence() {
+        let license = |id: &str| Box::new(Expr::License(id.to_string(), None));
+        assert_eq!(
+            Expr::parse("MIT OR Apache-2.0 AND Zlib").unwrap(),
+            Expr::Or(
+                license("MIT"),
+                Box::new(Expr::And(license("Apache-2.0"), license("Zlib")))
+            )
+        );
+        assert_eq!(
+            Expr::parse("(MIT/Apache-2.0) AND Zlib").unwrap(),
+            Expr::And(
+                Box::new(Expr::Or(license("MIT"), license("Apache-2.0"))),
+                license("Zlib")
+            )
+        );
+        assert_eq!(
+            Expr::parse("Apache-2.0 WITH LLVM-exception").unwrap(),
+            Expr::License("Apache-2.0".to_string(), Some("LLVM-exception".to_string()))
+        );
+        for invalid in ["", "MIT OR", "(MIT", "MIT)", "MIT WITH", "AND MIT"].iter() {
+            assert!(
+                Expr::parse(invalid).is_err(),
+                "{:?} should not parse",
+                invalid
+            );
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use cargo::core::{source::GitReference, Package, PackageId};
+
+use crate::overrides::{self, Metadata};
+use crate::{native, platform, ResolvedPackage};
+
+/// A check for a dependency shape that resolves fine with cargo but causes trouble when Nix builds
+/// it. The codes are stable, so that projects can allow lints by code or name.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Lint {
+    pub code: &'static str,
+    pub name: &'static str,
+    help: &'static str,
+}
+
+pub const GIT_BRANCH: Lint = Lint {
+    code: "C2N001",
+    name: "git-branch",
+    help: "Cargo.nix fetches the revision in Cargo.lock, which moves with every `cargo update`; \
+           pin the dependency with `rev = \"...\"` to build the same sources everywhere",
+};
+
+pub const SYS_WITHOUT_OVERRIDE: Lint = Lint {
+    code: "C2N002",
+    name: "sys-without-override",
+    help: "build scripts cannot download or find native libraries in the Nix sandbox; pass an \
+           override adding them to the build inputs in `packageOverrides`, such as the one that \
+           `cargo2nix --stdout --override-stubs` suggests, or list them in `build-inputs` of \
+           [workspace.metadata.cargo2nix.crates.<name>]",
+};
+
+pub const DUPLICATE_VERSIONS: Lint = Lint {
+    code: "C2N003",
+    name: "duplicate-versions",
+    help: "each version is built as a separate derivation; \
+           align the version requirements so that one version satisfies all of them",
+};
+
+pub const PATH_OUTSIDE_WORKSPACE: Lint = Lint {
+    code: "C2N004",
+    name: "path-outside-workspace",
+    help:
+        "Nix only sees the sources under the workspace root, and flakes only those tracked by git; \
+           move the crate into the workspace or depend on it through git",
+};
+
+pub const UNTRANSLATABLE_CFG: Lint = Lint {
+    code: "C2N005",
+    name: "untranslatable-cfg",
+    help: "the cfg has no Nix translation and is assumed to be unset, so a dependency that \
+           requires it is never built and one that excludes it is always built",
+};
+
+pub const LINTS: &[&Lint] = &[
+    &GIT_BRANCH,
+    &SYS_WITHOUT_OVERRIDE,
+    &DUPLICATE_VERSIONS,
+    &PATH_OUTSIDE_WORKSPACE,
+    &UNTRANSLATABLE_CFG,
+];
+
+/// An occurrence of a lint.
+pub struct Warning {
+    pub lint: &'static Lint,
+    pub message: String,
+}
+
+impl Lint {
+    fn find(code_or_name: &str) -> Result<&'static Lint> {
+        LINTS
+            .iter()
+            .copied()
+            .find(|l| l.code.eq_ignore_ascii_case(code_or_name) || l.name == code_or_name)
+            .ok_or_else(|| anyhow!("unknown lint `{}`", code_or_name))
+    }
+}
+
+/// The lints allowed by `allow-lints` in the `[workspace.metadata.cargo2nix]` or
+/// `[package.metadata.cargo2nix]` table of the root manifest.
+pub fn allowed_in_manifest(
+    workspace: &Metadata,
+    package: &Metadata,
+) -> Result<BTreeSet<&'static Lint>> {
+    let lints = workspace
+        .allow_lints
+        .iter()
+        .chain(package.allow_lints.iter());
+    Ok(parse_lints(lints.map(|l| l.as_str()))?
+        .into_iter()
+        .collect())
+}
+
+/// Parses lint codes or names, such as those passed to `--allow`.
+pub fn parse_lints<'a>(lints: impl IntoIterator<Item = &'a str>) -> Result<Vec<&'static Lint>> {
+    lints.into_iter().map(Lint::find).collect()
+}
+
+/// Runs all lints over the resolved graph of the workspace rooted at `ws_root`, whose root
+/// manifest has the `[workspace.metadata.cargo2nix]` table `workspace`.
+pub fn lint(
+    ws_root: &Path,
+    workspace: &Metadata,
+    root_pkgs: &[&Package],
+    rpkgs_by_id: &BTreeMap<PackageId, ResolvedPackage>,
+) -> Vec<Warning> {
+    let is_member = |id: &PackageId| root_pkgs.iter().any(|pkg| pkg.package_id() == *id);
+    let describe = |id: &PackageId| format!("{} {}", id.name(), id.version());
+    let mut warnings = Vec::new();
+    let mut warn = |lint, message| warnings.push(Warning { lint, message });
+
+    for id in rpkgs_by_id.keys() {
+        let source_id = id.source_id();
+        if source_id.is_git() {
+            let branch = match source_id.git_reference() {
+                Some(GitReference::Branch(branch)) => Some(format!("branch `{}`", branch)),
+                Some(GitReference::DefaultBranch) => Some("the default branch".to_string()),
+                _ => None,
+            };
+            if let Some(branch) = branch {
+                let pin = match source_id.precise() {
+                    Some(rev) => format!("only pinned to {} by Cargo.lock", rev),
+                    None => "not pinned by Cargo.lock".to_string(),
+                };
+                warn(
+                    &GIT_BRANCH,
+                    format!(
+                        "{} from {} follows {} and is {}",
+                        describe(id),
+                        source_id.url(),
+                        branch,
+                        pin
+                    ),
+                );
+            }
+        }
+    }
+
+    let overridden = native::overridden_crates();
+    for (id, rpkg) in rpkgs_by_id.iter() {
+        if let Some(links) = rpkg.pkg.manifest().links() {
+            let configured = workspace
+                .for_crate(id.name().as_str())
+                .map_or(false, Metadata::has_build_inputs)
+                || overrides::of_package(rpkg.pkg, workspace)
+                    .map_or(false, |m| m.has_build_inputs());
+            if !is_member(id) && !overridden.contains(id.name().as_str()) && !configured {
+                warn(
+                    &SYS_WITHOUT_OVERRIDE,
+                    format!(
+                        "{} links the native library `{}`, but overlay/overrides.nix has no \
+                         override for {}",
+                        describe(id),
+                        links,
+                        id.name()
+                    ),
+                );
+            }
+        }
+    }
+
+    let mut versions_by_name: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+    for id in rpkgs_by_id.keys() {
+        versions_by_name
+            .entry(id.name().as_str())
+            .or_default()
+            .push(id.version().to_string());
+    }
+    for (name, versions) in versions_by_name.iter() {
+        if versions.len() > 1 {
+            warn(
+                &DUPLICATE_VERSIONS,
+                format!(
+                    "{} is resolved to {} versions: {}",
+                    name,
+                    versions.len(),
+                    versions.join(", ")
+                ),
+            );
+        }
+    }
+
+    for id in rpkgs_by_id.keys() {
+        if id.source_id().is_path() && !is_member(id) {
+            let source_id = id.source_id();
+            let path = Path::new(source_id.url().path());
+            if !path.starts_with(ws_root) {
+                let relative = pathdiff::diff_paths(path, ws_root).unwrap_or_default();
+                warn(
+                    &PATH_OUTSIDE_WORKSPACE,
+                    format!(
+                        "{} at {} is outside the workspace",
+                        describe(id),
+                        relative.display()
+                    ),
+                );
+            }
+        }
+    }
+
+    let mut users_by_cfg: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (id, rpkg) in rpkgs_by_id.iter() {
+        let platforms = rpkg.deps.values().filter_map(|d| d.platforms.as_ref());
+        for platform in platforms.flatten() {
+            for cfg in platform::untranslatable_cfgs(platform, "hostPlatform") {
+                users_by_cfg.entry(cfg).or_default().insert(describe(id));
+            }
+        }
+    }
+    for (cfg, users) in users_by_cfg {
+        let users: Vec<_> = users.into_iter().collect();
+        warn(
+            &UNTRANSLATABLE_CFG,
+            format!(
+                "cfg({}) translates to false in Cargo.nix, but selects dependencies of {}",
+                cfg,
+                users.join(", ")
+            ),
+        );
+    }
+
+    warnings
+}
+
+/// Prints the warnings of lints that are not allowed, and fails if there are any.
+pub fn report(
+    warnings: &[Warning],
+    allowed: &BTreeSet<&Lint>,
+    mut out: impl io::Write,
+) -> Result<()> {
+    let mut count = 0;
+    for lint in LINTS.iter() {
+        if allowed.contains(lint) {
+            continue;
+        }
+        let warnings: Vec<_> = warnings.iter().filter(|w| w.lint == *lint).collect();
+        if warnings.is_empty() {
+            continue;
+        }
+
+        if count > 0 {
+            writeln!(out)?;
+        }
+        for warning in warnings.iter() {
+            writeln!(
+                out,
+                "warning[{}/{}]: {}",
+                lint.code, lint.name, warning.message
+            )?;
+        }
+        writeln!(out, "  = help: {}", lint.help)?;
+        writeln!(
+            out,
+            "  = note: to allow this, add \"{}\" to `allow-lints` in [workspace.metadata.cargo2nix] \
+             or pass `--allow {}`",
+            lint.code, lint.code
+        )?;
+        count += warnings.len();
+    }
+
+    match count {
+        0 => Ok(()),
+        1 => Err(anyhow!("1 lint warning")),
+        n => Err(anyhow!("{} lint warnings", n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lints_are_found_by_code_or_name() {
+        assert_eq!(Lint::find("C2N003").unwrap(), &DUPLICATE_VERSIONS);
+        assert_eq!(Lint::find("c2n003").unwrap(), &DUPLICATE_VERSIONS);
+        assert_eq!(
+            Lint::find("duplicate-versions").unwrap(),
+            &DUPLICATE_VERSIONS
+        );
+        assert!(Lint::find("C2N999").is_err());
+    }
+}
@@ -4,7 +4,7 @@ use std::{
     collections::{BTreeMap, BTreeSet, HashMap},
     fs,
     io::{self, BufRead, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
 use anyhow::{anyhow, Context, Result};
@@ -19,20 +19,32 @@ use cargo::{
         Package, PackageId, PackageIdSpec, Workspace,
     },
     ops::{resolve_ws_with_opts, Packages},
-    util::important_paths::find_root_manifest_for_wd,
+    util::{config::Definition, important_paths::find_root_manifest_for_wd},
 };
 use cargo_platform::Platform;
 use colorify::colorify;
 use semver::{Version, VersionReq};
 use tera::Tera;
 
-use crate::expr::BoolExpr;
+use crate::expr::{Atom, BoolExpr};
 use crate::template::BuildPlan;
 
+mod audit;
+mod diff;
+mod eval;
+mod explain;
 mod expr;
+mod graph;
+mod license;
+mod lint;
 mod manifest;
+mod native;
+mod overrides;
 mod platform;
+mod policy;
+mod sbom;
 mod template;
+mod why;
 
 type Feature<'a> = &'a str;
 type PackageName<'a> = &'a str;
@@ -52,9 +64,29 @@ fn main() {
 
 fn try_main(args: &[&str]) -> Result<()> {
     match &args[1..] {
-        ["--stdout"] | ["-s"] => generate_cargo_nix(io::stdout().lock()),
-        ["--file"] | ["-f"] => write_to_file("Cargo.nix"),
-        ["--file", file] | ["-f", file] => write_to_file(file),
+        [output, ..] if ["--stdout", "-s", "--file", "-f"].contains(output) => {
+            write_cargo_nix(&args[1..])
+        }
+        ["flake"] => write_to_file("flake.nix", |out| generate_flake(out)),
+        ["flake", "--stdout"] | ["flake", "-s"] => generate_flake(io::stdout().lock()),
+        ["explain", args @ ..] => explain(args),
+        ["sbom", args @ ..] => sbom(args),
+        ["audit", args @ ..] => audit(args),
+        ["lint", args @ ..] => lint(args),
+        ["plan"] => {
+            let plan = build_plan(&cargo_config()?)?;
+            serde_json::to_writer_pretty(io::stdout().lock(), &plan)?;
+            Ok(())
+        }
+        ["diff", "--git", old] => diff_revisions(old, None),
+        ["diff", "--git", old, new] => diff_revisions(old, Some(new)),
+        ["diff", old, new] => diff_files(old, new),
+        ["graph"] => graph("dot"),
+        ["graph", "--format", format] => graph(format),
+        ["licenses"] => licenses("license-policy.toml"),
+        ["licenses", "--policy", policy] => licenses(policy),
+        ["why", spec] => why(spec, None),
+        ["why", spec, feature] => why(spec, Some(feature)),
         ["--help"] | ["-h"] => print_help(),
         ["--version"] | ["-v"] => {
             println!("{}", version());
@@ -111,12 +143,51 @@ fn print_help() -> Result<()> {
     println!("$ cargo2nix -s,--stdout            # Output to stdout");
     println!("$ cargo2nix -f,--file              # Output to Cargo.nix");
     println!("$ cargo2nix -f,--file <file>       # Output to the given file");
+    println!("$ cargo2nix -s,--stdout|-f,--file [<file>] --override-stubs");
+    println!(
+        "                                   # Same, suggesting overrides for native libraries"
+    );
+    println!(
+        "$ cargo2nix explain [--target <triple>] [--features <root features>] [--cfg <cfg>]..."
+    );
+    println!("                                   # Print the crates and features Cargo.nix builds");
+    println!("$ cargo2nix sbom [<member>] [--format cyclonedx|spdx] [--target <triple>]");
+    println!("                 [--features <features>] [--no-default-features] [--cfg <cfg>]...");
+    println!("                 [--namespace <SPDX namespace URI prefix>]");
+    println!(
+        "                                   # Print an SBOM of the crates linked into a member"
+    );
+    println!("$ cargo2nix audit [--db <advisory-db>] [--target <triple>]... [--features <root features>]");
+    println!("                  [--cfg <cfg>]... [--json]");
+    println!(
+        "                                   # Check the crates against the RustSec advisory database"
+    );
+    println!("$ cargo2nix lint [--allow <lint>]...");
+    println!(
+        "                                   # Check for dependencies that are hard to build with Nix"
+    );
+    println!("$ cargo2nix plan                   # Print the build plan as JSON");
+    println!("$ cargo2nix diff <old.json> <new.json>");
+    println!("                                   # Print the changes between two build plans");
+    println!("$ cargo2nix diff --git <old rev> [<new rev>]");
+    println!("                                   # Same, for the workspace at git revisions");
+    println!("$ cargo2nix graph [--format dot|json]");
+    println!("                                   # Print the resolved dependency graph");
+    println!("$ cargo2nix why <package> [<feature>]");
+    println!("                                   # Print what enables a package or feature");
+    println!("$ cargo2nix licenses [--policy <file>]");
+    println!(
+        "                                   # Check licenses against license-policy.toml or <file>"
+    );
     println!("$ cargo2nix -v,--version           # Print version of cargo2nix");
     println!("$ cargo2nix -h,--help              # Print the help");
     Ok(())
 }
 
-fn write_to_file(file: impl AsRef<Path>) -> Result<()> {
+fn write_to_file(
+    file: impl AsRef<Path>,
+    generate: impl FnOnce(&mut dyn io::Write) -> Result<()>,
+) -> Result<()> {
     let path = file.as_ref();
     if path.exists() {
         let (vers_req, ver) = version_req(path)?;
@@ -161,7 +232,7 @@ fn write_to_file(file: impl AsRef<Path>) -> Result<()> {
         .tempfile()
         .context("could not create new temporary file")?;
 
-    generate_cargo_nix(&mut temp_file)?;
+    generate(&mut temp_file)?;
 
     if let Err(err) = temp_file.persist(path) {
         let (_, temp_path) = err.file.keep()?;
@@ -172,15 +243,431 @@ fn write_to_file(file: impl AsRef<Path>) -> Result<()> {
     Ok(())
 }
 
-fn generate_cargo_nix(mut out: impl io::Write) -> Result<()> {
-    let config = {
-        let mut config = cargo::Config::default()?;
-        config.configure(0, true, None, false, true, false, &None, &[], &[])?;
-        config
+/// Writes `Cargo.nix` to stdout or a file, as `args` select, emitting suggested overrides as
+/// comments if `--override-stubs` is among them.
+fn write_cargo_nix(args: &[&str]) -> Result<()> {
+    let override_stubs = args.contains(&"--override-stubs");
+    let args: Vec<_> = args
+        .iter()
+        .copied()
+        .filter(|&arg| arg != "--override-stubs")
+        .collect();
+    match &args[..] {
+        ["--stdout"] | ["-s"] => generate_cargo_nix(io::stdout().lock(), override_stubs),
+        ["--file"] | ["-f"] => {
+            write_to_file("Cargo.nix", |out| generate_cargo_nix(out, override_stubs))
+        }
+        ["--file", file] | ["-f", file] => {
+            write_to_file(file, |out| generate_cargo_nix(out, override_stubs))
+        }
+        _ => Err(anyhow!(
+            "invalid arguments for writing Cargo.nix: {:?}",
+            args
+        )),
+    }
+}
+
+/// Generates `Cargo.nix`, printing where each profile setting is defined and suggested overrides
+/// for crates that link native libraries without one of the bundled overrides, and emitting the
+/// latter as comments if `override_stubs` is set.
+fn generate_cargo_nix(out: impl io::Write, override_stubs: bool) -> Result<()> {
+    let mut plan = build_plan(&cargo_config()?)?;
+    // Settings from the manifest are where one would expect them; only point out the others.
+    for (name, settings) in plan.profile_sources.iter() {
+        for (setting, definition) in settings.iter() {
+            if let Definition::Path(path) = definition {
+                if path.ends_with("Cargo.toml") {
+                    continue;
+                }
+            }
+            eprintln!(
+                "note: profile.{}.{} is set in {}",
+                name, setting, definition
+            );
+        }
+    }
+    let stubs: Vec<_> = native::hints(&plan)
+        .iter()
+        .map(|hint| {
+            let library = match hint.library {
+                Some(attr) => format!(", likely pkgs.{}", attr),
+                None => String::new(),
+            };
+            eprintln!(
+                "note: {} {} links the native library `{}`{}, but no bundled override provides it; \
+                 pass an override like this in `packageOverrides`:\n{}",
+                hint.krate.name,
+                hint.krate.version,
+                hint.links,
+                library,
+                hint.to_override()
+            );
+            hint.to_override()
+        })
+        .collect();
+    if override_stubs {
+        plan.override_stubs = stubs;
+    }
+
+    render(
+        "Cargo.nix.tera",
+        include_str!("../templates/Cargo.nix.tera"),
+        &plan,
+        out,
+    )
+}
+
+/// Generates a flake exposing each workspace member as a package and a check running its tests,
+/// along with a development shell.
+fn generate_flake(out: impl io::Write) -> Result<()> {
+    render(
+        "flake.nix.tera",
+        include_str!("../templates/flake.nix.tera"),
+        &build_plan(&cargo_config()?)?,
+        out,
+    )
+}
+
+fn render(name: &str, template: &str, plan: &BuildPlan, mut out: impl io::Write) -> Result<()> {
+    let mut tera = Tera::default();
+    template::register_filters(&mut tera);
+    tera.add_raw_template(name, template)?;
+    let context = tera::Context::from_serialize(plan)?;
+    let rendered = tera.render(name, &context)?;
+    write!(out, "{}", rendered)?;
+
+    Ok(())
+}
+
+/// Lists what `Cargo.nix` builds for a target and root features, i.e. what
+/// `cargo tree -e normal,build --target <triple> --features <features>` reports.
+fn explain(mut args: &[&str]) -> Result<()> {
+    let config = cargo_config()?;
+    let build_triple = config.load_global_rustc(None)?.host.to_string();
+    let mut triple = build_triple.clone();
+    let mut root_features = Vec::new();
+    let mut cfgs = Vec::new();
+    loop {
+        match args {
+            ["--target", value, rest @ ..] => {
+                triple = value.to_string();
+                args = rest;
+            }
+            ["--features", value, rest @ ..] => {
+                root_features.extend(value.split(&[',', ' '][..]).filter(|f| !f.is_empty()));
+                args = rest;
+            }
+            ["--cfg", value, rest @ ..] => {
+                cfgs.push(value.to_string());
+                args = rest;
+            }
+            [] => break,
+            _ => return Err(anyhow!("invalid arguments for explain: {:?}", args)),
+        }
+    }
+
+    let plan = build_plan(&config)?;
+    if root_features.is_empty() {
+        root_features.extend(plan.root_features.iter().map(String::as_str));
+    }
+
+    let (host, build) = eval_envs(&triple, &build_triple, &root_features, cfgs)?;
+    explain::explain(&plan, &host, &build)?.write(io::stdout().lock(), &triple, &build_triple)
+}
+
+/// The environments `Cargo.nix` is evaluated in for the host and build platforms.
+fn eval_envs(
+    triple: &str,
+    build_triple: &str,
+    root_features: &[&str],
+    cfgs: Vec<String>,
+) -> Result<(eval::Env, eval::Env)> {
+    let mut host = eval::Env::new(eval::NixPlatform::for_triple(triple)?, root_features);
+    host.host_platform_cfgs = cfgs;
+    let mut build = host.clone();
+    build.host_platform = eval::NixPlatform::for_triple(build_triple)?;
+    Ok((host, build))
+}
+
+/// Writes an SBOM of the crates linked into a workspace member when building it for a target with
+/// the given features.
+fn sbom(mut args: &[&str]) -> Result<()> {
+    let config = cargo_config()?;
+    let build_triple = config.load_global_rustc(None)?.host.to_string();
+    let mut triple = build_triple.clone();
+    let mut format = sbom::Format::CycloneDx;
+    let mut namespace = sbom::DEFAULT_SPDX_NAMESPACE;
+    let mut member = None;
+    let mut features = Vec::new();
+    let mut default_features = true;
+    let mut cfgs = Vec::new();
+    loop {
+        match args {
+            ["--format", value, rest @ ..] => {
+                format = sbom::Format::parse(value)?;
+                args = rest;
+            }
+            ["--namespace", value, rest @ ..] => {
+                namespace = value;
+                args = rest;
+            }
+            ["--target", value, rest @ ..] => {
+                triple = value.to_string();
+                args = rest;
+            }
+            ["--features", value, rest @ ..] => {
+                features.extend(value.split(&[',', ' '][..]).filter(|f| !f.is_empty()));
+                args = rest;
+            }
+            ["--no-default-features", rest @ ..] => {
+                default_features = false;
+                args = rest;
+            }
+            ["--cfg", value, rest @ ..] => {
+                cfgs.push(value.to_string());
+                args = rest;
+            }
+            [value, rest @ ..] if member.is_none() && !value.starts_with('-') => {
+                member = Some(*value);
+                args = rest;
+            }
+            [] => break,
+            _ => return Err(anyhow!("invalid arguments for sbom: {:?}", args)),
+        }
+    }
+
+    let plan = build_plan(&config)?;
+    let member = match (member, &plan.workspace_members[..]) {
+        (None, [member]) => member,
+        (None, _) => return Err(anyhow!("the workspace has several members, choose one")),
+        (Some(name), members) => members
+            .iter()
+            .find(|m| m.name == name)
+            .ok_or_else(|| anyhow!("`{}` is not a workspace member", name))?,
+    };
+
+    let mut root_features = vec![member.name.clone()];
+    if default_features {
+        root_features.push(format!("{}/default", member.name));
+    }
+    root_features.extend(features.iter().map(|f| format!("{}/{}", member.name, f)));
+    let root_features: Vec<_> = root_features.iter().map(String::as_str).collect();
+
+    let (host, build) = eval_envs(&triple, &build_triple, &root_features, cfgs)?;
+    let explanation = explain::explain(&plan, &host, &build)?;
+    let mut out = io::stdout();
+    sbom::Sbom::new(&plan, member, &explanation)?.write(format, namespace, &mut out)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+/// Matches the crates of the plan against a local checkout of the RustSec advisory database,
+/// by default the one cargo-audit keeps in `$CARGO_HOME/advisory-db`, noting for each target
+/// whether `Cargo.nix` builds an affected crate for it.
+fn audit(mut args: &[&str]) -> Result<()> {
+    let config = cargo_config()?;
+    let build_triple = config.load_global_rustc(None)?.host.to_string();
+    let mut db = config.home().as_path_unlocked().join("advisory-db");
+    let mut triples = Vec::new();
+    let mut root_features = Vec::new();
+    let mut cfgs = Vec::new();
+    let mut json = false;
+    loop {
+        match args {
+            ["--db", value, rest @ ..] => {
+                db = PathBuf::from(value);
+                args = rest;
+            }
+            ["--target", value, rest @ ..] => {
+                triples.push(value.to_string());
+                args = rest;
+            }
+            ["--features", value, rest @ ..] => {
+                root_features.extend(value.split(&[',', ' '][..]).filter(|f| !f.is_empty()));
+                args = rest;
+            }
+            ["--cfg", value, rest @ ..] => {
+                cfgs.push(value.to_string());
+                args = rest;
+            }
+            ["--json", rest @ ..] => {
+                json = true;
+                args = rest;
+            }
+            [] => break,
+            _ => return Err(anyhow!("invalid arguments for audit: {:?}", args)),
+        }
+    }
+
+    let advisories = audit::load_advisories(&db)?;
+    let plan = build_plan(&config)?;
+    if root_features.is_empty() {
+        root_features.extend(plan.root_features.iter().map(String::as_str));
+    }
+    if triples.is_empty() {
+        triples.push(build_triple.clone());
+    }
+
+    let targets = triples
+        .into_iter()
+        .map(|triple| {
+            let (host, build) = eval_envs(&triple, &build_triple, &root_features, cfgs.clone())?;
+            let explanation = explain::explain(&plan, &host, &build)?;
+            Ok(audit::Target {
+                triple,
+                host,
+                build,
+                explanation,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let report = audit::Report::new(&plan, &advisories, &targets)?;
+    let mut out = io::stdout();
+    if json {
+        report.write_json(&mut out)?;
+        writeln!(out)?;
+    } else {
+        report.write(&mut out)?;
+    }
+    report.check()
+}
+
+/// Checks the resolved graph for dependencies that cause trouble once Nix builds them, except for
+/// the lints allowed in the root manifest or by `--allow`.
+fn lint(mut args: &[&str]) -> Result<()> {
+    let config = cargo_config()?;
+    let root_manifest_path = find_root_manifest_for_wd(config.cwd())?;
+    let (workspace_metadata, package_metadata) = overrides::in_manifest(&root_manifest_path)?;
+    let mut allowed = lint::allowed_in_manifest(&workspace_metadata, &package_metadata)?;
+    loop {
+        match args {
+            ["--allow", value, rest @ ..] => {
+                allowed.extend(lint::parse_lints(value.split(','))?);
+                args = rest;
+            }
+            [] => break,
+            _ => return Err(anyhow!("invalid arguments for lint: {:?}", args)),
+        }
+    }
+
+    with_resolved_graph(&config, |root_pkgs, rpkgs_by_id| {
+        let ws_root = root_manifest_path.parent().unwrap();
+        let warnings = lint::lint(ws_root, &workspace_metadata, &root_pkgs, &rpkgs_by_id);
+        lint::report(&warnings, &allowed, io::stdout().lock())
+    })
+}
+
+fn diff_files(old: &str, new: &str) -> Result<()> {
+    let read = |path: &str| -> Result<diff::Plan> {
+        let file = fs::File::open(path).context(format!("Couldn't open file {}", path))?;
+        serde_json::from_reader(io::BufReader::new(file))
+            .context(format!("{} is not a build plan", path))
+    };
+
+    diff::write_changelog(&read(old)?, &read(new)?, io::stdout().lock())
+}
+
+/// Compares the plans at two git revisions, or at `old` and in the working tree. Revisions are
+/// exported into temporary directories, so the working tree is left alone.
+fn diff_revisions(old: &str, new: Option<&str>) -> Result<()> {
+    let cwd = std::env::current_dir().context("could not get the current directory")?;
+    let plan_at = |rev: Option<&str>| -> Result<diff::Plan> {
+        let export = rev.map(|rev| diff::Export::new(&cwd, rev)).transpose()?;
+        let config = match &export {
+            Some(export) => cargo_config_in(export.path())?,
+            None => cargo_config()?,
+        };
+        diff::Plan::from_build_plan(&build_plan(&config)?)
     };
 
+    diff::write_changelog(&plan_at(Some(old))?, &plan_at(new)?, io::stdout().lock())
+}
+
+fn graph(format: &str) -> Result<()> {
+    let config = cargo_config()?;
     let root_manifest_path = find_root_manifest_for_wd(config.cwd())?;
-    let ws = Workspace::new(&root_manifest_path, &config)?;
+    with_resolved_graph(&config, |root_pkgs, mut rpkgs_by_id| {
+        simplify_optionality(rpkgs_by_id.values_mut(), root_pkgs.len());
+        let ws_root = root_manifest_path.parent().unwrap();
+        let graph = graph::Graph::new(ws_root, &root_pkgs, &rpkgs_by_id);
+        match format {
+            "dot" => graph.write_dot(io::stdout().lock()),
+            "json" => graph.write_json(io::stdout().lock()),
+            other => Err(anyhow!(
+                "unknown graph format `{}`, expected dot or json",
+                other
+            )),
+        }
+    })
+}
+
+fn why(spec: &str, feature: Option<&str>) -> Result<()> {
+    let spec = PackageIdSpec::parse(spec)?;
+    with_resolved_graph(&cargo_config()?, |root_pkgs, rpkgs_by_id| {
+        why::why(
+            &root_pkgs,
+            &rpkgs_by_id,
+            &spec,
+            feature,
+            io::stdout().lock(),
+        )
+    })
+}
+
+fn licenses(policy: &str) -> Result<()> {
+    let policy = policy::Policy::load(Path::new(policy))?;
+    with_resolved_graph(&cargo_config()?, |root_pkgs, rpkgs_by_id| {
+        policy::check(&policy, &root_pkgs, &rpkgs_by_id, io::stdout().lock())
+    })
+}
+
+fn cargo_config() -> Result<cargo::Config> {
+    let mut config = cargo::Config::default()?;
+    config.configure(0, true, None, false, true, false, &None, &[], &[])?;
+    Ok(config)
+}
+
+/// Like `cargo_config`, but for a workspace in `cwd` rather than in the current directory.
+fn cargo_config_in(cwd: PathBuf) -> Result<cargo::Config> {
+    let homedir = cargo::util::config::homedir(&cwd)
+        .ok_or_else(|| anyhow!("could not find the cargo home directory"))?;
+    let mut config = cargo::Config::new(cargo::core::Shell::new(), cwd, homedir);
+    config.configure(0, true, None, false, true, false, &None, &[], &[])?;
+    Ok(config)
+}
+
+/// Resolves the workspace in the current directory into the plan that `Cargo.nix` is rendered from.
+fn build_plan(config: &cargo::Config) -> Result<BuildPlan> {
+    with_resolved_graph(config, |root_pkgs, mut rpkgs_by_id| {
+        simplify_optionality(rpkgs_by_id.values_mut(), root_pkgs.len());
+        warn_untranslatable_cfgs(&rpkgs_by_id);
+        let root_manifest_path = find_root_manifest_for_wd(config.cwd())?;
+        let env = std::env::vars().collect();
+        let (profiles, profile_sources) =
+            manifest::load_profiles(&root_manifest_path, config, &env)?;
+        let (workspace_metadata, _) = overrides::in_manifest(&root_manifest_path)?;
+
+        let mut plan = BuildPlan::from_items(
+            root_pkgs,
+            profiles,
+            rpkgs_by_id,
+            &workspace_metadata,
+            config.cwd(),
+        )?;
+        plan.profile_sources = profile_sources;
+        Ok(plan)
+    })
+}
+
+/// Resolves the workspace in the current directory and records which workspace members and root
+/// features require each dependency and feature, before any simplification.
+fn with_resolved_graph<T>(
+    config: &cargo::Config,
+    f: impl for<'a> FnOnce(Vec<&'a Package>, BTreeMap<PackageId, ResolvedPackage<'a>>) -> Result<T>,
+) -> Result<T> {
+    let root_manifest_path = find_root_manifest_for_wd(config.cwd())?;
+    let ws = Workspace::new(&root_manifest_path, config)?;
     let rtd = RustcTargetData::new(&ws, &[CompileKind::Host])?;
     let specs = Packages::All.to_package_id_specs(&ws)?;
     let resolve = resolve_ws_with_opts(
@@ -212,28 +699,51 @@ fn generate_cargo_nix(mut out: impl io::Write) -> Result<()> {
 
     let root_pkgs: Vec<_> = ws.members().collect();
     for pkg in root_pkgs.iter() {
-        let pkg_ws = Workspace::new(pkg.manifest_path(), &config)?;
+        let pkg_ws = Workspace::new(pkg.manifest_path(), config)?;
         mark_required(pkg, &pkg_ws, &mut rpkgs_by_id)?;
         for feature in all_features(&pkg) {
             activate(pkg, feature, &pkg_ws, &mut rpkgs_by_id)?;
         }
     }
 
-    simplify_optionality(rpkgs_by_id.values_mut(), root_pkgs.len());
-    let root_manifest = fs::read(&root_manifest_path)?;
-    let profiles = manifest::extract_profiles(&root_manifest);
+    f(root_pkgs, rpkgs_by_id)
+}
 
-    let plan = BuildPlan::from_items(root_pkgs, profiles, rpkgs_by_id, config.cwd())?;
-    let mut tera = Tera::default();
-    tera.add_raw_template(
-        "Cargo.nix.tera",
-        include_str!("../templates/Cargo.nix.tera"),
-    )?;
-    let context = tera::Context::from_serialize(plan)?;
-    let rendered = tera.render("Cargo.nix.tera", &context)?;
-    write!(out, "{}", rendered)?;
+fn warn_untranslatable_cfgs(rpkgs_by_id: &BTreeMap<PackageId, ResolvedPackage>) {
+    let mut users_by_cfg: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    let mut users_by_triple: BTreeMap<&str, BTreeSet<String>> = BTreeMap::new();
+    for (id, rpkg) in rpkgs_by_id.iter() {
+        let user = format!("{} {}", id.name(), id.version());
+        let platforms = rpkg.deps.values().filter_map(|d| d.platforms.as_ref());
+        for platform in platforms.flatten() {
+            for cfg in platform::untranslatable_cfgs(platform, "hostPlatform") {
+                users_by_cfg.entry(cfg).or_default().insert(user.clone());
+            }
+            if let Platform::Name(triple) = platform {
+                if !platform::is_known_triple(triple) {
+                    users_by_triple
+                        .entry(triple)
+                        .or_default()
+                        .insert(user.clone());
+                }
+            }
+        }
+    }
 
-    Ok(())
+    for (cfg, users) in users_by_cfg.iter() {
+        eprintln!(
+            "warning: cfg({}) cannot be translated to Nix and is assumed to be unset (used by {})",
+            cfg,
+            users.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
+    for (triple, users) in users_by_triple.iter() {
+        eprintln!(
+            "warning: target {} is unknown and only matches a Nix platform with the same config string (used by {})",
+            triple,
+            users.iter().cloned().collect::<Vec<_>>().join(", ")
+        );
+    }
 }
 
 fn simplify_optionality<'a, 'b: 'a>(
@@ -468,7 +978,7 @@ impl<'a> ResolvedPackage<'a> {
 }
 
 #[derive(Debug)]
-struct ResolvedDependency<'a> {
+pub struct ResolvedDependency<'a> {
     extern_name: String,
     pkg: &'a Package,
     optionality: Optionality<'a>,
@@ -523,22 +1033,22 @@ impl<'a> Optionality<'a> {
             Optionality::Optional {
                 activated_by_features,
                 required_by_pkgs,
-            } => {
-                BoolExpr::ors(
-                    activated_by_features
-                        .iter()
-                        .map(|root_feature| {
-                            Single(format!(
-                                "{} ? {:?}",
-                                root_features_var,
-                                display_root_feature(*root_feature)
-                            ))
-                        })
-                        .chain(required_by_pkgs.iter().map(|pkg_name| {
-                            Single(format!("{} ? {:?}", root_features_var, pkg_name))
-                        })),
-                )
-            }
+            } => BoolExpr::ors(
+                activated_by_features
+                    .iter()
+                    .map(|root_feature| {
+                        Single(Atom::HasAttr(
+                            root_features_var.to_string(),
+                            display_root_feature(*root_feature),
+                        ))
+                    })
+                    .chain(required_by_pkgs.iter().map(|pkg_name| {
+                        Single(Atom::HasAttr(
+                            root_features_var.to_string(),
+                            pkg_name.to_string(),
+                        ))
+                    })),
+            ),
         }
     }
 }
@@ -1,31 +1,668 @@
-use toml::value::Table;
+use toml::value::{Table, Value};
 
+use anyhow::{anyhow, Context, Result};
+use cargo::util::config::{ConfigValue, Definition};
+use cargo::Config;
 use serde::Deserialize;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
 
 pub type TomlProfile = BTreeMap<String, Table>;
 
-pub fn extract_profiles(manifest_contents: &[u8]) -> TomlProfile {
+/// Where each profile setting was defined, keyed by profile name and then by the dotted path of
+/// the setting within the profile (e.g. `build-override.opt-level`).
+pub type ProfileSources = BTreeMap<String, BTreeMap<String, Definition>>;
+
+// Profiles cargo always knows about, which may be configured without appearing in the manifest.
+const BUILTIN_PROFILES: &[&str] = &["dev", "release", "test", "bench"];
+
+// Profile settings that can be set through `CARGO_PROFILE_<NAME>_<KEY>` environment variables.
+const ENV_PROFILE_KEYS: &[&str] = &[
+    "opt-level",
+    "lto",
+    "codegen-units",
+    "debug",
+    "debug-assertions",
+    "rpath",
+    "panic",
+    "overflow-checks",
+    "incremental",
+    "strip",
+    "split-debuginfo",
+    "dir-name",
+    "inherits",
+];
+
+pub fn extract_profiles(manifest_contents: &[u8]) -> Result<TomlProfile> {
     #[derive(Debug, Deserialize)]
     struct Manifest {
         pub profile: Option<TomlProfile>,
     }
 
-    toml::from_slice::<Manifest>(manifest_contents)
-        .ok()
-        .and_then(|m| m.profile)
-        .map(|mut profiles_by_name| {
-            remove_panic(&mut profiles_by_name);
-            profiles_by_name
+    Ok(toml::from_slice::<Manifest>(manifest_contents)?
+        .profile
+        .unwrap_or_default())
+}
+
+/// Loads the profiles of the root manifest and layers `[profile]` tables from `.cargo/config`
+/// files and the `CARGO_PROFILE_*` variables of `env` on top, in the same order of precedence as
+/// cargo does.
+pub fn load_profiles(
+    manifest_path: &Path,
+    config: &Config,
+    env: &HashMap<String, String>,
+) -> Result<(TomlProfile, ProfileSources)> {
+    let manifest_contents = std::fs::read(manifest_path)
+        .context(format!("could not read {}", manifest_path.display()))?;
+    let mut profiles_by_name = extract_profiles(&manifest_contents)
+        .context(format!("could not parse {}", manifest_path.display()))?;
+    let mut sources = ProfileSources::new();
+
+    let manifest_definition = Definition::Path(manifest_path.to_path_buf());
+    for (name, profile) in profiles_by_name.iter() {
+        let profile_sources = sources.entry(name.clone()).or_default();
+        for path in setting_paths(profile, "") {
+            profile_sources.insert(path, manifest_definition.clone());
+        }
+    }
+
+    let manifest_names: BTreeSet<String> = profiles_by_name.keys().cloned().collect();
+    let config_profiles = match config.values()?.get("profile") {
+        Some(ConfigValue::Table(profiles, _)) => profiles.clone(),
+        Some(cv) => {
+            return Err(anyhow!(
+                "expected a table for `profile`, but found a {} in {}",
+                cv.desc(),
+                cv.definition()
+            ))
+        }
+        None => Default::default(),
+    };
+
+    let mut pending: Vec<String> = manifest_names
+        .iter()
+        .cloned()
+        .chain(config_profiles.keys().cloned())
+        .chain(BUILTIN_PROFILES.iter().map(|name| name.to_string()))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let mut seen: BTreeSet<String> = pending.iter().cloned().collect();
+
+    while let Some(name) = pending.pop() {
+        let profile = profiles_by_name.entry(name.clone()).or_default();
+        let profile_sources = sources.entry(name.clone()).or_default();
+
+        match config_profiles.get(&name) {
+            Some(ConfigValue::Table(table, _)) => {
+                merge_config_table(profile, table, "", profile_sources)
+                    .context(format!("invalid config for profile `{}`", name))?
+            }
+            Some(cv) => {
+                return Err(anyhow!(
+                    "expected a table for `profile.{}`, but found a {} in {}",
+                    name,
+                    cv.desc(),
+                    cv.definition()
+                ))
+            }
+            None => {}
+        }
+
+        merge_env(profile, env, &env_prefix(&name), "", profile_sources);
+        if let Some(build_override) = env_build_override(&name, env, profile_sources) {
+            match profile
+                .entry("build-override".to_string())
+                .or_insert_with(|| Value::Table(Table::new()))
+            {
+                Value::Table(table) => table.extend(build_override),
+                _ => return Err(anyhow!("`profile.{}.build-override` must be a table", name)),
+            }
+        }
+
+        if let Some(inherits) = profile.get("inherits").and_then(Value::as_str) {
+            if seen.insert(inherits.to_string()) {
+                pending.push(inherits.to_string());
+            }
+        }
+    }
+
+    // Built-in profiles only end up in the output if something actually configures them.
+    let unconfigured: Vec<String> = profiles_by_name
+        .iter()
+        .filter(|(name, profile)| !manifest_names.contains(*name) && profile.is_empty())
+        .map(|(name, _)| name.clone())
+        .collect();
+    for name in unconfigured {
+        profiles_by_name.remove(&name);
+        sources.remove(&name);
+    }
+
+    validate_profiles(&profiles_by_name, &sources)?;
+    remove_panic(&mut profiles_by_name, &mut sources);
+    Ok((profiles_by_name, sources))
+}
+
+// Collects the dotted paths of all leaf settings in a profile table.
+fn setting_paths(table: &Table, prefix: &str) -> Vec<String> {
+    table
+        .iter()
+        .flat_map(|(key, value)| match value {
+            Value::Table(inner) => setting_paths(inner, &format!("{}{}.", prefix, key)),
+            _ => vec![format!("{}{}", prefix, key)],
         })
-        .unwrap_or_default()
+        .collect()
+}
+
+fn merge_config_table(
+    table: &mut Table,
+    config_table: &HashMap<String, ConfigValue>,
+    prefix: &str,
+    sources: &mut BTreeMap<String, Definition>,
+) -> Result<()> {
+    for (key, cv) in config_table {
+        let path = format!("{}{}", prefix, key);
+        match cv {
+            ConfigValue::Table(inner, _) => {
+                let entry = table
+                    .entry(key.clone())
+                    .or_insert_with(|| Value::Table(Table::new()));
+                match entry {
+                    Value::Table(entry) => {
+                        merge_config_table(entry, inner, &format!("{}.", path), sources)?
+                    }
+                    _ => {
+                        return Err(anyhow!(
+                            "`{}` is a table in {}, but not in the manifest",
+                            path,
+                            cv.definition()
+                        ))
+                    }
+                }
+            }
+            cv => {
+                table.insert(key.clone(), config_value_to_toml(cv));
+                sources.insert(path, cv.definition().clone());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn config_value_to_toml(cv: &ConfigValue) -> Value {
+    match cv {
+        ConfigValue::Integer(i, _) => Value::Integer(*i),
+        ConfigValue::String(s, _) => Value::String(s.clone()),
+        ConfigValue::Boolean(b, _) => Value::Boolean(*b),
+        ConfigValue::List(items, _) => Value::Array(
+            items
+                .iter()
+                .map(|(item, _)| Value::String(item.clone()))
+                .collect(),
+        ),
+        ConfigValue::Table(table, _) => Value::Table(
+            table
+                .iter()
+                .map(|(k, v)| (k.clone(), config_value_to_toml(v)))
+                .collect(),
+        ),
+    }
+}
+
+fn env_prefix(profile_name: &str) -> String {
+    format!(
+        "CARGO_PROFILE_{}_",
+        profile_name.to_uppercase().replace('-', "_")
+    )
+}
+
+fn env_key(prefix: &str, key: &str) -> String {
+    format!("{}{}", prefix, key.to_uppercase().replace('-', "_"))
+}
+
+fn merge_env(
+    table: &mut Table,
+    env: &HashMap<String, String>,
+    prefix: &str,
+    path_prefix: &str,
+    sources: &mut BTreeMap<String, Definition>,
+) {
+    for key in ENV_PROFILE_KEYS {
+        let env_key = env_key(prefix, key);
+        if let Some(value) = env.get(&env_key) {
+            table.insert(key.to_string(), env_value_to_toml(value));
+            sources.insert(
+                format!("{}{}", path_prefix, key),
+                Definition::Environment(env_key),
+            );
+        }
+    }
+}
+
+fn env_build_override(
+    profile_name: &str,
+    env: &HashMap<String, String>,
+    sources: &mut BTreeMap<String, Definition>,
+) -> Option<Table> {
+    let mut table = Table::new();
+    let prefix = env_key(&env_prefix(profile_name), "build-override_");
+    merge_env(&mut table, env, &prefix, "build-override.", sources);
+    if table.is_empty() {
+        None
+    } else {
+        Some(table)
+    }
+}
+
+// Interprets an environment variable the way cargo's config deserializer does.
+fn env_value_to_toml(value: &str) -> Value {
+    if let Ok(b) = value.parse::<bool>() {
+        Value::Boolean(b)
+    } else if let Ok(i) = value.parse::<i64>() {
+        Value::Integer(i)
+    } else {
+        Value::String(value.to_string())
+    }
+}
+
+/// The kind of value a profile setting accepts, mirroring the schema of cargo's `TomlProfile`.
+#[derive(Clone, Copy)]
+enum SettingKind {
+    OptLevel,
+    Lto,
+    CodegenUnits,
+    Debug,
+    Bool,
+    Panic,
+    Strip,
+    SplitDebuginfo,
+    Name,
+    Override,
+    Packages,
+}
+
+const PROFILE_SETTINGS: &[(&str, SettingKind)] = &[
+    ("opt-level", SettingKind::OptLevel),
+    ("lto", SettingKind::Lto),
+    ("codegen-units", SettingKind::CodegenUnits),
+    ("debug", SettingKind::Debug),
+    ("debug-assertions", SettingKind::Bool),
+    ("rpath", SettingKind::Bool),
+    ("panic", SettingKind::Panic),
+    ("overflow-checks", SettingKind::Bool),
+    ("incremental", SettingKind::Bool),
+    ("strip", SettingKind::Strip),
+    ("split-debuginfo", SettingKind::SplitDebuginfo),
+    ("dir-name", SettingKind::Name),
+    ("inherits", SettingKind::Name),
+    ("build-override", SettingKind::Override),
+    ("package", SettingKind::Packages),
+];
+
+// Settings which cargo rejects inside `build-override` and `package.<spec>` tables.
+const OVERRIDE_FORBIDDEN: &[&str] = &[
+    "panic",
+    "lto",
+    "rpath",
+    "dir-name",
+    "inherits",
+    "build-override",
+    "package",
+];
+
+/// Checks every profile against the settings cargo understands, so that a typo or an invalid value
+/// is reported here, together with where it was defined, rather than deep inside a Nix build.
+fn validate_profiles(profiles_by_name: &TomlProfile, sources: &ProfileSources) -> Result<()> {
+    let mut errors = Vec::new();
+    for (name, profile) in profiles_by_name.iter() {
+        let no_sources = BTreeMap::new();
+        let profile_sources = sources.get(name).unwrap_or(&no_sources);
+        validate_table(name, profile, "", false, profile_sources, &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "invalid profile settings:\n{}",
+            errors
+                .iter()
+                .map(|e| format!("  {}", e))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ))
+    }
+}
+
+fn validate_table(
+    profile_name: &str,
+    table: &Table,
+    prefix: &str,
+    is_override: bool,
+    sources: &BTreeMap<String, Definition>,
+    errors: &mut Vec<String>,
+) {
+    for (key, value) in table.iter() {
+        let path = format!("{}{}", prefix, key);
+        let location = || {
+            let definition = sources
+                .iter()
+                .find(|(p, _)| *p == &path || p.starts_with(&format!("{}.", path)))
+                .map(|(_, d)| format!(" (defined in {})", d))
+                .unwrap_or_default();
+            format!("`profile.{}.{}`{}", profile_name, path, definition)
+        };
+
+        let kind = match PROFILE_SETTINGS.iter().find(|(k, _)| k == key) {
+            Some((_, kind)) => *kind,
+            None => {
+                let suggestion =
+                    cargo::util::closest(key, PROFILE_SETTINGS.iter().map(|(k, _)| k), |k| k)
+                        .map(|k| format!(", did you mean `{}`?", k))
+                        .unwrap_or_default();
+                errors.push(format!("unknown setting {}{}", location(), suggestion));
+                continue;
+            }
+        };
+
+        if is_override && OVERRIDE_FORBIDDEN.contains(&key.as_str()) {
+            errors.push(format!(
+                "{} is not allowed in `build-override` or `package` profile overrides",
+                location()
+            ));
+            continue;
+        }
+
+        if let Err(expected) = validate_value(kind, value) {
+            errors.push(format!(
+                "invalid value {} for {}: expected {}",
+                value,
+                location(),
+                expected
+            ));
+            continue;
+        }
+
+        match (kind, value) {
+            (SettingKind::Override, Value::Table(inner)) => validate_table(
+                profile_name,
+                inner,
+                &format!("{}.", path),
+                true,
+                sources,
+                errors,
+            ),
+            (SettingKind::Packages, Value::Table(packages)) => {
+                for (spec, inner) in packages.iter() {
+                    let spec_path = format!("{}.{}", path, spec);
+                    match inner {
+                        Value::Table(inner) => validate_table(
+                            profile_name,
+                            inner,
+                            &format!("{}.", spec_path),
+                            true,
+                            sources,
+                            errors,
+                        ),
+                        other => errors.push(format!(
+                            "invalid value {} for `profile.{}.{}`: expected a table",
+                            other, profile_name, spec_path
+                        )),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn validate_value(kind: SettingKind, value: &Value) -> std::result::Result<(), &'static str> {
+    let valid = match (kind, value) {
+        (SettingKind::OptLevel, Value::Integer(i)) => (0..=3).contains(i),
+        (SettingKind::OptLevel, Value::String(s)) => s == "s" || s == "z",
+        (SettingKind::Lto, Value::Boolean(_)) => true,
+        (SettingKind::Lto, Value::String(s)) => ["fat", "thin", "off"].contains(&s.as_str()),
+        (SettingKind::CodegenUnits, Value::Integer(i)) => *i > 0 && *i <= i64::from(u32::MAX),
+        (SettingKind::Debug, Value::Boolean(_)) => true,
+        (SettingKind::Debug, Value::Integer(i)) => (0..=2).contains(i),
+        (SettingKind::Bool, Value::Boolean(_)) => true,
+        (SettingKind::Panic, Value::String(s)) => s == "unwind" || s == "abort",
+        (SettingKind::Strip, Value::Boolean(_)) => true,
+        (SettingKind::Strip, Value::String(s)) => {
+            ["none", "debuginfo", "symbols"].contains(&s.as_str())
+        }
+        (SettingKind::SplitDebuginfo, Value::String(s)) => {
+            ["off", "packed", "unpacked"].contains(&s.as_str())
+        }
+        (SettingKind::Name, Value::String(s)) => {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+        }
+        (SettingKind::Override, Value::Table(_)) | (SettingKind::Packages, Value::Table(_)) => true,
+        _ => false,
+    };
+
+    if valid {
+        return Ok(());
+    }
+
+    Err(match kind {
+        SettingKind::OptLevel => "an integer from 0 to 3, \"s\" or \"z\"",
+        SettingKind::Lto => "a boolean, \"fat\", \"thin\" or \"off\"",
+        SettingKind::CodegenUnits => "a positive integer",
+        SettingKind::Debug => "a boolean or an integer from 0 to 2",
+        SettingKind::Bool => "a boolean",
+        SettingKind::Panic => "\"unwind\" or \"abort\"",
+        SettingKind::Strip => "a boolean, \"none\", \"debuginfo\" or \"symbols\"",
+        SettingKind::SplitDebuginfo => "\"off\", \"packed\" or \"unpacked\"",
+        SettingKind::Name => "a non-empty name of alphanumerics, `-` or `_`",
+        SettingKind::Override | SettingKind::Packages => "a table",
+    })
 }
 
 // Remove the `panic` key from `test` and `bench` profiles, which is ignored by `cargo`.
-fn remove_panic(profiles_by_name: &mut TomlProfile) {
+fn remove_panic(profiles_by_name: &mut TomlProfile, sources: &mut ProfileSources) {
     for (name, profile) in profiles_by_name.iter_mut() {
         if name == "test" || name == "bench" {
             profile.remove("panic");
+            if let Some(profile_sources) = sources.get_mut(name) {
+                profile_sources.remove("panic");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cargo::core::Shell;
+    use std::fs;
+
+    /// Loads the profiles of a workspace with the given manifest, `.cargo/config.toml` and
+    /// environment variables.
+    fn load(
+        manifest: &str,
+        config: &str,
+        env: &[(&str, &str)],
+    ) -> Result<(TomlProfile, ProfileSources)> {
+        let dir = tempfile::tempdir()?;
+        let manifest_path = dir.path().join("Cargo.toml");
+        fs::write(&manifest_path, manifest)?;
+        fs::create_dir(dir.path().join(".cargo"))?;
+        fs::write(dir.path().join(".cargo/config.toml"), config)?;
+        let config = Config::new(
+            Shell::new(),
+            dir.path().to_path_buf(),
+            dir.path().join("cargo-home"),
+        );
+        let env = env
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        load_profiles(&manifest_path, &config, &env)
+    }
+
+    #[test]
+    fn later_layers_take_precedence() {
+        let (profiles, sources) = load(
+            r#"
+[profile.layered]
+inherits = "release"
+opt-level = 1
+debug = true
+lto = true
+"#,
+            r#"
+[profile.layered]
+opt-level = 2
+debug = 1
+"#,
+            &[("CARGO_PROFILE_LAYERED_OPT_LEVEL", "3")],
+        )
+        .unwrap();
+
+        let layered = &profiles["layered"];
+        assert_eq!(layered["opt-level"], Value::Integer(3));
+        assert_eq!(layered["debug"], Value::Integer(1));
+        assert_eq!(layered["lto"], Value::Boolean(true));
+
+        let sources = &sources["layered"];
+        assert!(
+            matches!(&sources["opt-level"], Definition::Environment(var) if var == "CARGO_PROFILE_LAYERED_OPT_LEVEL")
+        );
+        assert!(
+            matches!(&sources["debug"], Definition::Path(path) if path.ends_with(".cargo/config.toml"))
+        );
+        assert!(matches!(&sources["lto"], Definition::Path(path) if path.ends_with("Cargo.toml")));
+    }
+
+    #[test]
+    fn inherited_profiles_are_loaded() {
+        let (profiles, _) = load(
+            r#"
+[profile.chain-top]
+inherits = "chain-middle"
+"#,
+            r#"
+[profile.chain-middle]
+inherits = "chain-base"
+codegen-units = 1
+"#,
+            &[("CARGO_PROFILE_CHAIN_BASE_OPT_LEVEL", "1")],
+        )
+        .unwrap();
+
+        assert_eq!(profiles["chain-middle"]["codegen-units"], Value::Integer(1));
+        assert_eq!(profiles["chain-base"]["opt-level"], Value::Integer(1));
+        assert!(!profiles.contains_key("dev"));
+    }
+
+    /// Validates the profiles of a manifest, with every setting defined in `Cargo.toml`.
+    fn validate(manifest: &str) -> Result<()> {
+        let profiles = extract_profiles(manifest.as_bytes()).unwrap();
+        let definition = Definition::Path("Cargo.toml".into());
+        let sources = profiles
+            .iter()
+            .map(|(name, profile)| {
+                let paths = setting_paths(profile, "")
+                    .into_iter()
+                    .map(|path| (path, definition.clone()))
+                    .collect();
+                (name.clone(), paths)
+            })
+            .collect();
+        validate_profiles(&profiles, &sources)
+    }
+
+    fn error(manifest: &str) -> String {
+        format!("{}", validate(manifest).unwrap_err())
+    }
+
+    #[test]
+    fn valid_settings_pass() {
+        validate(
+            r#"
+[profile.release]
+opt-level = "z"
+lto = "thin"
+codegen-units = 1
+debug = 1
+strip = "symbols"
+split-debuginfo = "packed"
+panic = "abort"
+
+[profile.release.build-override]
+opt-level = 0
+
+[profile.release.package.foo]
+debug-assertions = true
+"#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn unknown_settings_are_reported() {
+        assert_eq!(
+            error("[profile.release]\nopt-levle = 3"),
+            "invalid profile settings:\n  unknown setting `profile.release.opt-levle` \
+             (defined in Cargo.toml), did you mean `opt-level`?"
+        );
+    }
+
+    #[test]
+    fn override_settings_are_checked() {
+        assert_eq!(
+            error("[profile.dev.build-override]\npanic = \"abort\""),
+            "invalid profile settings:\n  `profile.dev.build-override.panic` \
+             (defined in Cargo.toml) is not allowed in `build-override` or `package` profile \
+             overrides"
+        );
+        assert_eq!(
+            error("[profile.dev.package]\nfoo = 1"),
+            "invalid profile settings:\n  invalid value 1 for `profile.dev.package.foo`: \
+             expected a table"
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_reported() {
+        let cases = &[
+            ("opt-level = 4", "an integer from 0 to 3, \"s\" or \"z\""),
+            ("lto = \"full\"", "a boolean, \"fat\", \"thin\" or \"off\""),
+            ("codegen-units = 0", "a positive integer"),
+            ("debug = 3", "a boolean or an integer from 0 to 2"),
+            ("rpath = 1", "a boolean"),
+            ("panic = \"crash\"", "\"unwind\" or \"abort\""),
+            (
+                "strip = \"all\"",
+                "a boolean, \"none\", \"debuginfo\" or \"symbols\"",
+            ),
+            (
+                "split-debuginfo = true",
+                "\"off\", \"packed\" or \"unpacked\"",
+            ),
+            (
+                "inherits = \"\"",
+                "a non-empty name of alphanumerics, `-` or `_`",
+            ),
+            ("build-override = 1", "a table"),
+        ];
+        for (setting, expected) in cases.iter() {
+            let (key, value) = {
+                let mut parts = setting.splitn(2, " = ");
+                (parts.next().unwrap(), parts.next().unwrap())
+            };
+            assert_eq!(
+                error(&format!("[profile.dev]\n{}", setting)),
+                format!(
+                    "invalid profile settings:\n  invalid value {} for `profile.dev.{}` \
+                     (defined in Cargo.toml): expected {}",
+                    value, key, expected
+                )
+            );
         }
     }
 }
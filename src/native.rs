@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use crate::template::{BuildPlan, Crate};
+
+/// Values of `links` of common `-sys` crates and the nixpkgs attributes providing the native
+/// library they link. Attribute names are those of the nixpkgs pinned in `default.nix`.
+const NIX_PACKAGES: &[(&str, &str)] = &[
+    ("alsa", "alsaLib"),
+    ("archive", "libarchive"),
+    ("bzip2", "bzip2"),
+    ("cairo", "cairo"),
+    ("curl", "curl"),
+    ("dbus", "dbus"),
+    ("expat", "expat"),
+    ("ffi", "libffi"),
+    ("fontconfig", "fontconfig"),
+    ("freetype", "freetype"),
+    ("gdk_pixbuf-2.0", "gdk-pixbuf"),
+    ("gio-2.0", "glib"),
+    ("git2", "libgit2"),
+    ("glib-2.0", "glib"),
+    ("gobject-2.0", "glib"),
+    ("gpg-error", "libgpgerror"),
+    ("gpgme", "gpgme"),
+    ("gtk-3", "gtk3"),
+    ("hidapi", "hidapi"),
+    ("lz4", "lz4"),
+    ("lzma", "xz"),
+    ("mysqlclient", "libmysqlclient"),
+    ("nghttp2", "nghttp2"),
+    ("onig", "oniguruma"),
+    ("openssl", "openssl"),
+    ("pango", "pango"),
+    ("pcap", "libpcap"),
+    ("png", "libpng"),
+    ("pq", "postgresql"),
+    ("pulse", "libpulseaudio"),
+    ("rdkafka", "rdkafka"),
+    ("rocksdb", "rocksdb"),
+    ("seccomp", "libseccomp"),
+    ("snappy", "snappy"),
+    ("sodium", "libsodium"),
+    ("sqlite3", "sqlite"),
+    ("ssh2", "libssh2"),
+    ("usb-1.0", "libusb1"),
+    ("uv", "libuv"),
+    ("xcb", "xorg.libxcb"),
+    ("xkbcommon", "libxkbcommon"),
+    ("xml2", "libxml2"),
+    ("z", "zlib"),
+    ("zmq", "zeromq"),
+    ("zstd", "zstd"),
+];
+
+/// Build dependencies of `-sys` crates that run tools which have to be among the native build
+/// inputs, and the nixpkgs attributes providing them.
+const BUILD_TOOLS: &[(&str, &str)] = &[("cmake", "cmake"), ("pkg-config", "pkgconfig")];
+
+/// The crates that `overlay/overrides.nix` has an override for.
+pub fn overridden_crates() -> BTreeSet<&'static str> {
+    let mut crates = BTreeSet::new();
+    let mut in_override = false;
+    for line in include_str!("../overlay/overrides.nix").lines() {
+        if line.contains("makeOverride {") {
+            in_override = true;
+        } else if in_override {
+            let name = line
+                .trim()
+                .strip_prefix("name = \"")
+                .and_then(|rest| rest.strip_suffix("\";"));
+            if let Some(name) = name {
+                crates.insert(name);
+                in_override = false;
+            }
+        }
+    }
+    crates
+}
+
+/// What a crate linking a native library likely needs from nixpkgs.
+pub struct Hint<'a> {
+    pub krate: &'a Crate,
+    pub links: &'a str,
+    /// The nixpkgs attribute of the library, if it is a known one.
+    pub library: Option<&'static str>,
+    /// The nixpkgs attributes of the tools its build script runs.
+    pub tools: Vec<&'static str>,
+    /// Whether its build script compiles C code, possibly a bundled copy of the library.
+    pub uses_cc: bool,
+}
+
+/// Hints for the crates with `links` that are not workspace members, have none of the bundled
+/// overrides and get no build inputs from `[workspace.metadata.cargo2nix]`.
+pub fn hints(plan: &BuildPlan) -> Vec<Hint<'_>> {
+    let overridden = overridden_crates();
+    plan.crates
+        .iter()
+        .filter(|krate| {
+            let is_member = plan
+                .workspace_members
+                .iter()
+                .any(|m| m.name == krate.name && m.version == krate.version);
+            !is_member
+                && !overridden.contains(krate.name.as_str())
+                && krate.overrides.build_inputs.is_empty()
+        })
+        .filter_map(|krate| {
+            let links = krate.links.as_deref()?;
+            let build_deps = || krate.build_dependencies.iter().map(|d| d.name.as_str());
+            Some(Hint {
+                krate,
+                links,
+                library: NIX_PACKAGES
+                    .iter()
+                    .find(|(l, _)| *l == links)
+                    .map(|(_, attr)| *attr),
+                tools: BUILD_TOOLS
+                    .iter()
+                    .filter(|(name, _)| build_deps().any(|d| d == *name))
+                    .map(|(_, attr)| *attr)
+                    .collect(),
+                uses_cc: build_deps().any(|d| d == "cc"),
+            })
+        })
+        .collect()
+}
+
+impl Hint<'_> {
+    /// A `makeOverride` entry for `packageOverrides` adding the library and tools to the inputs of
+    /// the crate.
+    pub fn to_override(&self) -> String {
+        let mut lines = vec![
+            "(pkgs.rustBuilder.rustLib.makeOverride {".to_string(),
+            format!("  name = \"{}\";", self.krate.name),
+            "  overrideAttrs = drv: {".to_string(),
+            "    propagatedBuildInputs = drv.propagatedBuildInputs or [ ] ++ [".to_string(),
+        ];
+        match self.library {
+            Some(attr) => lines.push(format!("      pkgs.{}", attr)),
+            None => lines.push(format!(
+                "      # the nixpkgs package providing the native library `{}`",
+                self.links
+            )),
+        }
+        if self.uses_cc {
+            lines.push(
+                "      # unless the build script compiles a bundled copy of it with cc".to_string(),
+            );
+        }
+        lines.push("    ];".to_string());
+        if !self.tools.is_empty() {
+            let tools: Vec<_> = self.tools.iter().map(|t| format!("pkgs.{}", t)).collect();
+            lines.push(format!(
+                "    nativeBuildInputs = drv.nativeBuildInputs or [ ] ++ [ {} ];",
+                tools.join(" ")
+            ));
+        }
+        lines.push("  };".to_string());
+        lines.push("})".to_string());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_are_found() {
+        let overridden = overridden_crates();
+        for name in ["openssl-sys", "libgit2-sys", "libsqlite3-sys", "pq-sys"].iter() {
+            assert!(overridden.contains(name), "{} is not found", name);
+        }
+        assert!(!overridden.contains("RUSTFLAGS"));
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use cargo::core::Package;
+use serde::{Deserialize, Serialize};
+
+/// The keys of the tables, to warn about the others rather than fail on them.
+const KEYS: &[&str] = &[
+    "allow-lints",
+    "native-build-inputs",
+    "build-inputs",
+    "env",
+    "rustc-flags",
+    "patches",
+    "crates",
+    "trusted-dependencies",
+];
+
+/// A `[package.metadata.cargo2nix]` or `[workspace.metadata.cargo2nix]` table.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct Metadata {
+    /// The lints `cargo2nix lint` does not warn about, by code or name.
+    pub allow_lints: Vec<String>,
+    /// Attribute paths into the nixpkgs of the build platform, such as `cmake`.
+    native_build_inputs: Vec<String>,
+    /// Attribute paths into the nixpkgs of the host platform, such as `openssl`.
+    build_inputs: Vec<String>,
+    env: BTreeMap<String, String>,
+    rustc_flags: Vec<String>,
+    /// Relative to the manifest declaring them.
+    patches: Vec<PathBuf>,
+    /// The settings for crates of the dependency graph by name, only read from the workspace
+    /// table.
+    crates: BTreeMap<String, Metadata>,
+    /// The dependencies whose own package table is read, only read from the workspace table.
+    /// Those of other dependencies are ignored, since they change how the crate is built.
+    trusted_dependencies: Vec<String>,
+}
+
+/// The settings `Cargo.nix` passes to `mkRustCrate` for a crate, gathered from the workspace
+/// table and the crate's own package table.
+#[derive(Debug, Default, Serialize)]
+pub struct Overrides {
+    pub native_build_inputs: Vec<String>,
+    pub build_inputs: Vec<String>,
+    pub env: BTreeMap<String, String>,
+    pub rustc_flags: Vec<String>,
+    pub patches: Vec<Patch>,
+}
+
+#[derive(Debug, Serialize)]
+pub enum Patch {
+    /// Relative to the workspace root, which `Cargo.nix` refers to as `workspaceSrc`.
+    Workspace(PathBuf),
+    /// Relative to the root of the crate's unpacked sources.
+    Crate(PathBuf),
+}
+
+impl Metadata {
+    /// Reads the `cargo2nix` table of a `metadata` table, if there is one, warning about unknown
+    /// keys. `table` describes where the table is for the warnings.
+    fn from_metadata(metadata: Option<&toml::Value>, table: &str) -> Result<Self> {
+        match metadata.and_then(|m| m.get("cargo2nix")) {
+            Some(value) => {
+                warn_about_unknown_keys(value, table);
+                Ok(value.clone().try_into()?)
+            }
+            None => Ok(Metadata::default()),
+        }
+    }
+
+    /// The table for a crate of the dependency graph in the workspace table.
+    pub fn for_crate(&self, name: &str) -> Option<&Metadata> {
+        self.crates.get(name)
+    }
+
+    /// Whether the table adds native libraries to the build inputs.
+    pub fn has_build_inputs(&self) -> bool {
+        !self.build_inputs.is_empty()
+    }
+}
+
+fn warn_about_unknown_keys(value: &toml::Value, table: &str) {
+    let entries = value.as_table().into_iter().flatten();
+    for (key, value) in entries {
+        if !KEYS.contains(&key.as_str()) {
+            eprintln!("warning: unknown key `{}` in {} is ignored", key, table);
+        } else if key == "crates" {
+            for (name, value) in value.as_table().into_iter().flatten() {
+                warn_about_unknown_keys(value, &format!("{}.crates.{}", table, name));
+            }
+        }
+    }
+}
+
+/// Reads the `[workspace.metadata.cargo2nix]` and `[package.metadata.cargo2nix]` tables of a
+/// manifest, in that order.
+pub fn in_manifest(manifest_path: &Path) -> Result<(Metadata, Metadata)> {
+    let manifest = fs::read_to_string(manifest_path)
+        .context(format!("could not read {}", manifest_path.display()))?;
+    let manifest: toml::Value = toml::from_str(&manifest)
+        .context(format!("could not parse {}", manifest_path.display()))?;
+
+    let table = |table: &str| {
+        let metadata = manifest.get(table).and_then(|t| t.get("metadata"));
+        let table = format!(
+            "{}.metadata.cargo2nix in {}",
+            table,
+            manifest_path.display()
+        );
+        Metadata::from_metadata(metadata, &table).context(format!("invalid {}", table))
+    };
+    let workspace = table("workspace")?;
+    let package = table("package")?;
+    let workspace_only = match package.crates.keys().next() {
+        Some(name) => Some(format!("crates.{}", name)),
+        None if !package.trusted_dependencies.is_empty() => {
+            Some("trusted-dependencies".to_string())
+        }
+        None => None,
+    };
+    if let Some(key) = workspace_only {
+        return Err(anyhow!(
+            "package.metadata.cargo2nix.{} in {} is only read from [workspace.metadata.cargo2nix]",
+            key,
+            manifest_path.display()
+        ));
+    }
+    Ok((workspace, package))
+}
+
+/// Reads the `[package.metadata.cargo2nix]` table of a crate of the dependency graph. Only the
+/// tables of local crates and of the dependencies in `trusted-dependencies` of `workspace` are
+/// read, and a broken one of a dependency is warned about and ignored rather than failing.
+pub fn of_package(pkg: &Package, workspace: &Metadata) -> Result<Metadata> {
+    let is_local = pkg.package_id().source_id().is_path();
+    let is_trusted = workspace
+        .trusted_dependencies
+        .iter()
+        .any(|name| name == pkg.name().as_str());
+    if !is_local && !is_trusted {
+        return Ok(Metadata::default());
+    }
+
+    let table = format!(
+        "package.metadata.cargo2nix in {}",
+        pkg.manifest_path().display()
+    );
+    match Metadata::from_metadata(pkg.manifest().custom_metadata(), &table) {
+        Ok(metadata) => Ok(metadata),
+        Err(err) if is_local => Err(err.context(format!("invalid {}", table))),
+        Err(err) => {
+            eprintln!("warning: invalid {} is ignored: {}", table, err);
+            Ok(Metadata::default())
+        }
+    }
+}
+
+/// The overrides of a crate: those of the workspace table for workspace members, then those of
+/// the workspace table for the crate by name, then those of its own package table.
+///
+/// `local_path` is the path of a local crate relative to the workspace root, so that its patches
+/// are taken from the workspace rather than from its sources, which only keep the files needed to
+/// build it.
+pub fn overrides(
+    workspace: &Metadata,
+    pkg: &Package,
+    is_member: bool,
+    local_path: Option<&Path>,
+) -> Result<Overrides> {
+    let mut overrides = Overrides::default();
+    if is_member {
+        overrides.add(workspace, |p| Patch::Workspace(p.to_path_buf()));
+    }
+    if let Some(metadata) = workspace.for_crate(pkg.name().as_str()) {
+        overrides.add(metadata, |p| Patch::Workspace(p.to_path_buf()));
+    }
+    let package = of_package(pkg, workspace)?;
+    overrides.add(&package, |p| match local_path {
+        Some(dir) if dir == Path::new(".") => Patch::Workspace(p.to_path_buf()),
+        Some(dir) => Patch::Workspace(dir.join(p)),
+        None => Patch::Crate(p.to_path_buf()),
+    });
+    Ok(overrides)
+}
+
+impl Overrides {
+    /// Adds the settings of a table, with later tables taking precedence for environment
+    /// variables.
+    fn add(&mut self, metadata: &Metadata, patch: impl Fn(&Path) -> Patch) {
+        self.native_build_inputs
+            .extend(metadata.native_build_inputs.iter().cloned());
+        self.build_inputs
+            .extend(metadata.build_inputs.iter().cloned());
+        self.env.extend(metadata.env.clone());
+        self.rustc_flags
+            .extend(metadata.rustc_flags.iter().cloned());
+        self.patches
+            .extend(metadata.patches.iter().map(|p| patch(p)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tables_parse() {
+        let manifest: toml::Value = toml::from_str(
+            r#"
+[cargo2nix]
+native-build-inputs = ["pkg-config"]
+env = { FOO_NO_VENDOR = "1" }
+patches = ["nix/fix.patch"]
+
+[cargo2nix.crates.openssl-sys]
+build-inputs = ["openssl"]
+"#,
+        )
+        .unwrap();
+        let metadata = Metadata::from_metadata(Some(&manifest), "the table").unwrap();
+
+        assert_eq!(metadata.native_build_inputs, ["pkg-config"]);
+        assert_eq!(metadata.env["FOO_NO_VENDOR"], "1");
+        assert_eq!(metadata.patches, [PathBuf::from("nix/fix.patch")]);
+        assert!(metadata.crates["openssl-sys"].has_build_inputs());
+
+        let unknown: toml::Value = toml::from_str("[cargo2nix]\nbuildInputs = []").unwrap();
+        assert!(Metadata::from_metadata(Some(&unknown), "the table").is_ok());
+        let invalid: toml::Value = toml::from_str("[cargo2nix]\nenv = []").unwrap();
+        assert!(Metadata::from_metadata(Some(&invalid), "the table").is_err());
+        assert!(Metadata::from_metadata(None, "the table").is_ok());
+    }
+}
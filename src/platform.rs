@@ -1,61 +1,633 @@
-use cargo_platform::{CfgExpr, Platform};
+use std::collections::BTreeSet;
 
-use crate::expr::BoolExpr;
+use cargo_platform::{Cfg, CfgExpr, Platform};
+
+use crate::expr::{Atom, BoolExpr};
+
+/// Rust target triples and the `target_arch`, `target_os`, `target_env` and `target_abi` they
+/// stand for. The arch is kept as spelled in the triple, since it can be more specific than
+/// `target_arch` (e.g. `armv7` and `thumbv7em` are both `arm`).
+///
+/// Nix names most platforms differently (`arm64-apple-darwin`, `x86_64-w64-mingw32`,
+/// `wasm32-unknown-none`, ...), so triples are matched through these components rather than by
+/// comparing `hostPlatform.config`.
+const TRIPLES: &[(&str, &str, &str, &str, &str)] = &[
+    ("x86_64-unknown-linux-gnu", "x86_64", "linux", "gnu", ""),
+    (
+        "x86_64-unknown-linux-gnux32",
+        "x86_64",
+        "linux",
+        "gnu",
+        "x32",
+    ),
+    ("x86_64-unknown-linux-musl", "x86_64", "linux", "musl", ""),
+    ("i686-unknown-linux-gnu", "i686", "linux", "gnu", ""),
+    ("i686-unknown-linux-musl", "i686", "linux", "musl", ""),
+    ("i586-unknown-linux-gnu", "i586", "linux", "gnu", ""),
+    ("aarch64-unknown-linux-gnu", "aarch64", "linux", "gnu", ""),
+    ("aarch64-unknown-linux-musl", "aarch64", "linux", "musl", ""),
+    ("arm-unknown-linux-gnueabi", "arm", "linux", "gnu", "eabi"),
+    (
+        "arm-unknown-linux-gnueabihf",
+        "arm",
+        "linux",
+        "gnu",
+        "eabihf",
+    ),
+    ("arm-unknown-linux-musleabi", "arm", "linux", "musl", "eabi"),
+    (
+        "arm-unknown-linux-musleabihf",
+        "arm",
+        "linux",
+        "musl",
+        "eabihf",
+    ),
+    (
+        "armv5te-unknown-linux-gnueabi",
+        "armv5te",
+        "linux",
+        "gnu",
+        "eabi",
+    ),
+    (
+        "armv7-unknown-linux-gnueabi",
+        "armv7",
+        "linux",
+        "gnu",
+        "eabi",
+    ),
+    (
+        "armv7-unknown-linux-gnueabihf",
+        "armv7",
+        "linux",
+        "gnu",
+        "eabihf",
+    ),
+    (
+        "armv7-unknown-linux-musleabihf",
+        "armv7",
+        "linux",
+        "musl",
+        "eabihf",
+    ),
+    ("aarch64-linux-android", "aarch64", "android", "", ""),
+    ("armv7-linux-androideabi", "armv7", "android", "", "eabi"),
+    ("i686-linux-android", "i686", "android", "", ""),
+    ("x86_64-linux-android", "x86_64", "android", "", ""),
+    ("x86_64-apple-darwin", "x86_64", "macos", "", ""),
+    ("aarch64-apple-darwin", "aarch64", "macos", "", ""),
+    ("x86_64-apple-ios", "x86_64", "ios", "", ""),
+    ("aarch64-apple-ios", "aarch64", "ios", "", ""),
+    ("x86_64-pc-windows-gnu", "x86_64", "windows", "gnu", ""),
+    ("x86_64-pc-windows-msvc", "x86_64", "windows", "msvc", ""),
+    ("i686-pc-windows-gnu", "i686", "windows", "gnu", ""),
+    ("i686-pc-windows-msvc", "i686", "windows", "msvc", ""),
+    ("aarch64-pc-windows-msvc", "aarch64", "windows", "msvc", ""),
+    ("wasm32-unknown-unknown", "wasm32", "unknown", "", ""),
+    ("wasm32-wasi", "wasm32", "wasi", "p1", ""),
+    ("wasm32-wasip1", "wasm32", "wasi", "p1", ""),
+    ("wasm32-unknown-emscripten", "wasm32", "emscripten", "", ""),
+    ("riscv64gc-unknown-linux-gnu", "riscv64", "linux", "gnu", ""),
+    ("powerpc-unknown-linux-gnu", "powerpc", "linux", "gnu", ""),
+    (
+        "powerpc64-unknown-linux-gnu",
+        "powerpc64",
+        "linux",
+        "gnu",
+        "elfv1",
+    ),
+    (
+        "powerpc64le-unknown-linux-gnu",
+        "powerpc64le",
+        "linux",
+        "gnu",
+        "elfv2",
+    ),
+    ("mips-unknown-linux-gnu", "mips", "linux", "gnu", ""),
+    ("mipsel-unknown-linux-gnu", "mipsel", "linux", "gnu", ""),
+    (
+        "mips64-unknown-linux-gnuabi64",
+        "mips64",
+        "linux",
+        "gnu",
+        "abi64",
+    ),
+    (
+        "mips64el-unknown-linux-gnuabi64",
+        "mips64el",
+        "linux",
+        "gnu",
+        "abi64",
+    ),
+    ("s390x-unknown-linux-gnu", "s390x", "linux", "gnu", ""),
+    ("x86_64-unknown-freebsd", "x86_64", "freebsd", "", ""),
+    ("x86_64-unknown-netbsd", "x86_64", "netbsd", "", ""),
+    ("x86_64-unknown-openbsd", "x86_64", "openbsd", "", ""),
+    ("x86_64-unknown-redox", "x86_64", "redox", "relibc", ""),
+    ("thumbv6m-none-eabi", "thumbv6m", "none", "", "eabi"),
+    ("thumbv7m-none-eabi", "thumbv7m", "none", "", "eabi"),
+    ("thumbv7em-none-eabi", "thumbv7em", "none", "", "eabi"),
+    ("thumbv7em-none-eabihf", "thumbv7em", "none", "", "eabihf"),
+    ("riscv32imac-unknown-none-elf", "riscv32", "none", "", ""),
+];
 
 pub fn to_expr(p: &Platform, platform_var: &str) -> BoolExpr {
     match p {
-        Platform::Name(triplet) => {
-            BoolExpr::Single(format!("{}.config == {:?}", platform_var, triplet))
-        }
+        Platform::Name(triple) => triple_to_expr(triple, platform_var)
+            .unwrap_or_else(|| eq(platform_var, "config", triple)),
         Platform::Cfg(cfg) => cfg_to_expr(cfg, platform_var),
     }
 }
 
-fn cfg_to_expr(cfg: &CfgExpr, platform_var: &str) -> BoolExpr {
-    use self::BoolExpr::{False, Single};
-    use cargo_platform::Cfg;
+/// Whether `triple` is in the translation table, rather than compared against `hostPlatform.config`
+/// verbatim.
+pub fn is_known_triple(triple: &str) -> bool {
+    TRIPLES.iter().any(|(t, ..)| *t == triple)
+}
+
+fn triple_to_expr(triple: &str, platform_var: &str) -> Option<BoolExpr> {
+    let &(_, arch, os, env, abi) = TRIPLES.iter().find(|(t, ..)| *t == triple)?;
+    let cfg = |key: &str, value: &str| {
+        translate_cfg(
+            &Cfg::KeyPair(key.to_string(), value.to_string()),
+            platform_var,
+        )
+    };
+
+    // Most triples are told apart by their arch and os alone, so the env and abi are only
+    // compared where they are set, or where another triple with the same arch and os sets them.
+    let same_arch_and_os: Vec<_> = TRIPLES
+        .iter()
+        .filter(|t| t.0 != triple && t.1 == arch && t.2 == os)
+        .collect();
+    let same_env: Vec<_> = same_arch_and_os.iter().filter(|t| t.3 == env).collect();
+
+    let mut exprs = vec![
+        triple_arch_to_expr(arch, platform_var)?,
+        cfg("target_os", os)?,
+    ];
+    exprs.extend(component_to_exprs(
+        env,
+        same_arch_and_os.iter().map(|t| t.3),
+        |v| cfg("target_env", v),
+    )?);
+    exprs.extend(component_to_exprs(
+        abi,
+        same_env.iter().map(|t| t.4),
+        |v| cfg("target_abi", v),
+    )?);
+    Some(BoolExpr::ands(exprs))
+}
+
+// Matches the env or abi of a triple. An empty one only rules out the values of `others`, since
+// translating it on its own would rule out every value Nix knows about.
+fn component_to_exprs<'a>(
+    value: &str,
+    others: impl Iterator<Item = &'a str>,
+    cfg: impl Fn(&str) -> Option<BoolExpr>,
+) -> Option<Vec<BoolExpr>> {
+    if !value.is_empty() {
+        return Some(vec![cfg(value)?]);
+    }
 
+    let others: BTreeSet<_> = others.filter(|v| !v.is_empty()).collect();
+    if others.is_empty() {
+        return Some(Vec::new());
+    }
+    let others = others.into_iter().map(cfg).collect::<Option<Vec<_>>>()?;
+    Some(vec![BoolExpr::ors(others).not()])
+}
+
+// Nix records the ARM architecture version in `parsed.cpu.version`, but can't tell the
+// microcontroller profiles (`thumbv*`) apart.
+fn triple_arch_to_expr(arch: &str, platform_var: &str) -> Option<BoolExpr> {
+    let is = |predicate| is(platform_var, predicate);
+    let cpu = |name| eq(platform_var, "parsed.cpu.name", name);
+    let arm_version =
+        |version| is("isAarch32").and(eq(platform_var, "parsed.cpu.version", version));
+
+    Some(match arch {
+        "x86_64" => is("isx86_64"),
+        "i686" | "i586" => cpu(arch),
+        "aarch64" => is("isAarch64"),
+        "arm" => arm_version("6"),
+        "armv5te" => arm_version("5"),
+        "armv7" => arm_version("7"),
+        "thumbv6m" | "thumbv7m" | "thumbv7em" => is("isAarch32"),
+        "wasm32" | "riscv32" | "riscv64" | "powerpc" | "powerpc64" | "powerpc64le" | "mips"
+        | "mipsel" | "mips64" | "mips64el" | "s390x" => cpu(arch),
+        _ => return None,
+    })
+}
+
+/// Collects the cfgs in `p` which have no Nix translation and are therefore assumed to be unset.
+pub fn untranslatable_cfgs(p: &Platform, platform_var: &str) -> BTreeSet<String> {
+    fn collect(cfg: &CfgExpr, platform_var: &str, out: &mut BTreeSet<String>) {
+        match cfg {
+            CfgExpr::Not(c) => collect(c, platform_var, out),
+            CfgExpr::All(cfgs) | CfgExpr::Any(cfgs) => {
+                cfgs.iter().for_each(|c| collect(c, platform_var, out))
+            }
+            CfgExpr::Value(cfg) => {
+                if translate_cfg(cfg, platform_var).is_none() {
+                    out.insert(cfg.to_string());
+                }
+            }
+        }
+    }
+
+    let mut out = BTreeSet::new();
+    if let Platform::Cfg(cfg) = p {
+        collect(cfg, platform_var, &mut out);
+    }
+    out
+}
+
+fn cfg_to_expr(cfg: &CfgExpr, platform_var: &str) -> BoolExpr {
     match cfg {
         CfgExpr::Not(c) => cfg_to_expr(c, platform_var).not(),
         CfgExpr::All(cfgs) => BoolExpr::ands(cfgs.iter().map(|c| cfg_to_expr(c, platform_var))),
         CfgExpr::Any(cfgs) => BoolExpr::ors(cfgs.iter().map(|c| cfg_to_expr(c, platform_var))),
-        CfgExpr::Value(Cfg::Name(n)) => match n.as_str() {
-            "windows" => Single(format!("{}.isWindows", platform_var)),
-            "unix" => Single(format!("{}.isUnix", platform_var)),
-            _ => False,
+        CfgExpr::Value(cfg) => translate_cfg(cfg, platform_var).unwrap_or(BoolExpr::False),
+    }
+}
+
+/// Translates a single cfg into a predicate over the Nix `hostPlatform`, or over one of the
+/// generated `hostPlatform*` parameters for settings that the platform alone doesn't determine.
+///
+/// Cfgs which `rustc --print cfg` never reports, and which cargo therefore never considers set
+/// when selecting dependencies (`test`, `doc`, `miri`, ...), translate to `false`. Names and keys
+/// unknown to rustc are custom `--cfg` flags, which are looked up in `hostPlatformCfgs`.
+fn translate_cfg(cfg: &Cfg, platform_var: &str) -> Option<BoolExpr> {
+    use self::BoolExpr::{False, True};
+
+    let is = |predicate| is(platform_var, predicate);
+    let eq = |attr, value| eq(platform_var, attr, value);
+
+    let expr = match cfg {
+        Cfg::Name(n) => match n.as_str() {
+            "windows" => is("isWindows"),
+            "unix" => is("isUnix"),
+            "debug_assertions" => {
+                BoolExpr::Single(Atom::Var(format!("{}DebugAssertions", platform_var)))
+            }
+            "test" | "doc" | "doctest" | "miri" | "proc_macro" | "overflow_checks"
+            | "ub_checks" | "contract_checks" | "emscripten_wasm_eh" => False,
+            "target_thread_local" => return None,
+            custom => custom_cfg_to_expr(custom, platform_var),
         },
-        CfgExpr::Value(Cfg::KeyPair(k, v)) => match (k.as_str(), v.as_str()) {
-            ("target_arch", v) => Single(format!("{}.parsed.cpu.name == {:?}", platform_var, v)),
-            ("target_os", "macos") => Single(format!(
-                "{}.parsed.kernel.name == {:?}",
-                platform_var, "darwin"
-            )),
-            ("target_os", v) => Single(format!("{}.parsed.kernel.name == {:?}", platform_var, v)),
-            ("target_family", "unix") => Single(format!("{}.isUnix", platform_var)),
-            ("target_family", "windows") => Single(format!("{}.isWindows", platform_var)),
-            ("target_env", v) => Single(format!("{}.parsed.abi.name == {:?}", platform_var, v)),
-            ("target_endian", "little") => Single(format!(
-                "{}.parsed.cpu.significantByte == {:?}",
-                platform_var, "littleEndian"
-            )),
-            ("target_endian", "big") => Single(format!(
-                "{}.parsed.cpu.significantByte == {:?}",
-                platform_var, "bigEndian"
+        Cfg::KeyPair(k, v) => match (k.as_str(), v.as_str()) {
+            ("target_arch", v) => arch_to_expr(v, platform_var),
+            ("target_os", "linux") => is("isLinux").and(is("isAndroid").not()),
+            ("target_os", "android") => is("isAndroid"),
+            ("target_os", "macos") => is("isMacOS"),
+            ("target_os", "ios") => is("isiOS"),
+            ("target_os", "windows") => is("isWindows"),
+            ("target_os", "wasi") => is("isWasi"),
+            ("target_os", "redox") => is("isRedox"),
+            ("target_os", "none") => is("isNone").and(is("isWasm").not()),
+            // Nix calls `wasm32-unknown-unknown` `wasm32-unknown-none`.
+            ("target_os", "unknown") => is("isNone").and(is("isWasm")),
+            ("target_os", v) => eq("parsed.kernel.name", v),
+            ("target_family", "unix") => is("isUnix"),
+            ("target_family", "windows") => is("isWindows"),
+            ("target_family", "wasm") => is("isWasm"),
+            ("target_family", _) => return None,
+            ("target_env", "gnu") => is("isGnu"),
+            ("target_env", "musl") => is("isMusl"),
+            ("target_env", "uclibc") => is("isUClibc"),
+            ("target_env", "") => BoolExpr::ors(vec![
+                is("isGnu"),
+                is("isMusl"),
+                is("isUClibc"),
+                is("isWasi"),
+                eq("parsed.abi.name", "msvc"),
+            ])
+            .not(),
+            // WASI preview 1 is the only WASI flavour Nix knows about.
+            ("target_env", "p1") => is("isWasi"),
+            ("target_env", v) => eq("parsed.abi.name", v),
+            ("target_abi", v) => abi_to_expr(v, platform_var)?,
+            ("target_endian", "little") => is("isLittleEndian"),
+            ("target_endian", "big") => is("isBigEndian"),
+            ("target_endian", _) => False,
+            ("target_pointer_width", bits @ "16")
+            | ("target_pointer_width", bits @ "32")
+            | ("target_pointer_width", bits @ "64") => BoolExpr::Single(Atom::EqInt(
+                format!("{}.parsed.cpu.bits", platform_var),
+                bits.parse().unwrap(),
             )),
-            ("target_pointer_width", "32") => {
-                Single(format!("{}.parsed.cpu.bits == 32", platform_var))
+            ("target_pointer_width", _) => False,
+            ("target_vendor", "apple") => is("isDarwin"),
+            ("target_vendor", "pc") => is("isWindows").or(eq("parsed.vendor.name", "pc")),
+            ("target_vendor", v) => eq("parsed.vendor.name", v),
+            ("target_has_atomic", width) | ("target_has_atomic_equal_alignment", width) => {
+                let expr = atomic_to_expr(width, platform_var)?;
+                if k == "target_has_atomic_equal_alignment" && width == "64" {
+                    // 64-bit integers are only 4-byte aligned on 32-bit x86.
+                    expr.and(is("isx86_32").not())
+                } else {
+                    expr
+                }
             }
-            ("target_pointer_width", "64") => {
-                Single(format!("{}.parsed.cpu.bits == 64", platform_var))
+            ("target_has_atomic_load_store", "8")
+            | ("target_has_atomic_load_store", "16")
+            | ("target_has_atomic_load_store", "32")
+            | ("target_has_atomic_load_store", "ptr") => True,
+            ("target_has_atomic_load_store", width) => atomic_to_expr(width, platform_var)?,
+            ("panic", v) => {
+                BoolExpr::Single(Atom::Eq(format!("{}Panic", platform_var), v.to_string()))
             }
-            ("target_vendor", v) => {
-                Single(format!("{}.parsed.vendor.name == {:?}", platform_var, v))
+            ("relocation_model", "pic") => is("isWasm").or(is("isNone")).not(),
+            ("relocation_model", "static") => is("isWasm").or(is("isNone")),
+            ("relocation_model", _) => False,
+            ("fmt_debug", "full") => True,
+            ("fmt_debug", _) => False,
+            ("sanitize", _) => False,
+            ("target_cpu", v) => {
+                BoolExpr::Single(Atom::Eq(format!("{}Cpu", platform_var), v.to_string()))
             }
-            ("target_cpu", v) => Single(format!("{}Cpu == {:?}", platform_var, v)),
-            ("target_feature", v) => {
-                Single(format!("builtins.elem {:?} {}Features", v, platform_var,))
-            }
-            _ => False,
+            ("target_feature", v) => target_feature_to_expr(v, platform_var),
+            (k, _) if k.starts_with("target_") => return None,
+            (k, v) => custom_cfg_to_expr(&format!("{}={:?}", k, v), platform_var),
         },
+    };
+
+    Some(expr)
+}
+
+// Custom cfgs are listed in `--cfg` syntax, e.g. `tokio_unstable` or `foo="bar"`.
+fn custom_cfg_to_expr(cfg: &str, platform_var: &str) -> BoolExpr {
+    BoolExpr::Single(Atom::Elem(cfg.to_string(), format!("{}Cfgs", platform_var)))
+}
+
+// Rust and Nix mostly agree on CPU names, except for architectures which Nix splits by version or
+// endianness.
+fn arch_to_expr(arch: &str, platform_var: &str) -> BoolExpr {
+    let is = |predicate| is(platform_var, predicate);
+    let eq = |attr, value| eq(platform_var, attr, value);
+    match arch {
+        "x86" => is("isx86_32"),
+        "arm" => is("isAarch32"),
+        "aarch64" => is("isAarch64"),
+        "mips" => is("isMips32"),
+        "mips64" => is("isMips64"),
+        "powerpc" => is("isPower").and(is("is32bit")),
+        "powerpc64" => is("isPower64"),
+        "riscv32" => is("isRiscV").and(is("is32bit")),
+        "riscv64" => is("isRiscV").and(is("is64bit")),
+        other => eq("parsed.cpu.name", other),
+    }
+}
+
+// Nix folds the ABI modifier into `parsed.abi` (e.g. `gnueabihf`), while rustc reports it separately.
+fn abi_to_expr(abi: &str, platform_var: &str) -> Option<BoolExpr> {
+    const ABIS: &[&str] = &["eabi", "eabihf", "abi64", "x32", "ilp32", "elfv1", "elfv2"];
+
+    let is = |predicate| is(platform_var, predicate);
+    let abi_is =
+        |abis: &[&str]| BoolExpr::ors(abis.iter().map(|a| eq(platform_var, "parsed.abi.name", a)));
+
+    Some(match abi {
+        "" => BoolExpr::ors(ABIS.iter().filter_map(|abi| abi_to_expr(abi, platform_var))).not(),
+        "eabi" => abi_is(&["eabi", "gnueabi", "musleabi", "androideabi"]),
+        "eabihf" => abi_is(&["eabihf", "gnueabihf", "musleabihf"]),
+        "abi64" => abi_is(&["gnuabi64", "muslabi64"]),
+        "x32" => abi_is(&["gnux32"]),
+        "ilp32" => abi_is(&["gnuilp32"]),
+        // Little-endian 64-bit PowerPC always uses ELFv2, big-endian only when asked to.
+        "elfv2" => is("isPower64")
+            .and(is("isLittleEndian"))
+            .or(abi_is(&["gnuabielfv2"])),
+        "elfv1" => is("isPower64")
+            .and(is("isBigEndian"))
+            .and(abi_is(&["gnuabielfv2"]).not()),
+        _ => return None,
+    })
+}
+
+fn atomic_to_expr(width: &str, platform_var: &str) -> Option<BoolExpr> {
+    let is = |predicate| is(platform_var, predicate);
+    let no_atomics = || is("isAvr").or(is("isMsp430"));
+    Some(match width {
+        "8" | "16" | "32" | "ptr" => no_atomics().not(),
+        "64" => BoolExpr::ors(vec![
+            is("is64bit"),
+            is("isx86_32"),
+            is("isWasm"),
+            // Cortex-M cores lack the doubleword exclusive loads and stores.
+            BoolExpr::ands(vec![
+                is("isAarch32"),
+                is("isNone").not(),
+                BoolExpr::Single(Atom::VersionAtLeast {
+                    path: format!("{}.parsed.cpu.version", platform_var),
+                    default: "0".to_string(),
+                    minimum: "6".to_string(),
+                }),
+            ]),
+        ]),
+        // `cmpxchg16b` is only part of the baseline of the x86_64 Darwin and Windows targets.
+        "128" => BoolExpr::ors(vec![
+            is("isAarch64"),
+            eq(platform_var, "parsed.cpu.name", "s390x"),
+            is("isx86_64").and(is("isDarwin").or(is("isWindows"))),
+        ]),
+        _ => return None,
+    })
+}
+
+// Target features enabled by default are reported by rustc without any `-C target-feature` flag,
+// so they must hold even when `hostPlatformFeatures` doesn't list them.
+fn target_feature_to_expr(feature: &str, platform_var: &str) -> BoolExpr {
+    let requested = BoolExpr::Single(Atom::Elem(
+        feature.to_string(),
+        format!("{}Features", platform_var),
+    ));
+    match feature {
+        "fxsr" | "sse" | "sse2" => requested.or(is(platform_var, "isx86")),
+        "neon" => requested.or(is(platform_var, "isAarch64")),
+        _ => requested,
+    }
+}
+
+fn is(platform_var: &str, predicate: &str) -> BoolExpr {
+    BoolExpr::Single(Atom::Var(format!("{}.{}", platform_var, predicate)))
+}
+
+fn eq(platform_var: &str, attr: &str, value: &str) -> BoolExpr {
+    BoolExpr::Single(Atom::Eq(
+        format!("{}.{}", platform_var, attr),
+        value.to_string(),
+    ))
+}
+
+/// Checks the generated predicates against the cfgs rustc reports for a range of targets.
+///
+/// Each Rust target is evaluated against the model in `crate::eval` of the Nix `hostPlatform` that
+/// `lib.systems.elaborate` produces for the corresponding Nix system. The fixtures in `tests/fixtures/rustc-cfg` are the
+/// output of `rustc --print cfg --target <triple>`, and can be refreshed with:
+///
+/// ```sh
+/// for f in tests/fixtures/rustc-cfg/*.cfg; do
+///   rustc --print cfg --target "$(basename "$f" .cfg)" > "$f"
+/// done
+/// ```
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::path::Path;
+    use std::str::FromStr;
+
+    use cargo_platform::{Cfg, CfgExpr, Platform};
+
+    use super::{to_expr, TRIPLES};
+    use crate::eval::{Env, NixPlatform};
+
+    /// Targets with a fixture in tests/fixtures/rustc-cfg.
+    const TARGETS: &[&str] = &[
+        "x86_64-unknown-linux-gnu",
+        "x86_64-unknown-linux-musl",
+        "i686-unknown-linux-gnu",
+        "aarch64-unknown-linux-gnu",
+        "armv7-unknown-linux-gnueabihf",
+        "arm-unknown-linux-gnueabi",
+        "x86_64-apple-darwin",
+        "aarch64-apple-darwin",
+        "aarch64-apple-ios",
+        "x86_64-pc-windows-gnu",
+        "x86_64-pc-windows-msvc",
+        "i686-pc-windows-gnu",
+        "aarch64-linux-android",
+        "wasm32-unknown-unknown",
+        "wasm32-wasip1",
+        "riscv64gc-unknown-linux-gnu",
+        "powerpc64le-unknown-linux-gnu",
+        "mips64-unknown-linux-gnuabi64",
+        "s390x-unknown-linux-gnu",
+        "thumbv7em-none-eabihf",
+        "x86_64-unknown-freebsd",
+        "x86_64-unknown-netbsd",
+    ];
+
+    // Target features which rustc enables by default and which the translation accounts for. Any
+    // other `target_feature` is only set through `hostPlatformFeatures`.
+    const MODELLED_FEATURES: &[&str] = &["fxsr", "sse", "sse2", "neon"];
+
+    fn env(triple: &str) -> Env {
+        Env::new(NixPlatform::for_triple(triple).unwrap(), &[] as &[&str])
+    }
+
+    fn fixture(triple: &str) -> BTreeSet<String> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/rustc-cfg")
+            .join(format!("{}.cfg", triple));
+        fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e))
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    fn is_modelled(cfg: &Cfg) -> bool {
+        match cfg {
+            Cfg::KeyPair(k, v) if k == "target_feature" => MODELLED_FEATURES.contains(&v.as_str()),
+            _ => true,
+        }
+    }
+
+    #[test]
+    fn cfgs_match_rustc() {
+        let cfgs_by_target: Vec<_> = TARGETS
+            .iter()
+            .map(|triple| (*triple, env(triple), fixture(triple)))
+            .collect();
+
+        // Every cfg reported for any target is checked against every target, so that a predicate
+        // which is too broad shows up as well as one which is too narrow.
+        let vocabulary: BTreeSet<Cfg> = cfgs_by_target
+            .iter()
+            .flat_map(|(_, _, cfgs)| cfgs.iter())
+            .map(|cfg| Cfg::from_str(cfg).unwrap())
+            .filter(is_modelled)
+            .collect();
+
+        let mut mismatches = Vec::new();
+        for (triple, env, cfgs) in cfgs_by_target.iter() {
+            let reported: BTreeSet<Cfg> = cfgs.iter().map(|c| Cfg::from_str(c).unwrap()).collect();
+            for cfg in vocabulary.iter() {
+                let expr = to_expr(&Platform::Cfg(CfgExpr::Value(cfg.clone())), "hostPlatform");
+                let expected = reported.contains(cfg);
+                if env.eval(&expr).unwrap() != expected {
+                    mismatches.push(format!(
+                        "{}: cfg({}) is {} for rustc, but `{}` is {}",
+                        triple,
+                        cfg,
+                        expected,
+                        expr.to_nix(),
+                        !expected
+                    ));
+                }
+            }
+        }
+
+        assert!(
+            mismatches.is_empty(),
+            "{} mismatches:\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        );
+    }
+
+    #[test]
+    fn triples_match_only_their_platform() {
+        // Several Rust targets, like the Cortex-M ones, share a Nix platform.
+        let modelled: Vec<_> = TRIPLES
+            .iter()
+            .filter_map(|(triple, ..)| Some((*triple, NixPlatform::for_triple(triple).ok()?)))
+            .collect();
+
+        let mut mismatches = Vec::new();
+        for (triple, nix) in modelled.iter() {
+            let expr = to_expr(&Platform::Name(triple.to_string()), "hostPlatform");
+            for (other, other_nix) in modelled.iter() {
+                let env = Env::new(other_nix.clone(), &[] as &[&str]);
+                if env.eval(&expr).unwrap() != (nix == other_nix) {
+                    mismatches.push(format!("{}: `{}` on {}", triple, expr.to_nix(), other));
+                }
+            }
+        }
+
+        assert!(
+            mismatches.is_empty(),
+            "{} mismatches:\n{}",
+            mismatches.len(),
+            mismatches.join("\n")
+        );
+    }
+
+    #[test]
+    fn triples_only_compare_the_components_they_need() {
+        let nix = |triple: &str| {
+            to_expr(&Platform::Name(triple.to_string()), "hostPlatform")
+                .to_nix()
+                .to_string()
+        };
+
+        assert_eq!(
+            nix("aarch64-apple-darwin"),
+            "hostPlatform.isAarch64 && hostPlatform.isMacOS"
+        );
+        assert_eq!(
+            nix("x86_64-pc-windows-msvc"),
+            "hostPlatform.isx86_64 && hostPlatform.isWindows \
+             && hostPlatform.parsed.abi.name == \"msvc\""
+        );
+        assert_eq!(
+            nix("x86_64-unknown-linux-gnu"),
+            "hostPlatform.isx86_64 && hostPlatform.isLinux && !hostPlatform.isAndroid \
+             && hostPlatform.isGnu && !(hostPlatform.parsed.abi.name == \"gnux32\")"
+        );
     }
 }
@@ -0,0 +1,193 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use cargo::core::{Package, PackageId};
+use serde::Deserialize;
+
+use crate::license::Expr;
+use crate::why;
+use crate::ResolvedPackage;
+
+/// Which licenses crates may be used under, read from a TOML file such as
+///
+/// ```toml
+/// allow = ["MIT", "Apache-2.0", "BSD-3-Clause"]
+/// deny = ["GPL-3.0-only", "GPL-3.0-or-later"]
+///
+/// [exceptions]
+/// ring = ["LicenseRef-ring"]
+/// ```
+///
+/// Licenses are SPDX identifiers, optionally with an exception as in `Apache-2.0 WITH
+/// LLVM-exception`. A license is denied if it is listed in `deny`, and otherwise accepted if `allow`
+/// is empty or lists it. The licenses listed for a crate in `exceptions` are accepted for that crate
+/// only, even if denied. Crates without a license expression are licensed under `NOASSERTION`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    #[serde(default)]
+    allow: BTreeSet<String>,
+    #[serde(default)]
+    deny: BTreeSet<String>,
+    #[serde(default)]
+    exceptions: BTreeMap<String, BTreeSet<String>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Verdict {
+    Accepted,
+    Denied,
+    NotAllowed,
+}
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Self> {
+        let policy = fs::read_to_string(path)
+            .context(format!("could not read license policy {}", path.display()))?;
+        toml::from_str(&policy).context(format!("{} is not a license policy", path.display()))
+    }
+
+    fn verdict(&self, crate_name: &str, id: &str, exception: Option<&str>) -> Verdict {
+        let with_exception = exception.map(|e| format!("{} WITH {}", id, e));
+        let lists = |licenses: &BTreeSet<String>| {
+            licenses.iter().any(|l| {
+                l.eq_ignore_ascii_case(id)
+                    || with_exception
+                        .as_ref()
+                        .map_or(false, |w| l.eq_ignore_ascii_case(w))
+            })
+        };
+
+        if self.exceptions.get(crate_name).map_or(false, lists) {
+            Verdict::Accepted
+        } else if lists(&self.deny) {
+            Verdict::Denied
+        } else if self.allow.is_empty() || lists(&self.allow) {
+            Verdict::Accepted
+        } else {
+            Verdict::NotAllowed
+        }
+    }
+
+    /// The reasons the crate cannot be used under `license`, or nothing if it can.
+    fn violations(&self, crate_name: &str, license: &str) -> Vec<String> {
+        let expr = match Expr::parse(license) {
+            Ok(expr) => expr,
+            Err(err) => return vec![err.to_string()],
+        };
+        let accepts = |id: &str, exception: Option<&str>| {
+            self.verdict(crate_name, id, exception) == Verdict::Accepted
+        };
+        if expr.is_satisfied_by(&accepts) {
+            return Vec::new();
+        }
+
+        expr.licenses()
+            .into_iter()
+            .filter_map(|(id, exception)| {
+                let license = match exception {
+                    Some(exception) => format!("{} WITH {}", id, exception),
+                    None => id.to_string(),
+                };
+                match self.verdict(crate_name, id, exception) {
+                    Verdict::Accepted => None,
+                    Verdict::Denied => Some(format!("{} is denied", license)),
+                    Verdict::NotAllowed => Some(format!("{} is not allowed", license)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Checks the licenses of every crate in the resolved graph other than the workspace members
+/// against `policy`, printing each violation with the shortest dependency path from each member
+/// that depends on the crate. Fails if there are any violations.
+pub fn check<'a>(
+    policy: &Policy,
+    root_pkgs: &[&'a Package],
+    rpkgs_by_id: &BTreeMap<PackageId, ResolvedPackage<'a>>,
+    mut out: impl io::Write,
+) -> Result<()> {
+    let mut count = 0;
+    for (id, rpkg) in rpkgs_by_id.iter() {
+        if root_pkgs.iter().any(|pkg| pkg.package_id() == *id) {
+            continue;
+        }
+
+        let license = rpkg.pkg.manifest().metadata().license.as_deref();
+        let violations = policy.violations(&id.name(), license.unwrap_or("NOASSERTION"));
+        if violations.is_empty() {
+            continue;
+        }
+
+        if count > 0 {
+            writeln!(out)?;
+        }
+        count += 1;
+        match license {
+            Some(license) => write!(
+                out,
+                "{} {} is licensed under {}",
+                id.name(),
+                id.version(),
+                license
+            )?,
+            None => write!(out, "{} {} has no license", id.name(), id.version())?,
+        }
+        writeln!(out, ": {}", violations.join(", "))?;
+        for pkg in root_pkgs.iter() {
+            if let Some(path) = why::any_path(pkg.package_id(), *id, rpkgs_by_id) {
+                write!(out, "  {} {}", pkg.name(), pkg.version())?;
+                why::write_path(&mut out, &path)?;
+                writeln!(out)?;
+            }
+        }
+    }
+
+    match count {
+        0 => Ok(()),
+        1 => Err(anyhow!("1 crate violates the license policy")),
+        n => Err(anyhow!("{} crates violate the license policy", n)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policies_accept_expressions() {
+        let policy: Policy = toml::from_str(
+            r#"
+            allow = ["MIT", "Apache-2.0", "Apache-2.0 WITH LLVM-exception"]
+            deny = ["GPL-3.0-only"]
+
+            [exceptions]
+            special = ["GPL-3.0-only", "NOASSERTION"]
+            "#,
+        )
+        .unwrap();
+
+        let ok: &[&str] = &[];
+        assert_eq!(policy.violations("a", "MIT OR GPL-3.0-only"), ok);
+        assert_eq!(policy.violations("a", "MIT/Apache-2.0"), ok);
+        assert_eq!(policy.violations("a", "Apache-2.0 WITH LLVM-exception"), ok);
+        assert_eq!(policy.violations("special", "GPL-3.0-only"), ok);
+        assert_eq!(policy.violations("special", "NOASSERTION"), ok);
+        assert_eq!(
+            policy.violations("a", "MIT AND GPL-3.0-only"),
+            ["GPL-3.0-only is denied"]
+        );
+        assert_eq!(
+            policy.violations("a", "GPL-3.0-only OR Zlib"),
+            ["GPL-3.0-only is denied", "Zlib is not allowed"]
+        );
+        assert_eq!(
+            policy.violations("a", "MIT OR"),
+            ["invalid license expression `MIT OR`"]
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use cargo::util::Sha256;
+use serde_json::{json, Value};
+
+use crate::explain::{Explanation, PackageSet};
+use crate::license;
+use crate::template::{BuildPlan, Crate, CrateId, Member, Source};
+
+/// Where SPDX documents are namespaced unless another URI is passed with `--namespace`.
+pub const DEFAULT_SPDX_NAMESPACE: &str = "https://github.com/cargo2nix/cargo2nix/spdxdocs";
+
+#[derive(Clone, Copy, Debug)]
+pub enum Format {
+    CycloneDx,
+    Spdx,
+}
+
+impl Format {
+    pub fn parse(format: &str) -> Result<Self> {
+        match format {
+            "cyclonedx" => Ok(Format::CycloneDx),
+            "spdx" => Ok(Format::Spdx),
+            _ => Err(anyhow!(
+                "unknown SBOM format `{}`, expected cyclonedx or spdx",
+                format
+            )),
+        }
+    }
+}
+
+/// The crates linked into a workspace member, as found by `explain` for the member alone.
+/// Build dependencies and procedural macros run while building but are not linked, so only crates
+/// built for the host platform are listed.
+pub struct Sbom<'a> {
+    member: &'a Crate,
+    crates: BTreeMap<CrateId<'a>, (&'a Crate, Vec<&'a str>)>,
+    dependencies: BTreeMap<CrateId<'a>, Vec<CrateId<'a>>>,
+}
+
+impl<'a> Sbom<'a> {
+    pub fn new(
+        plan: &'a BuildPlan,
+        member: &Member,
+        explanation: &Explanation<'a>,
+    ) -> Result<Self> {
+        let crates_by_id: BTreeMap<_, _> = plan.crates.iter().map(|c| (c.id(), c)).collect();
+        let member_id = CrateId {
+            name: &member.name,
+            version: &member.version,
+            registry: "unknown",
+        };
+        let member = crates_by_id
+            .get(&member_id)
+            .copied()
+            .ok_or_else(|| anyhow!("{} is missing from the plan", member.name))?;
+
+        let crates = explanation
+            .crates
+            .iter()
+            .filter(|((set, _), _)| *set == PackageSet::Host)
+            .map(|((_, id), features)| {
+                (*id, (crates_by_id[id], features.iter().copied().collect()))
+            })
+            .collect();
+        let dependencies = explanation
+            .dependencies
+            .iter()
+            .filter(|((set, _), _)| *set == PackageSet::Host)
+            .map(|((_, id), deps)| {
+                let deps = deps
+                    .iter()
+                    .filter(|(set, _)| *set == PackageSet::Host)
+                    .map(|(_, dep)| *dep)
+                    .collect();
+                (*id, deps)
+            })
+            .collect();
+
+        Ok(Sbom {
+            member,
+            crates,
+            dependencies,
+        })
+    }
+
+    /// Writes the SBOM, with `spdx_namespace` as the URI prefix of the namespace of SPDX documents.
+    pub fn write(&self, format: Format, spdx_namespace: &str, out: impl io::Write) -> Result<()> {
+        let sbom = match format {
+            Format::CycloneDx => self.cyclonedx(),
+            Format::Spdx => self.spdx(spdx_namespace)?,
+        };
+        serde_json::to_writer_pretty(out, &sbom)?;
+        Ok(())
+    }
+
+    /// A CycloneDX 1.3 BOM, referring to components by their package URL.
+    fn cyclonedx(&self) -> Value {
+        let component = |krate: &Crate, features: &[&str]| {
+            let mut component = json!({
+                "type": if krate.id() == self.member.id() { "application" } else { "library" },
+                "bom-ref": purl(krate),
+                "name": krate.name,
+                "version": krate.version,
+                "purl": purl(krate),
+            });
+            if let Some(sha256) = sha256(krate) {
+                component["hashes"] = json!([{ "alg": "SHA-256", "content": sha256 }]);
+            }
+            if let Some(description) = &krate.meta.description {
+                component["description"] = json!(description);
+            }
+            if !krate.meta.authors.is_empty() {
+                component["author"] = json!(krate.meta.authors.join(", "));
+            }
+            if let Some(license) = &krate.meta.license {
+                component["licenses"] = json!([{ "expression": license::normalize(license) }]);
+            }
+            let mut references = Vec::new();
+            if let Some(homepage) = &krate.meta.homepage {
+                references.push(json!({ "type": "website", "url": homepage }));
+            }
+            if let Some(repository) = &krate.meta.repository {
+                references.push(json!({ "type": "vcs", "url": repository }));
+            }
+            if !references.is_empty() {
+                component["externalReferences"] = json!(references);
+            }
+            if !features.is_empty() {
+                component["properties"] =
+                    json!([{ "name": "cargo:features", "value": features.join(",") }]);
+            }
+            component
+        };
+
+        let member_features = &self.crates[&self.member.id()].1;
+        let components: Vec<_> = self
+            .crates
+            .values()
+            .filter(|(krate, _)| krate.id() != self.member.id())
+            .map(|(krate, features)| component(krate, features))
+            .collect();
+        let dependencies: Vec<_> = self
+            .dependencies
+            .iter()
+            .map(|(id, deps)| {
+                let depends_on: Vec<_> = deps.iter().map(|dep| purl(self.crates[dep].0)).collect();
+                json!({ "ref": purl(self.crates[id].0), "dependsOn": depends_on })
+            })
+            .collect();
+
+        json!({
+            "bomFormat": "CycloneDX",
+            "specVersion": "1.3",
+            "version": 1,
+            "metadata": {
+                "timestamp": timestamp(),
+                "tools": [{ "vendor": "cargo2nix", "name": "cargo2nix", "version": env!("CARGO_PKG_VERSION") }],
+                "component": component(self.member, member_features),
+            },
+            "components": components,
+            "dependencies": dependencies,
+        })
+    }
+
+    /// An SPDX 2.2 document describing the member. Its namespace ends in a hash of its contents,
+    /// so that it only changes when the document does.
+    fn spdx(&self, namespace: &str) -> Result<Value> {
+        let spdx_ids = spdx_ids(self.crates.values().map(|(krate, _)| *krate));
+
+        let packages: Vec<_> = self
+            .crates
+            .values()
+            .map(|(krate, _)| {
+                let mut package = json!({
+                    "SPDXID": spdx_ids[&krate.id()],
+                    "name": krate.name,
+                    "versionInfo": krate.version,
+                    "downloadLocation": download_location(krate),
+                    "filesAnalyzed": false,
+                    "licenseConcluded": "NOASSERTION",
+                    "licenseDeclared": krate
+                        .meta
+                        .license
+                        .as_deref()
+                        .map_or_else(|| "NOASSERTION".to_string(), license::normalize),
+                    "copyrightText": "NOASSERTION",
+                    "externalRefs": [{
+                        "referenceCategory": "PACKAGE_MANAGER",
+                        "referenceType": "purl",
+                        "referenceLocator": purl(krate),
+                    }],
+                });
+                if let Some(sha256) = sha256(krate) {
+                    package["checksums"] =
+                        json!([{ "algorithm": "SHA256", "checksumValue": sha256 }]);
+                }
+                if let Some(description) = &krate.meta.description {
+                    package["description"] = json!(description);
+                }
+                if let Some(homepage) = &krate.meta.homepage {
+                    package["homepage"] = json!(homepage);
+                }
+                package
+            })
+            .collect();
+
+        let mut relationships = vec![json!({
+            "spdxElementId": "SPDXRef-DOCUMENT",
+            "relationshipType": "DESCRIBES",
+            "relatedSpdxElement": spdx_ids[&self.member.id()],
+        })];
+        for (id, deps) in self.dependencies.iter() {
+            for dep in deps {
+                relationships.push(json!({
+                    "spdxElementId": spdx_ids[id],
+                    "relationshipType": "DEPENDS_ON",
+                    "relatedSpdxElement": spdx_ids[dep],
+                }));
+            }
+        }
+
+        let name = format!("{}-{}", self.member.name, self.member.version);
+        let mut document = json!({
+            "spdxVersion": "SPDX-2.2",
+            "dataLicense": "CC0-1.0",
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "name": name,
+            "creationInfo": {
+                "created": timestamp(),
+                "creators": [format!("Tool: cargo2nix-{}", env!("CARGO_PKG_VERSION"))],
+            },
+            "packages": packages,
+            "relationships": relationships,
+        });
+        let hash = Sha256::new()
+            .update(&serde_json::to_vec(&document)?)
+            .finish_hex();
+        document["documentNamespace"] = json!(format!(
+            "{}/{}-{}",
+            namespace.trim_end_matches('/'),
+            name,
+            hash
+        ));
+        Ok(document)
+    }
+}
+
+/// The package URL of a crate. Crates from other registries, git or paths carry where they come
+/// from as qualifiers, the latter relative to the workspace root.
+fn purl(krate: &Crate) -> String {
+    let purl = format!("pkg:cargo/{}@{}", krate.name, krate.version);
+    match &krate.source {
+        Source::CratesIo { .. } => purl,
+        Source::Local { path } => format!(
+            "{}?download_url={}",
+            purl,
+            percent_encode(&format!("file:{}", path.display()))
+        ),
+        Source::Registry { index, .. } => {
+            format!("{}?repository_url={}", purl, percent_encode(index))
+        }
+        Source::Git { url, rev, .. } => format!(
+            "{}?vcs_url={}",
+            purl,
+            percent_encode(&format!("git+{}@{}", url, rev))
+        ),
+    }
+}
+
+fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+fn sha256(krate: &Crate) -> Option<&str> {
+    match &krate.source {
+        Source::CratesIo { sha256 } | Source::Registry { sha256, .. } => Some(sha256),
+        Source::Git { .. } | Source::Local { .. } => None,
+    }
+}
+
+fn download_location(krate: &Crate) -> String {
+    match &krate.source {
+        Source::CratesIo { .. } => format!(
+            "https://crates.io/api/v1/crates/{}/{}/download",
+            krate.name, krate.version
+        ),
+        Source::Git { url, rev, .. } => format!("git+{}@{}", url, rev),
+        Source::Registry { .. } | Source::Local { .. } => "NOASSERTION".to_string(),
+    }
+}
+
+/// SPDX identifiers of the form `SPDXRef-<name>-<version>`, numbered if that would be ambiguous.
+fn spdx_ids<'a>(crates: impl Iterator<Item = &'a Crate>) -> BTreeMap<CrateId<'a>, String> {
+    let sanitize = |s: &str| -> String {
+        s.chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    };
+
+    let mut ids = BTreeMap::new();
+    let mut taken = BTreeMap::new();
+    for krate in crates {
+        let id = format!(
+            "SPDXRef-{}-{}",
+            sanitize(&krate.name),
+            sanitize(&krate.version)
+        );
+        let count = taken.entry(id.clone()).or_insert(0);
+        *count += 1;
+        let id = if *count == 1 {
+            id
+        } else {
+            format!("{}-{}", id, count)
+        };
+        ids.insert(krate.id(), id);
+    }
+    ids
+}
+
+/// The creation time as RFC 3339, taken from `SOURCE_DATE_EPOCH` when set so that SBOMs can be
+/// reproduced.
+fn timestamp() -> String {
+    let secs = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs())
+        });
+
+    // Converts days since the epoch to a date in the proleptic Gregorian calendar, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days.
+    let days = (secs / 86400) as i64 + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    let secs_of_day = secs % 86400;
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_follow_source_date_epoch() {
+        for (epoch, expected) in [
+            ("0", "1970-01-01T00:00:00Z"),
+            ("951782400", "2000-02-29T00:00:00Z"),
+            ("1792324799", "2026-10-18T11:59:59Z"),
+        ]
+        .iter()
+        {
+            std::env::set_var("SOURCE_DATE_EPOCH", epoch);
+            assert_eq!(timestamp(), *expected);
+        }
+        std::env::remove_var("SOURCE_DATE_EPOCH");
+    }
+}
@@ -1,13 +1,26 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Result};
-use cargo::core::{dependency::DepKind, Package, PackageId, SourceId, source::GitReference};
-use serde::Serialize;
+use cargo::core::{
+    dependency::DepKind, source::GitReference, Package, PackageId, SourceId, TargetKind,
+};
+use serde::{Deserialize, Serialize};
+use tera::Tera;
 
-use crate::manifest::TomlProfile;
-use crate::{platform, BoolExpr, Feature as FeatureStr, Optionality, ResolvedPackage};
+use crate::license;
+use crate::manifest::{ProfileSources, TomlProfile};
+use crate::overrides::{self, Metadata, Overrides};
+use crate::{
+    platform, BoolExpr, Feature as FeatureStr, Optionality, ResolvedDependency, ResolvedPackage,
+};
 
+/// Everything `Cargo.nix` is rendered from.
+///
+/// The output must only change when the workspace does, so nothing here is left in the order
+/// cargo happens to produce it: crates are sorted by name, version and registry, dependencies of
+/// each kind by extern name and then the same crate order, and features by name. Only the
+/// workspace members keep the order they are declared in.
 #[derive(Debug, Serialize)]
 pub struct BuildPlan {
     pub cargo2nix_version: String,
@@ -15,6 +28,12 @@ pub struct BuildPlan {
     pub profiles: BTreeMap<String, String>,
     pub workspace_members: Vec<Member>,
     pub crates: Vec<Crate>,
+    /// Suggested overrides for crates linking native libraries, emitted as comments. Only filled
+    /// on request, see `native::hints`.
+    pub override_stubs: Vec<String>,
+    /// Where each profile setting was defined, reported when generating `Cargo.nix`.
+    #[serde(skip)]
+    pub profile_sources: ProfileSources,
 }
 
 impl BuildPlan {
@@ -22,6 +41,7 @@ impl BuildPlan {
         root_pkgs: Vec<&'_ Package>,
         profiles: TomlProfile,
         rpkgs_by_id: BTreeMap<PackageId, ResolvedPackage<'_>>,
+        workspace_metadata: &Metadata,
         cwd: &Path,
     ) -> Result<Self> {
         let root_features = root_pkgs
@@ -32,9 +52,11 @@ impl BuildPlan {
         let profiles = profiles
             .into_iter()
             .map(|(name, profile)| (name, toml::to_string(&profile).unwrap()))
-            .map(|(name, toml)| (name, toml.replace("${", "\\${").escape_debug().to_string()))
+            .map(|(name, toml)| (name, to_nix_string(&toml)))
             .collect();
 
+        let member_ids: BTreeSet<_> = root_pkgs.iter().map(|pkg| pkg.package_id()).collect();
+
         let workspace_members = root_pkgs
             .into_iter()
             .map(|pkg| Member {
@@ -43,19 +65,55 @@ impl BuildPlan {
             })
             .collect();
 
-        let crates = rpkgs_by_id
+        let mut rpkgs: Vec<_> = rpkgs_by_id.into_iter().collect();
+        rpkgs.sort_by_key(|(pkg_id, _)| crate_order(*pkg_id));
+
+        let crates = rpkgs
             .into_iter()
             .map(|(pkg_id, resolved_pkg)| {
                 let (deps, dev_deps, build_deps) = to_dependencies(&resolved_pkg);
+                let pkg = resolved_pkg.pkg;
+                let lib = pkg.targets().iter().find(|t| t.is_lib());
+                let source = to_source(&resolved_pkg, cwd)?;
+                let local_path = match &source {
+                    Source::Local { path } => Some(path.as_path()),
+                    _ => None,
+                };
+                let overrides = overrides::overrides(
+                    workspace_metadata,
+                    pkg,
+                    member_ids.contains(&pkg_id),
+                    local_path,
+                )?;
                 Ok(Crate {
                     name: pkg_id.name().to_string(),
                     version: pkg_id.version().to_string(),
                     registry: to_registry_string(pkg_id.source_id()),
-                    source: to_source(&resolved_pkg, cwd)?,
+                    source,
                     features: to_features(&resolved_pkg.features),
                     dependencies: deps,
                     dev_dependencies: dev_deps,
                     build_dependencies: build_deps,
+                    targets: if member_ids.contains(&pkg_id) {
+                        to_targets(pkg)
+                    } else {
+                        Vec::new()
+                    },
+                    lib_name: lib.map(|t| t.crate_name()),
+                    lib_crate_types: lib
+                        .map(|t| {
+                            t.rustc_crate_types()
+                                .iter()
+                                .map(|t| t.to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                    links: pkg.manifest().links().map(|l| l.to_string()),
+                    edition: pkg.manifest().edition().to_string(),
+                    build_script: to_build_script(pkg),
+                    manifest: to_nix_string(&to_manifest(pkg)?),
+                    meta: to_meta(pkg),
+                    overrides,
                 })
             })
             .collect::<Result<_>>()?;
@@ -66,6 +124,8 @@ impl BuildPlan {
             profiles,
             workspace_members,
             crates,
+            override_stubs: Vec::new(),
+            profile_sources: ProfileSources::new(),
         })
     }
 }
@@ -86,9 +146,64 @@ pub struct Crate {
     pub dependencies: Vec<Dependency>,
     pub dev_dependencies: Vec<Dependency>,
     pub build_dependencies: Vec<Dependency>,
+    /// The targets of a workspace member, empty for other crates.
+    pub targets: Vec<Target>,
+    /// The name the library is linked as, if the crate has one.
+    pub lib_name: Option<String>,
+    pub lib_crate_types: Vec<String>,
+    pub links: Option<String>,
+    pub edition: String,
+    /// The path of the build script, relative to the crate root.
+    pub build_script: Option<PathBuf>,
+    /// The manifest the crate is built with, escaped for a Nix string. See `to_manifest`.
+    pub manifest: String,
+    pub meta: Meta,
+    /// Settings from the `cargo2nix` tables of package metadata, see `overrides::overrides`.
+    pub overrides: Overrides,
 }
 
+/// Package metadata for the derivation's `meta`. The template escapes it with the `nix_string`
+/// and `nix_license` filters, and uses the repository as the homepage of crates without one.
+/// Authors have no place in `meta` and only end up in the SBOM.
 #[derive(Debug, Serialize)]
+pub struct Meta {
+    pub description: Option<String>,
+    /// An SPDX license expression.
+    pub license: Option<String>,
+    pub homepage: Option<String>,
+    pub repository: Option<String>,
+    pub authors: Vec<String>,
+}
+
+/// Identifies a crate the way `Cargo.nix` does, as `rustPackages.<registry>.<name>.<version>`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct CrateId<'a> {
+    pub name: &'a str,
+    pub version: &'a str,
+    pub registry: &'a str,
+}
+
+impl Crate {
+    pub fn id(&self) -> CrateId<'_> {
+        CrateId {
+            name: &self.name,
+            version: &self.version,
+            registry: &self.registry,
+        }
+    }
+}
+
+impl Dependency {
+    pub fn id(&self) -> CrateId<'_> {
+        CrateId {
+            name: &self.name,
+            version: &self.version,
+            registry: &self.registry,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub enum Source {
     CratesIo { sha256: String },
     Git { url: String, rev: String, branch: Option<String> },
@@ -99,7 +214,7 @@ pub enum Source {
 #[derive(Debug, Serialize)]
 pub struct Feature {
     pub name: String,
-    pub activated_by: Option<String>,
+    pub activated_by: Option<BoolExpr>,
 }
 
 #[derive(Debug, Serialize)]
@@ -108,10 +223,23 @@ pub struct Dependency {
     pub extern_name: String,
     pub version: String,
     pub registry: String,
-    pub cfg_condition: Option<String>,
+    pub cfg_condition: Option<BoolExpr>,
     pub is_proc_macro: bool,
 }
 
+#[derive(Debug, Serialize)]
+pub struct Target {
+    /// `lib`, `bin`, `example`, `test` or `bench`, as in the cargo flag selecting the target.
+    pub kind: &'static str,
+    pub name: String,
+    pub required_features: Vec<String>,
+    pub crate_types: Vec<String>,
+    /// Whether the target uses libtest's harness, and so takes its command line arguments.
+    pub harness: bool,
+    /// Whether `cargo test` runs the target.
+    pub tested: bool,
+}
+
 fn to_registry_string(src_id: SourceId) -> String {
     if src_id.is_path() {
         "unknown".to_string()
@@ -177,7 +305,17 @@ fn to_source(pkg: &ResolvedPackage<'_>, cwd: &Path) -> Result<Source> {
     Ok(source)
 }
 
+/// The canonical order of crates in the build plan.
+fn crate_order(pkg_id: PackageId) -> impl Ord {
+    (
+        pkg_id.name().to_string(),
+        pkg_id.version().clone(),
+        to_registry_string(pkg_id.source_id()),
+    )
+}
+
 fn to_features(features: &BTreeMap<FeatureStr<'_>, Optionality<'_>>) -> Vec<Feature> {
+    // Iterating the map already yields the features by name.
     features
         .iter()
         .map(
@@ -188,13 +326,167 @@ fn to_features(features: &BTreeMap<FeatureStr<'_>, Optionality<'_>>) -> Vec<Feat
                 },
                 expr => Feature {
                     name: name.to_string(),
-                    activated_by: Some(expr.to_nix().to_string()),
+                    activated_by: Some(expr),
                 },
             },
         )
         .collect()
 }
 
+/// The targets of `pkg` in the order cargo lists them, leaving out the build script.
+fn to_targets(pkg: &Package) -> Vec<Target> {
+    pkg.targets()
+        .iter()
+        .filter_map(|target| {
+            let kind = match target.kind() {
+                TargetKind::Lib(_) => "lib",
+                TargetKind::Bin => "bin",
+                TargetKind::ExampleBin | TargetKind::ExampleLib(_) => "example",
+                TargetKind::Test => "test",
+                TargetKind::Bench => "bench",
+                TargetKind::CustomBuild => return None,
+            };
+            Some(Target {
+                kind,
+                name: target.name().to_string(),
+                required_features: target.required_features().cloned().unwrap_or_default(),
+                crate_types: target
+                    .rustc_crate_types()
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect(),
+                harness: target.harness(),
+                tested: target.tested(),
+            })
+        })
+        .collect()
+}
+
+/// Reduces the manifest of `pkg` to what building it on its own needs: the package and its
+/// targets. Dependencies are passed to rustc by the overlay rather than resolved by cargo, and the
+/// features and profile are added at build time.
+///
+/// Benches are only kept for local crates, since published crates often leave their bench
+/// sources out of the package.
+fn to_manifest(pkg: &Package) -> Result<String> {
+    let original = match serde_json::to_value(pkg.manifest().original())? {
+        serde_json::Value::Object(original) => original,
+        _ => unreachable!("manifests serialize as tables"),
+    };
+
+    let mut sections = vec![("package", "package"), ("project", "package")];
+    sections.extend(&[
+        ("lib", "lib"),
+        ("bin", "bin"),
+        ("test", "test"),
+        ("example", "example"),
+    ]);
+    if pkg.package_id().source_id().is_path() {
+        sections.push(("bench", "bench"));
+    }
+
+    let mut manifest = serde_json::Map::new();
+    for (key, section) in sections {
+        if let Some(value) = original.get(key).filter(|v| !v.is_null()) {
+            manifest
+                .entry(section)
+                .or_insert_with(|| without_nulls(value.clone()));
+        }
+    }
+
+    let manifest = toml::Value::try_from(manifest)?;
+    Ok(toml::to_string(&manifest)?)
+}
+
+/// Removes nulls, which TOML cannot represent, from the fields of tables.
+fn without_nulls(value: serde_json::Value) -> serde_json::Value {
+    use serde_json::Value;
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k, without_nulls(v)))
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(without_nulls).collect()),
+        value => value,
+    }
+}
+
+fn to_meta(pkg: &Package) -> Meta {
+    let metadata = pkg.manifest().metadata();
+    let trimmed = |s: &Option<String>| s.as_ref().map(|s| s.trim().to_string());
+    Meta {
+        description: trimmed(&metadata.description),
+        license: trimmed(&metadata.license),
+        homepage: trimmed(&metadata.homepage),
+        repository: trimmed(&metadata.repository),
+        authors: metadata.authors.clone(),
+    }
+}
+
+/// Escapes `s` for a Nix string literal. Nix has no escapes for other characters, which are
+/// valid in a string literal as they are.
+pub fn to_nix_string(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .replace('\t', "\\t")
+        .replace("${", "\\${")
+}
+
+/// The Tera filters the templates use to write Nix.
+pub fn register_filters(tera: &mut Tera) {
+    fn string_arg(value: &tera::Value) -> tera::Result<&str> {
+        value
+            .as_str()
+            .ok_or_else(|| tera::Error::msg(format!("expected a string, got {}", value)))
+    }
+    tera.register_filter("nix_string", |value: &tera::Value, _: &_| {
+        Ok(to_nix_string(string_arg(value)?).into())
+    });
+    tera.register_filter("nix_license", |value: &tera::Value, _: &_| {
+        Ok(license::to_nix(string_arg(value)?).into())
+    });
+    tera.register_filter("nix_comment", |value: &tera::Value, _: &_| {
+        let lines: Vec<_> = string_arg(value)?
+            .lines()
+            .map(|l| format!("# {}", l))
+            .collect();
+        Ok(lines.join("\n").into())
+    });
+}
+
+fn to_build_script(pkg: &Package) -> Option<PathBuf> {
+    let target = pkg.targets().iter().find(|t| t.is_custom_build())?;
+    let path = target.src_path().path()?;
+    Some(path.strip_prefix(pkg.root()).unwrap_or(path).to_path_buf())
+}
+
+/// The condition under which `Cargo.nix` includes `dep`, or `None` if it always does.
+pub fn to_cfg_condition(dep: &ResolvedDependency<'_>) -> Option<BoolExpr> {
+    let platforms = match dep.platforms {
+        None => BoolExpr::True,
+        Some(ref platforms) => BoolExpr::ors(
+            platforms
+                .iter()
+                .map(|p| platform::to_expr(p, "hostPlatform")),
+        ),
+    };
+
+    match dep
+        .optionality
+        .to_expr("rootFeatures'")
+        .and(platforms)
+        .simplify()
+    {
+        BoolExpr::True => None,
+        expr => Some(expr),
+    }
+}
+
 fn to_dependencies(
     pkg: &ResolvedPackage<'_>,
 ) -> (Vec<Dependency>, Vec<Dependency>, Vec<Dependency>) {
@@ -202,32 +494,16 @@ fn to_dependencies(
     let mut dev_dependencies = Vec::new();
     let mut build_dependencies = Vec::new();
 
-    for ((pkg_id, kind), dep) in &pkg.deps {
-        let platforms = match dep.platforms {
-            None => BoolExpr::True,
-            Some(ref platforms) => BoolExpr::ors(
-                platforms
-                    .iter()
-                    .map(|p| platform::to_expr(p, "hostPlatform")),
-            ),
-        };
-
-        let cfg_condition = match dep
-            .optionality
-            .to_expr("rootFeatures'")
-            .and(platforms)
-            .simplify()
-        {
-            BoolExpr::True => None,
-            expr => Some(expr.to_nix().to_string()),
-        };
+    let mut deps: Vec<_> = pkg.deps.iter().collect();
+    deps.sort_by_key(|((pkg_id, _), dep)| (dep.extern_name.clone(), crate_order(*pkg_id)));
 
+    for ((pkg_id, kind), dep) in deps {
         let dep = Dependency {
             name: pkg_id.name().to_string(),
             extern_name: dep.extern_name.to_string(),
             version: pkg_id.version().to_string(),
             registry: to_registry_string(pkg_id.source_id()),
-            cfg_condition,
+            cfg_condition: to_cfg_condition(dep),
             is_proc_macro: crate::is_proc_macro(&dep.pkg),
         };
 
@@ -240,3 +516,17 @@ fn to_dependencies(
 
     (dependencies, dev_dependencies, build_dependencies)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nix_strings_are_escaped() {
+        assert_eq!(
+            to_nix_string("a \"quoted\" \\ path\n\tto ${out}"),
+            "a \\\"quoted\\\" \\\\ path\\n\\tto \\${out}"
+        );
+        assert_eq!(to_nix_string("Zoë's crate 🦀 $HOME"), "Zoë's crate 🦀 $HOME");
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::io;
+
+use anyhow::{anyhow, Result};
+use cargo::core::{dependency::DepKind, Package, PackageId, PackageIdSpec};
+
+use crate::{
+    all_features, display_root_feature, Optionality, PackageName, ResolvedDependency,
+    ResolvedPackage, RootFeature,
+};
+
+/// What a generated condition can be satisfied by: a workspace member built without features
+/// (`rootFeatures' ? "<member>"`), or one of its features (`rootFeatures' ? "<member>/<feature>"`).
+#[derive(Clone, Copy)]
+enum Root<'a> {
+    Member(PackageName<'a>),
+    Feature(RootFeature<'a>),
+}
+
+impl<'a> Root<'a> {
+    fn pkg_name(self) -> PackageName<'a> {
+        match self {
+            Root::Member(name) | Root::Feature((name, _)) => name,
+        }
+    }
+
+    fn enables(self, optionality: &Optionality) -> bool {
+        match optionality {
+            Optionality::Required => true,
+            Optionality::Optional {
+                required_by_pkgs,
+                activated_by_features,
+            } => {
+                required_by_pkgs.contains(self.pkg_name())
+                    || match self {
+                        Root::Member(_) => false,
+                        Root::Feature(root_feature) => {
+                            activated_by_features.contains(&root_feature)
+                        }
+                    }
+            }
+        }
+    }
+
+    fn display(self) -> String {
+        match self {
+            Root::Member(name) => name.to_string(),
+            Root::Feature(root_feature) => display_root_feature(root_feature),
+        }
+    }
+}
+
+pub type Edge<'a, 'b> = (&'b (PackageId, DepKind), &'b ResolvedDependency<'a>);
+
+/// Prints the workspace members and root features that enable the packages matching `spec`, or
+/// `feature` of them, with the shortest dependency path from the member to the package.
+///
+/// `rpkgs_by_id` must not have been simplified, since that forgets which root features
+/// activate a dependency once every workspace member requires it.
+pub fn why<'a>(
+    root_pkgs: &[&'a Package],
+    rpkgs_by_id: &BTreeMap<PackageId, ResolvedPackage<'a>>,
+    spec: &PackageIdSpec,
+    feature: Option<&str>,
+    mut out: impl io::Write,
+) -> Result<()> {
+    let targets: Vec<_> = rpkgs_by_id
+        .keys()
+        .filter(|id| spec.matches(**id))
+        .copied()
+        .collect();
+    if targets.is_empty() {
+        return Err(anyhow!("package `{}` is not in the dependency graph", spec));
+    }
+
+    let roots: Vec<_> = root_pkgs
+        .iter()
+        .flat_map(|pkg| {
+            let name = pkg.name().as_str();
+            let features = all_features(pkg).map(move |f| (pkg, Root::Feature((name, f))));
+            std::iter::once((pkg, Root::Member(name))).chain(features)
+        })
+        .collect();
+
+    for (i, target) in targets.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+
+        match feature {
+            None => writeln!(out, "{} {} is enabled by:", target.name(), target.version())?,
+            Some(feature) => writeln!(
+                out,
+                "feature `{}` of {} {} is enabled by:",
+                feature,
+                target.name(),
+                target.version()
+            )?,
+        }
+
+        let mut found = false;
+        for (pkg, root) in roots.iter() {
+            // Everything a member enables on its own is enabled by each of its features too.
+            if let Root::Feature((name, _)) = root {
+                let member = Root::Member(name);
+                if enables_target(member, pkg.package_id(), *target, feature, rpkgs_by_id).is_some()
+                {
+                    continue;
+                }
+            }
+
+            let path = enables_target(*root, pkg.package_id(), *target, feature, rpkgs_by_id);
+            if let Some(path) = path {
+                found = true;
+                write!(
+                    out,
+                    "  {}: {} {}",
+                    root.display(),
+                    pkg.name(),
+                    pkg.version()
+                )?;
+                write_path(&mut out, &path)?;
+                writeln!(out)?;
+            }
+        }
+
+        if !found {
+            writeln!(
+                out,
+                "  nothing, no workspace member or root feature enables it"
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes the packages along `path` as ` -> <name> <version>`, noting dependencies that are not
+/// normal ones or only used on some platforms.
+pub fn write_path(mut out: impl io::Write, path: &[Edge]) -> io::Result<()> {
+    for ((dep_id, kind), dep) in path {
+        write!(out, " -> {} {}", dep_id.name(), dep_id.version())?;
+        let mut notes = Vec::new();
+        match kind {
+            DepKind::Normal => {}
+            DepKind::Development => notes.push("dev".to_string()),
+            DepKind::Build => notes.push("build".to_string()),
+        }
+        if let Some(platforms) = &dep.platforms {
+            let platforms: Vec<_> = platforms.iter().map(|p| p.to_string()).collect();
+            notes.push(platforms.join(" or "));
+        }
+        if !notes.is_empty() {
+            write!(out, " ({})", notes.join(", "))?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the shortest path from `from` to `to` along any dependency, whether or not it is
+/// optional.
+pub fn any_path<'a, 'b>(
+    from: PackageId,
+    to: PackageId,
+    rpkgs_by_id: &'b BTreeMap<PackageId, ResolvedPackage<'a>>,
+) -> Option<Vec<Edge<'a, 'b>>> {
+    shortest_path(|_| true, from, to, rpkgs_by_id)
+}
+
+/// Returns the path from `root_id` to `target` if `root` enables it, as well as `feature` of it.
+fn enables_target<'a, 'b>(
+    root: Root<'a>,
+    root_id: PackageId,
+    target: PackageId,
+    feature: Option<&str>,
+    rpkgs_by_id: &'b BTreeMap<PackageId, ResolvedPackage<'a>>,
+) -> Option<Vec<Edge<'a, 'b>>> {
+    if let Some(feature) = feature {
+        let optionality = rpkgs_by_id[&target].features.get(feature)?;
+        if !root.enables(optionality) {
+            return None;
+        }
+    }
+
+    shortest_path(|o| root.enables(o), root_id, target, rpkgs_by_id)
+}
+
+/// Breadth-first search along the dependencies that `enables` accepts.
+fn shortest_path<'a, 'b>(
+    enables: impl Fn(&Optionality) -> bool,
+    from: PackageId,
+    to: PackageId,
+    rpkgs_by_id: &'b BTreeMap<PackageId, ResolvedPackage<'a>>,
+) -> Option<Vec<Edge<'a, 'b>>> {
+    let mut parents: BTreeMap<PackageId, (PackageId, Edge<'a, 'b>)> = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(from);
+    while let Some(id) = queue.pop_front() {
+        if id == to {
+            let mut path = Vec::new();
+            let mut id = to;
+            while id != from {
+                let (parent, edge) = parents[&id];
+                path.push(edge);
+                id = parent;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for (key, dep) in rpkgs_by_id[&id].deps.iter() {
+            let dep_id = key.0;
+            if dep_id != from && !parents.contains_key(&dep_id) && enables(&dep.optionality) {
+                parents.insert(dep_id, (id, (key, dep)));
+                queue.push_back(dep_id);
+            }
+        }
+    }
+
+    None
+}
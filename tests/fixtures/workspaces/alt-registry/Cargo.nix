@@ -0,0 +1,99 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "alt-registry/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    alt-registry = rustPackages.unknown.alt-registry."0.1.0";
+  };
+  "registry+https://alt.example.com/index".alt-crate."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "alt-crate";
+    version = "0.1.0";
+    registry = "registry+https://alt.example.com/index";
+    src = fetchCrateAlternativeRegistry {
+      index = https://alt.example.com/index;
+      name = "alt-crate";
+      version = "0.1.0";
+      sha256 = "c00ab447b2de3b40a7a8c09b05ed9cffdd9fde37bacf278ee0323159ceaefab9";
+    };
+    edition = "2018";
+    libName = "alt_crate";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nhomepage = \"https://alt.example.com\"\nlicense = \"LicenseRef-Custom\"\nname = \"alt-crate\"\nversion = \"0.1.0\"\n";
+    meta = {
+      license = "LicenseRef-Custom";
+      homepage = "https://alt.example.com";
+    };
+  });
+  
+  "unknown".alt-registry."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "alt-registry";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"alt-registry\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      alt_crate = rustPackages."registry+https://alt.example.com/index".alt-crate."0.1.0" { inherit profileName; };
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" { inherit profileName; };
+    };
+    targets = [
+      { kind = "bin"; name = "alt-registry"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "1.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6de753b69b752faff1602403f63ae11a2eae890fc14e13c728d3c6097096c539"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Leaf Author <leaf@example.com>\"]\ndescription = \"A leaf crate with \${dollar} and \\\"quotes\\\"\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"leaf\"\nrepository = \"https://github.com/example/leaf\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A leaf crate with \${dollar} and \"quotes\"";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/example/leaf";
+    };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
+}
@@ -0,0 +1,95 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "git-deps/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    git-deps = rustPackages.unknown.git-deps."0.1.0";
+  };
+  "git+https://github.com/example/git-crate".git-crate."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "git-crate";
+    version = "0.1.0";
+    registry = "git+https://github.com/example/git-crate";
+    src = fetchCrateGit {
+      url = https://github.com/example/git-crate;
+      name = "git-crate";
+      version = "0.1.0";
+      rev = "0f9c2a4b6d8e1f3a5c7b9d0e2f4a6c8b1d3e5f70";
+      ref = "main";};
+    edition = "2018";
+    libName = "git_crate";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"git-crate\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" { inherit profileName; };
+    };
+  });
+  
+  "unknown".git-deps."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "git-deps";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"git-deps\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      git_crate = rustPackages."git+https://github.com/example/git-crate".git-crate."0.1.0" { inherit profileName; };
+    };
+    targets = [
+      { kind = "bin"; name = "git-deps"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "2.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "0db4b40d4eed3b666f4a6c526cf50c2565d37b5b9ae3d41dbab9801079e922fe"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nlicense = \"LGPL-3.0-or-later\"\nname = \"leaf\"\nversion = \"2.0.0\"\n";
+    meta = {
+      license = [ lib.licenses.lgpl3Plus ];
+    };
+    features = builtins.concatLists [
+      [ "alloc" ]
+      [ "default" ]
+    ];
+  });
+  
+}
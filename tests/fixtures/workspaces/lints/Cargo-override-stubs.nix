@@ -0,0 +1,162 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+#
+# These crates link native libraries that none of the bundled overrides provide. Overrides like
+# the following, passed in `packageOverrides`, add them to the build inputs:
+#
+# (pkgs.rustBuilder.rustLib.makeOverride {
+#   name = "native-sys";
+#   overrideAttrs = drv: {
+#     propagatedBuildInputs = drv.propagatedBuildInputs or [ ] ++ [
+#       pkgs.zlib
+#       # unless the build script compiles a bundled copy of it with cc
+#     ];
+#     nativeBuildInputs = drv.nativeBuildInputs or [ ] ++ [ pkgs.pkgconfig ];
+#   };
+# })
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "lints/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    lints = rustPackages.unknown.lints."0.1.0";
+  };
+  "registry+https://github.com/rust-lang/crates.io-index".cc."1.0.66" = overridableMkRustCrate (profileName: rec {
+    name = "cc";
+    version = "1.0.66";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "79163c5621b95c555e1e5f9002f8442d3068be6212e9097142e7aa067439ab20"; };
+    edition = "2018";
+    libName = "cc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"cc\"\nversion = \"1.0.66\"\n";
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "1.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6de753b69b752faff1602403f63ae11a2eae890fc14e13c728d3c6097096c539"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Leaf Author <leaf@example.com>\"]\ndescription = \"A leaf crate with \${dollar} and \\\"quotes\\\"\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"leaf\"\nrepository = \"https://github.com/example/leaf\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A leaf crate with \${dollar} and \"quotes\"";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/example/leaf";
+    };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "2.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "0db4b40d4eed3b666f4a6c526cf50c2565d37b5b9ae3d41dbab9801079e922fe"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nlicense = \"LGPL-3.0-or-later\"\nname = \"leaf\"\nversion = \"2.0.0\"\n";
+    meta = {
+      license = [ lib.licenses.lgpl3Plus ];
+    };
+    features = builtins.concatLists [
+      [ "default" ]
+    ];
+  });
+  
+  "unknown".lints."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "lints";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"lints\"\nversion = \"0.1.0\"\n[package.metadata.cargo2nix]\nallow-lints = [\"duplicate-versions\"]\n";
+    dependencies = {
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" { inherit profileName; };
+      ${ if false then "leaf2" else null } = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" { inherit profileName; };
+      native_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".native-sys."0.1.0" { inherit profileName; };
+      outside_crate = rustPackages."unknown".outside-crate."0.1.0" { inherit profileName; };
+    };
+    targets = [
+      { kind = "bin"; name = "lints"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".native-sys."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "native-sys";
+    version = "0.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "cd739db2893207cbbf25abf0263741624e4b8e84b96d5a9a1f8e3d41d41646f1"; };
+    edition = "2018";
+    libName = "native_sys";
+    libCrateTypes = [ "lib" ];
+    links = "z";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nbuild = \"build.rs\"\nedition = \"2018\"\nlinks = \"z\"\nname = \"native-sys\"\nversion = \"0.1.0\"\n[package.metadata.cargo2nix]\npatches = [\"nix/no-vendor.patch\"]\n\n[package.metadata.cargo2nix.env]\nNATIVE_SYS_USE_PKG_CONFIG = \"1\"\n";
+    buildDependencies = {
+      cc = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.0.66" { profileName = "__noProfile"; };
+      pkg_config = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.19" { profileName = "__noProfile"; };
+    };
+  });
+  
+  "unknown".outside-crate."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "outside-crate";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal (workspaceSrc + "/../../outside-crate");
+    edition = "2018";
+    libName = "outside_crate";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"outside-crate\"\nversion = \"0.1.0\"\n";
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.19" = overridableMkRustCrate (profileName: rec {
+    name = "pkg-config";
+    version = "0.3.19";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "b4f3e61c9e64c4321f0967813d942de2c4536521fce1569cbce491972e2279e6"; };
+    edition = "2018";
+    libName = "pkg_config";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"pkg-config\"\nversion = \"0.3.19\"\n";
+  });
+  
+}
@@ -0,0 +1,159 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "app/default"
+    "helper/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    app = rustPackages.unknown.app."0.1.0";
+    helper = rustPackages.unknown.helper."0.1.0";
+  };
+  "unknown".app."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "app";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal (workspaceSrc + "/app");
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"app\"\nversion = \"0.1.0\"\n[package.metadata.cargo2nix]\nnative-build-inputs = [\"protobuf\", \"xorg.libxcb\"]\npatches = [\"nix/app.patch\"]\nrustc-flags = [\"-Ctarget-cpu=native\"]\n\n[package.metadata.cargo2nix.env]\nBUILT_BY = \"app\"\nPROTOC_NO_VENDOR = \"1\"\n";
+    nativeBuildInputAttrs = [ "protobuf" "xorg.libxcb" ];
+    env = {
+      "BUILT_BY" = "app";
+      "GREETING" = "say \"hi\" from \${HOME}";
+      "PROTOC_NO_VENDOR" = "1";
+    };
+    rustcflags = [ "--cfg" "nix" "-Ctarget-cpu=native" ];
+    patches = [
+      (workspaceSrc + "/app/nix/app.patch")
+    ];
+    dependencies = {
+      helper = rustPackages."unknown".helper."0.1.0" { inherit profileName; };
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" { inherit profileName; };
+      native_sys = rustPackages."registry+https://github.com/rust-lang/crates.io-index".native-sys."0.1.0" { inherit profileName; };
+    };
+    targets = [
+      { kind = "bin"; name = "app"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".cc."1.0.66" = overridableMkRustCrate (profileName: rec {
+    name = "cc";
+    version = "1.0.66";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "79163c5621b95c555e1e5f9002f8442d3068be6212e9097142e7aa067439ab20"; };
+    edition = "2018";
+    libName = "cc";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"cc\"\nversion = \"1.0.66\"\n";
+  });
+  
+  "unknown".helper."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "helper";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal (workspaceSrc + "/helper");
+    edition = "2018";
+    libName = "helper";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"helper\"\nversion = \"0.1.0\"\n";
+    env = {
+      "BUILT_BY" = "cargo2nix";
+      "GREETING" = "say \"hi\" from \${HOME}";
+    };
+    rustcflags = [ "--cfg" "nix" ];
+    targets = [
+      { kind = "lib"; name = "helper"; requiredFeatures = [ ]; crateTypes = [ "lib" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "1.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6de753b69b752faff1602403f63ae11a2eae890fc14e13c728d3c6097096c539"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Leaf Author <leaf@example.com>\"]\ndescription = \"A leaf crate with \${dollar} and \\\"quotes\\\"\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"leaf\"\nrepository = \"https://github.com/example/leaf\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A leaf crate with \${dollar} and \"quotes\"";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/example/leaf";
+    };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".native-sys."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "native-sys";
+    version = "0.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "cd739db2893207cbbf25abf0263741624e4b8e84b96d5a9a1f8e3d41d41646f1"; };
+    edition = "2018";
+    libName = "native_sys";
+    libCrateTypes = [ "lib" ];
+    links = "z";
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[package]\nbuild = \"build.rs\"\nedition = \"2018\"\nlinks = \"z\"\nname = \"native-sys\"\nversion = \"0.1.0\"\n[package.metadata.cargo2nix]\npatches = [\"nix/no-vendor.patch\"]\n\n[package.metadata.cargo2nix.env]\nNATIVE_SYS_USE_PKG_CONFIG = \"1\"\n";
+    nativeBuildInputAttrs = [ "pkg-config" ];
+    buildInputAttrs = [ "zlib" ];
+    env = {
+      "NATIVE_SYS_USE_PKG_CONFIG" = "1";
+    };
+    patches = [
+      (workspaceSrc + "/nix/native-sys-static.patch")
+      "nix/no-vendor.patch"
+    ];
+    buildDependencies = {
+      cc = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".cc."1.0.66" { profileName = "__noProfile"; };
+      pkg_config = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.19" { profileName = "__noProfile"; };
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".pkg-config."0.3.19" = overridableMkRustCrate (profileName: rec {
+    name = "pkg-config";
+    version = "0.3.19";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "b4f3e61c9e64c4321f0967813d942de2c4536521fce1569cbce491972e2279e6"; };
+    edition = "2018";
+    libName = "pkg_config";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"pkg-config\"\nversion = \"0.3.19\"\n";
+  });
+  
+}
@@ -0,0 +1,133 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "app/default"
+    "macros/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    app = rustPackages.unknown.app."0.1.0";
+    macros = rustPackages.unknown.macros."0.1.0";
+  };
+  "unknown".app."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "app";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal (workspaceSrc + "/app");
+    edition = "2018";
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nname = \"app\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      derive_thing = buildRustPackages."registry+https://github.com/rust-lang/crates.io-index".derive-thing."0.1.0" { profileName = "__noProfile"; };
+      macros = buildRustPackages."unknown".macros."0.1.0" { profileName = "__noProfile"; };
+    };
+    targets = [
+      { kind = "bin"; name = "app"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".derive-thing."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "derive-thing";
+    version = "0.1.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "4f3090d7faf1c13cb2e9e69d87f0f408646a37b2749b3e4ede9d3cff5d04b99f"; };
+    edition = "2018";
+    libName = "derive_thing";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nedition = \"2018\"\nname = \"derive-thing\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" { inherit profileName; };
+    };
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."1.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "1.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "6de753b69b752faff1602403f63ae11a2eae890fc14e13c728d3c6097096c539"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nauthors = [\"Leaf Author <leaf@example.com>\"]\ndescription = \"A leaf crate with \${dollar} and \\\"quotes\\\"\"\nedition = \"2018\"\nlicense = \"MIT OR Apache-2.0\"\nname = \"leaf\"\nrepository = \"https://github.com/example/leaf\"\nversion = \"1.0.0\"\n";
+    meta = {
+      description = "A leaf crate with \${dollar} and \"quotes\"";
+      license = [ lib.licenses.mit lib.licenses.asl20 ];
+      homepage = "https://github.com/example/leaf";
+    };
+    features = builtins.concatLists [
+      [ "default" ]
+      [ "std" ]
+    ];
+  });
+  
+  "registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" = overridableMkRustCrate (profileName: rec {
+    name = "leaf";
+    version = "2.0.0";
+    registry = "registry+https://github.com/rust-lang/crates.io-index";
+    src = fetchCratesIo { inherit name version; sha256 = "0db4b40d4eed3b666f4a6c526cf50c2565d37b5b9ae3d41dbab9801079e922fe"; };
+    edition = "2018";
+    libName = "leaf";
+    libCrateTypes = [ "lib" ];
+    manifest = builtins.fromTOML "[package]\nedition = \"2018\"\nlicense = \"LGPL-3.0-or-later\"\nname = \"leaf\"\nversion = \"2.0.0\"\n";
+    meta = {
+      license = [ lib.licenses.lgpl3Plus ];
+    };
+    features = builtins.concatLists [
+      (lib.optional (rootFeatures' ? "macros") "alloc")
+      [ "default" ]
+    ];
+  });
+  
+  "unknown".macros."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "macros";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal (workspaceSrc + "/macros");
+    edition = "2018";
+    libName = "macros";
+    libCrateTypes = [ "proc-macro" ];
+    manifest = builtins.fromTOML "[lib]\nproc-macro = true\n\n[package]\nedition = \"2018\"\nname = \"macros\"\nversion = \"0.1.0\"\n";
+    dependencies = {
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" { inherit profileName; };
+    };
+    devDependencies = {
+      leaf = rustPackages."registry+https://github.com/rust-lang/crates.io-index".leaf."2.0.0" { inherit profileName; };
+    };
+    targets = [
+      { kind = "lib"; name = "macros"; requiredFeatures = [ ]; crateTypes = [ "proc-macro" ]; harness = true; tested = true; }
+    ];
+  });
+  
+}
@@ -0,0 +1,56 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It builds the packages in Cargo.nix; regenerate it with `cargo2nix flake`.
+
+{
+  inputs = {
+    nixpkgs.url = "github:NixOS/nixpkgs/e34208e10033315fddf6909d3ff68e2d3cf48a23";
+    rust-overlay = {
+      url = "github:oxalica/rust-overlay/a9309152e39974309a95f3350ccb1337734c3fe5";
+      flake = false;
+    };
+    cargo2nix = {
+      url = "github:cargo2nix/cargo2nix/v0.9.0";
+      flake = false;
+    };
+  };
+
+  outputs = { self, nixpkgs, rust-overlay, cargo2nix }:
+    let
+      cargo2nixVersion = "0.9.0";
+      rustChannel = "1.50.0";
+      systems = [ "x86_64-linux" "aarch64-linux" "x86_64-darwin" "aarch64-darwin" ];
+
+      forAllSystems = f: nixpkgs.lib.genAttrs systems (system:
+        let
+          pkgs = import nixpkgs {
+            inherit system;
+            overlays = [ (import "${cargo2nix}/overlay") (import rust-overlay) ];
+          };
+          rustPkgs = pkgs.rustBuilder.makePackageSet' {
+            inherit rustChannel;
+            packageFun = import ./Cargo.nix;
+            packageOverrides = pkgs: pkgs.rustBuilder.overrides.all;
+          };
+        in
+          f pkgs rustPkgs);
+    in
+    {
+      packages = forAllSystems (pkgs: rustPkgs: {
+        app = rustPkgs.workspace.app { };
+        macros = rustPkgs.workspace.macros { };
+      });
+
+      checks = forAllSystems (pkgs: rustPkgs: {
+        app = pkgs.rustBuilder.runTests rustPkgs.workspace.app { };
+        macros = pkgs.rustBuilder.runTests rustPkgs.workspace.macros { };
+      });
+
+      devShells = forAllSystems (pkgs: rustPkgs: {
+        default = pkgs.mkShell {
+          inputsFrom = pkgs.lib.mapAttrsToList (_: pkg: pkg { }) rustPkgs.noBuild.workspace;
+          nativeBuildInputs = with rustPkgs; [ cargo rustc ];
+          RUST_SRC_PATH = "${rustPkgs.rust-src}/lib/rustlib/src/rust/library";
+        };
+      });
+    };
+}
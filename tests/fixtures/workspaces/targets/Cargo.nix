@@ -0,0 +1,67 @@
+# This file was @generated by cargo2nix 0.9.0.
+# It is not intended to be manually edited.
+
+args@{
+  release ? true,
+  rootFeatures ? [
+    "targets/default"
+  ],
+  rustPackages,
+  buildRustPackages,
+  hostPlatform,
+  hostPlatformCpu ? null,
+  hostPlatformFeatures ? [],
+  hostPlatformCfgs ? [],
+  hostPlatformPanic ? if hostPlatform.isWasm || hostPlatform.isNone then "abort" else "unwind",
+  hostPlatformDebugAssertions ? true,
+  mkRustCrate,
+  rustLib,
+  lib,
+  workspaceSrc,
+}:
+let
+  workspaceSrc = if args.workspaceSrc == null then ./. else args.workspaceSrc;
+in let
+  inherit (rustLib) fetchCratesIo fetchCrateLocal fetchCrateGit fetchCrateAlternativeRegistry expandFeatures decideProfile genDrvsByProfile;
+  profilesByName = {
+  };
+  rootFeatures' = expandFeatures rootFeatures;
+  overridableMkRustCrate = f:
+    let
+      drvs = genDrvsByProfile profilesByName ({ profile, profileName }: mkRustCrate ({ inherit release profile hostPlatformCpu hostPlatformFeatures hostPlatformCfgs; } // (f profileName)));
+    in { compileMode ? null, profileName ? decideProfile compileMode release, target ? null }:
+      let
+        drv = drvs.${profileName};
+        drv' = if compileMode == null then drv else drv.override { inherit compileMode; };
+      in if target == null then drv' else drv'.override { inherit target; };
+in
+{
+  cargo2nixVersion = "0.9.0";
+  workspace = {
+    targets = rustPackages.unknown.targets."0.1.0";
+  };
+  "unknown".targets."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "targets";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    edition = "2018";
+    libName = "targets";
+    libCrateTypes = [ "rlib" "cdylib" ];
+    buildScript = "build.rs";
+    manifest = builtins.fromTOML "[[bench]]\nharness = false\nname = \"speed\"\n\n[[bin]]\nname = \"tool\"\npath = \"src/bin/tool.rs\"\nrequired-features = [\"cli\"]\n\n[[example]]\nname = \"demo\"\n\n[[test]]\nharness = false\nname = \"custom\"\n\n[lib]\ncrate-type = [\"rlib\", \"cdylib\"]\n\n[package]\nbuild = \"build.rs\"\nedition = \"2018\"\nname = \"targets\"\nversion = \"0.1.0\"\n";
+    features = builtins.concatLists [
+      [ "cli" ]
+    ];
+    targets = [
+      { kind = "lib"; name = "targets"; requiredFeatures = [ ]; crateTypes = [ "rlib" "cdylib" ]; harness = true; tested = true; }
+      { kind = "bin"; name = "tool"; requiredFeatures = [ "cli" ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+      { kind = "bin"; name = "targets"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+      { kind = "example"; name = "demo"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = false; }
+      { kind = "test"; name = "custom"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = false; tested = true; }
+      { kind = "test"; name = "smoke"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = true; tested = true; }
+      { kind = "bench"; name = "speed"; requiredFeatures = [ ]; crateTypes = [ "bin" ]; harness = false; tested = false; }
+    ];
+  });
+  
+}
@@ -0,0 +1,359 @@
+//! Snapshot tests regenerating the `Cargo.nix` of each example and fixture workspace and comparing
+//! it with the committed one, so any change in the output, including its ordering, shows up as a
+//! failing test.
+//!
+//! The fixture workspaces in `tests/fixtures/workspaces` cover git dependencies, alternative
+//! registries, renamed dependencies, proc macros, target-specific dependencies, the targets of
+//! workspace members, settings from `[package.metadata.cargo2nix]` and dependencies that
+//! `cargo2nix lint` warns about. Everything they depend on is vendored in `tests/fixtures/vendor`,
+//! or in `tests/fixtures/outside-crate` for a path dependency outside the workspace, so they are
+//! generated offline.
+//!
+//! The examples depend on crates.io, whose crates are vendored in `tests/fixtures/examples-vendor`
+//! for the same reason. Of each crate, only `Cargo.toml`, `.cargo-checksum.json` with an empty
+//! `files` map and empty build scripts and library and binary roots are kept, which is all
+//! `cargo2nix` reads. After changing the lock file of an example, add the crates it now locks
+//! from `cargo vendor --versioned-dirs` output and strip them the same way.
+//!
+//! A flake, SBOMs, build plans and the dependency graph are generated, licenses checked and crates
+//! audited against the advisories in `tests/fixtures/advisory-db` for some of the fixtures too, and
+//! the `explain`, `why` and `diff` subcommands are run over them.
+//!
+//! After an intended change, run with `CARGO2NIX_BLESS=1` to rewrite the committed files.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn check_example(name: &str) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // The examples are meant to be copied, so crates.io is replaced in a Cargo home of their own
+    // rather than in a `.cargo/config.toml` next to them.
+    let cargo_home = tempfile::tempdir().unwrap();
+    fs::write(
+        cargo_home.path().join("config.toml"),
+        format!(
+            "[source.crates-io]\nreplace-with = \"vendored-sources\"\n\n\
+             [source.vendored-sources]\ndirectory = {:?}\n",
+            root.join("tests/fixtures/examples-vendor")
+        ),
+    )
+    .unwrap();
+    check_snapshot(
+        &root.join("examples").join(name),
+        Command::new(env!("CARGO_BIN_EXE_cargo2nix"))
+            .arg("--stdout")
+            .env("CARGO_HOME", cargo_home.path()),
+        "Cargo.nix",
+        true,
+    );
+}
+
+fn check_fixture(name: &str) {
+    check_fixture_output(name, &["--stdout"], "Cargo.nix");
+}
+
+fn check_fixture_output(name: &str, args: &[&str], snapshot: &str) {
+    check_fixture_run(name, args, snapshot, true);
+}
+
+fn check_fixture_failure(name: &str, args: &[&str], snapshot: &str) {
+    check_fixture_run(name, args, snapshot, false);
+}
+
+fn check_fixture_run(name: &str, args: &[&str], snapshot: &str, success: bool) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/workspaces")
+        .join(name);
+    // A fresh Cargo home keeps the user's configuration and caches out of the output.
+    let cargo_home = tempfile::tempdir().unwrap();
+    check_snapshot(
+        &dir,
+        Command::new(env!("CARGO_BIN_EXE_cargo2nix"))
+            .args(args)
+            .env("CARGO_HOME", cargo_home.path())
+            .env("SOURCE_DATE_EPOCH", "0"),
+        snapshot,
+        success,
+    );
+}
+
+/// Runs `cargo2nix` in `dir` and compares its output with the file `snapshot` there, expecting it
+/// to succeed or fail.
+fn check_snapshot(dir: &Path, cargo2nix: &mut Command, snapshot: &str, success: bool) {
+    let name = dir.display();
+    let output = cargo2nix
+        .current_dir(dir)
+        .output()
+        .expect("could not run cargo2nix");
+    assert_eq!(
+        output.status.success(),
+        success,
+        "cargo2nix {} for {}:\n{}",
+        if success { "failed" } else { "succeeded" },
+        name,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let generated = String::from_utf8(output.stdout).unwrap();
+    let snapshot_path = dir.join(snapshot);
+    if env::var_os("CARGO2NIX_BLESS").is_some() {
+        fs::write(&snapshot_path, &generated).unwrap();
+        return;
+    }
+
+    let snapshot = fs::read_to_string(&snapshot_path).unwrap();
+    if generated != snapshot {
+        let first_difference = generated
+            .lines()
+            .zip(snapshot.lines())
+            .position(|(g, e)| g != e)
+            .unwrap_or_else(|| generated.lines().count().min(snapshot.lines().count()));
+        panic!(
+            "{} differs from the generated output from line {} on; \
+             rerun with CARGO2NIX_BLESS=1 if the change is intended",
+            snapshot_path.display(),
+            first_difference + 1
+        );
+    }
+}
+
+#[test]
+fn hello_world() {
+    check_example("1-hello-world");
+}
+
+#[test]
+fn bigger_project() {
+    check_example("2-bigger-project");
+}
+
+#[test]
+fn static_resources() {
+    check_example("3-static-resources");
+}
+
+// `4-independent-packaging` is deliberately not covered: its `Cargo.nix` is generated from a
+// checkout of rust-analyzer, which is not part of this repository.
+
+#[test]
+fn alt_registry() {
+    check_fixture("alt-registry");
+}
+
+#[test]
+fn git_deps() {
+    check_fixture("git-deps");
+}
+
+#[test]
+fn lints() {
+    check_fixture("lints");
+}
+
+#[test]
+fn lints_override_stubs() {
+    check_fixture_output(
+        "lints",
+        &["--stdout", "--override-stubs"],
+        "Cargo-override-stubs.nix",
+    );
+}
+
+#[test]
+fn lints_lint() {
+    check_fixture_failure("lints", &["lint"], "lint.txt");
+}
+
+#[test]
+fn metadata() {
+    check_fixture("metadata");
+}
+
+#[test]
+fn proc_macros() {
+    check_fixture("proc-macros");
+}
+
+#[test]
+fn proc_macros_flake() {
+    check_fixture_output("proc-macros", &["flake", "--stdout"], "flake.nix");
+}
+
+#[test]
+fn proc_macros_graph_json() {
+    check_fixture_output("proc-macros", &["graph", "--format", "json"], "graph.json");
+}
+
+#[test]
+fn proc_macros_why() {
+    check_fixture_output("proc-macros", &["why", "leaf"], "why.txt");
+}
+
+#[test]
+fn proc_macros_licenses() {
+    check_fixture_failure("proc-macros", &["licenses"], "licenses.txt");
+}
+
+#[test]
+fn renamed_deps() {
+    check_fixture("renamed-deps");
+}
+
+#[test]
+fn renamed_deps_plan() {
+    check_fixture_output("renamed-deps", &["plan"], "plan.json");
+}
+
+#[test]
+fn renamed_deps_diff() {
+    check_fixture_output(
+        "renamed-deps",
+        &["diff", "plan.json", "../target-deps/plan.json"],
+        "diff.txt",
+    );
+}
+
+/// Commits the `renamed-deps` fixture and then the `target-deps` one to a new git repository, and
+/// compares the revisions, which must give the same changes as comparing their plans.
+#[test]
+fn diff_git_revisions() {
+    let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let repo = tempfile::tempdir().unwrap();
+    let cargo_home = tempfile::tempdir().unwrap();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(&[
+                "-c",
+                "user.name=cargo2nix",
+                "-c",
+                "user.email=cargo2nix@example.com",
+            ])
+            .args(args)
+            .current_dir(repo.path())
+            .status()
+            .expect("could not run git");
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    let copy_fixture = |name: &str| {
+        let fixture = fixtures.join("workspaces").join(name);
+        let src = repo.path().join("src");
+        if src.exists() {
+            fs::remove_dir_all(&src).unwrap();
+        }
+        fs::create_dir(&src).unwrap();
+        for file in &["Cargo.toml", "Cargo.lock", "src/main.rs"] {
+            fs::copy(fixture.join(file), repo.path().join(file)).unwrap();
+        }
+    };
+
+    // The fixtures' sources are vendored next to them, which the exported revisions are not.
+    let config = fs::read_to_string(fixtures.join("workspaces/.cargo/config.toml"))
+        .unwrap()
+        .replace("\"../vendor\"", &format!("{:?}", fixtures.join("vendor")));
+    fs::create_dir(repo.path().join(".cargo")).unwrap();
+    fs::write(repo.path().join(".cargo/config.toml"), config).unwrap();
+
+    git(&["init", "--quiet"]);
+    copy_fixture("renamed-deps");
+    git(&["add", "--all"]);
+    git(&["commit", "--quiet", "--message", "renamed-deps"]);
+    copy_fixture("target-deps");
+    git(&["add", "--all"]);
+    git(&["commit", "--quiet", "--message", "target-deps"]);
+    let lock_file = fs::read(repo.path().join("Cargo.lock")).unwrap();
+
+    let expected = fs::read_to_string(fixtures.join("workspaces/renamed-deps/diff.txt")).unwrap();
+    for args in &[
+        &["diff", "--git", "HEAD~", "HEAD"][..],
+        &["diff", "--git", "HEAD~"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_cargo2nix"))
+            .args(*args)
+            .current_dir(repo.path())
+            .env("CARGO_HOME", cargo_home.path())
+            .output()
+            .expect("could not run cargo2nix");
+        assert!(
+            output.status.success(),
+            "cargo2nix {:?} failed:\n{}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+    }
+
+    // The revisions are generated from exports, not by checking them out.
+    assert_eq!(fs::read(repo.path().join("Cargo.lock")).unwrap(), lock_file);
+}
+
+// Without build dependencies, the output does not depend on the platform the tests run on.
+#[test]
+fn renamed_deps_explain() {
+    check_fixture_output(
+        "renamed-deps",
+        &["explain", "--target", "x86_64-pc-windows-gnu"],
+        "explain.txt",
+    );
+}
+
+#[test]
+fn renamed_deps_why() {
+    check_fixture_output("renamed-deps", &["why", "leaf:2.0.0", "alloc"], "why.txt");
+}
+
+#[test]
+fn git_deps_spdx() {
+    check_fixture_output("git-deps", &["sbom", "--format", "spdx"], "sbom.spdx.json");
+}
+
+#[test]
+fn target_deps() {
+    check_fixture("target-deps");
+}
+
+#[test]
+fn target_deps_plan() {
+    check_fixture_output("target-deps", &["plan"], "plan.json");
+}
+
+#[test]
+fn target_deps_graph() {
+    check_fixture_output("target-deps", &["graph"], "graph.dot");
+}
+
+const AUDIT_TARGET_DEPS: &[&str] = &[
+    "audit",
+    "--db",
+    "../../advisory-db",
+    "--target",
+    "x86_64-unknown-linux-gnu",
+    "--target",
+    "x86_64-pc-windows-gnu",
+];
+
+#[test]
+fn target_deps_audit() {
+    check_fixture_failure("target-deps", AUDIT_TARGET_DEPS, "audit.txt");
+}
+
+#[test]
+fn target_deps_audit_json() {
+    let args = [AUDIT_TARGET_DEPS, &["--json"]].concat();
+    check_fixture_failure("target-deps", &args, "audit.json");
+}
+
+#[test]
+fn target_deps_cyclonedx() {
+    check_fixture_output(
+        "target-deps",
+        &["sbom", "--target", "x86_64-pc-windows-gnu"],
+        "sbom.cdx.json",
+    );
+}
+
+#[test]
+fn targets() {
+    check_fixture("targets");
+}